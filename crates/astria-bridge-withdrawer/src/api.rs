@@ -21,7 +21,10 @@ use hyper::server::conn::AddrIncoming;
 use serde::Serialize;
 use tokio::sync::watch;
 
-use crate::bridge_withdrawer::StateSnapshot;
+use crate::bridge_withdrawer::{
+    DeadletteredBatch,
+    StateSnapshot,
+};
 
 pub(crate) type ApiServer = axum::Server<AddrIncoming, IntoMakeService<Router>>;
 
@@ -44,6 +47,7 @@ pub(crate) fn start(socket_addr: SocketAddr, withdrawer_state: WithdrawerState)
         .route("/healthz", get(get_healthz))
         .route("/readyz", get(get_readyz))
         .route("/status", get(get_status))
+        .route("/deadletter", get(get_deadletter))
         .with_state(AppState {
             withdrawer_state,
         });
@@ -80,6 +84,18 @@ async fn get_status(State(withdrawer_state): State<WithdrawerState>) -> Json<Sta
     Json(withdrawer_state.borrow().clone())
 }
 
+/// Handler of a call to `/deadletter`.
+///
+/// Returns a summary of all batches that were permanently rejected by the sequencer since the
+/// withdrawer started. Their withdrawals are not resubmitted while the withdrawer keeps running and
+/// require operator action; see the deadletter log for the individual withdrawals.
+#[allow(clippy::unused_async)] // Permit because axum handlers must be async
+async fn get_deadletter(
+    State(withdrawer_state): State<WithdrawerState>,
+) -> Json<Vec<DeadletteredBatch>> {
+    Json(withdrawer_state.borrow().deadletter().to_vec())
+}
+
 enum Healthz {
     Ok,
     Degraded,
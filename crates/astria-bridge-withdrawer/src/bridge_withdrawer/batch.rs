@@ -1,8 +1,251 @@
 use astria_core::protocol::transaction::v1alpha1::Action;
+use prost::Message as _;
+use sha2::{
+    Digest as _,
+    Sha256,
+};
 
+#[derive(Debug)]
 pub(crate) struct Batch {
     /// The withdrawal payloads
     pub(crate) actions: Vec<Action>,
     /// The corresponding rollup block height
     pub(crate) rollup_height: u64,
 }
+
+// Batches are sent from the watcher task to the submitter task.
+const _: () = {
+    const fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<Batch>();
+};
+
+impl Batch {
+    /// The maximum number of actions in a batch produced by [`Batch::try_merge`].
+    pub(crate) const MAX_MERGED_ACTIONS: usize = 256;
+
+    /// Returns a 32 byte id identifying the contents of this batch.
+    ///
+    /// The id is the sha256 hash over the rollup height followed by the length delimited protobuf
+    /// encoding of each action. The hash is computed incrementally, one action at a time, so the
+    /// memory required does not grow with the size of the batch.
+    pub(crate) fn idempotency_id(&self) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update(self.rollup_height.to_be_bytes());
+        let mut buf = Vec::new();
+        for action in &self.actions {
+            buf.clear();
+            action
+                .to_raw()
+                .encode_length_delimited(&mut buf)
+                .expect("a vector grows to fit the encoded action");
+            hasher.update(&buf);
+        }
+        hasher.finalize().into()
+    }
+
+    /// Merges `other` into this batch, so that the withdrawals of both are submitted together.
+    ///
+    /// The batches are only merged if `other` follows this batch at a greater rollup height, and
+    /// if the merged batch holds no more than [`Batch::MAX_MERGED_ACTIONS`] actions. The merged
+    /// batch takes the rollup height of `other`.
+    ///
+    /// # Errors
+    /// Returns both batches unchanged if they cannot be merged.
+    pub(crate) fn try_merge(self, other: Batch) -> Result<Batch, (Batch, Batch)> {
+        if self.rollup_height >= other.rollup_height
+            || self.actions.len().saturating_add(other.actions.len()) > Self::MAX_MERGED_ACTIONS
+        {
+            return Err((self, other));
+        }
+        let Self {
+            mut actions, ..
+        } = self;
+        actions.extend(other.actions);
+        Ok(Batch {
+            actions,
+            rollup_height: other.rollup_height,
+        })
+    }
+}
+
+/// An adaptive target for the number of actions submitted in a single sequencer transaction.
+///
+/// The target follows an additive-increase/multiplicative-decrease (AIMD) scheme: every
+/// transaction accepted by the sequencer grows the target by one action, while every transaction
+/// rejected for being too large halves it. The target always stays within the configured
+/// `[min, max]` bounds.
+#[derive(Debug)]
+pub(crate) struct BatchSizeTarget {
+    current: usize,
+    min: usize,
+    max: usize,
+}
+
+impl BatchSizeTarget {
+    const ADDITIVE_INCREASE: usize = 1;
+    const MULTIPLICATIVE_DECREASE_DIVISOR: usize = 2;
+
+    /// Creates a new target starting at `max`.
+    ///
+    /// `min` is clamped to at least 1 and `max` to at least `min`.
+    pub(crate) fn new(min: usize, max: usize) -> Self {
+        let min = min.max(1);
+        let max = max.max(min);
+        Self {
+            current: max,
+            min,
+            max,
+        }
+    }
+
+    /// Returns the current target number of actions per transaction.
+    pub(crate) fn get(&self) -> usize {
+        self.current
+    }
+
+    /// Returns `true` if the target cannot shrink any further.
+    pub(crate) fn is_at_min(&self) -> bool {
+        self.current == self.min
+    }
+
+    /// Grows the target after the sequencer accepted a transaction.
+    pub(crate) fn on_accepted(&mut self) {
+        self.current = self
+            .current
+            .saturating_add(Self::ADDITIVE_INCREASE)
+            .min(self.max);
+    }
+
+    /// Shrinks the target after the sequencer rejected a transaction for being too large.
+    pub(crate) fn on_too_large(&mut self) {
+        self.current = self
+            .current
+            .saturating_div(Self::MULTIPLICATIVE_DECREASE_DIVISOR)
+            .max(self.min);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::{
+        primitive::v1::asset,
+        protocol::transaction::v1alpha1::{
+            action::BridgeUnlockAction,
+            Action,
+        },
+    };
+
+    use super::{
+        Batch,
+        BatchSizeTarget,
+    };
+
+    fn make_bridge_unlock_action(amount: u128) -> Action {
+        Action::BridgeUnlock(BridgeUnlockAction {
+            to: crate::astria_address([1; 20]),
+            amount,
+            memo: vec![],
+            fee_asset_id: asset::Id::from_str_unchecked("nria"),
+            bridge_address: None,
+        })
+    }
+
+    fn make_batch(amounts: &[u128]) -> Batch {
+        Batch {
+            actions: amounts
+                .iter()
+                .copied()
+                .map(make_bridge_unlock_action)
+                .collect(),
+            rollup_height: 42,
+        }
+    }
+
+    #[test]
+    fn identical_batches_have_equal_idempotency_ids() {
+        assert_eq!(
+            make_batch(&[1, 2, 3]).idempotency_id(),
+            make_batch(&[1, 2, 3]).idempotency_id(),
+        );
+    }
+
+    #[test]
+    fn batches_differing_in_one_action_have_different_idempotency_ids() {
+        assert_ne!(
+            make_batch(&[1, 2, 3]).idempotency_id(),
+            make_batch(&[1, 2, 4]).idempotency_id(),
+        );
+    }
+
+    #[test]
+    fn batches_differing_in_rollup_height_have_different_idempotency_ids() {
+        let mut other = make_batch(&[1, 2, 3]);
+        other.rollup_height = 43;
+        assert_ne!(
+            make_batch(&[1, 2, 3]).idempotency_id(),
+            other.idempotency_id(),
+        );
+    }
+
+    #[test]
+    fn batches_at_increasing_heights_are_merged() {
+        let first = make_batch(&[1, 2]);
+        let mut second = make_batch(&[3]);
+        second.rollup_height = 43;
+
+        let merged = first.try_merge(second).unwrap();
+        assert_eq!(43, merged.rollup_height);
+        let mut expected = make_batch(&[1, 2, 3]);
+        expected.rollup_height = 43;
+        assert_eq!(expected.idempotency_id(), merged.idempotency_id());
+    }
+
+    #[test]
+    fn batches_at_the_same_height_are_not_merged() {
+        let first = make_batch(&[1, 2]);
+        let second = make_batch(&[3]);
+        let (first_id, second_id) = (first.idempotency_id(), second.idempotency_id());
+
+        let (first, second) = first.try_merge(second).unwrap_err();
+        assert_eq!(first_id, first.idempotency_id());
+        assert_eq!(second_id, second.idempotency_id());
+    }
+
+    #[test]
+    fn rejections_shrink_and_acceptances_grow_target() {
+        let mut target = BatchSizeTarget::new(2, 16);
+        assert_eq!(16, target.get());
+
+        target.on_too_large();
+        assert_eq!(8, target.get());
+        target.on_too_large();
+        assert_eq!(4, target.get());
+
+        target.on_accepted();
+        assert_eq!(5, target.get());
+        target.on_accepted();
+        assert_eq!(6, target.get());
+    }
+
+    #[test]
+    fn target_stays_within_bounds() {
+        let mut target = BatchSizeTarget::new(3, 5);
+        for _ in 0..10 {
+            target.on_too_large();
+        }
+        assert_eq!(3, target.get());
+        assert!(target.is_at_min());
+
+        for _ in 0..10 {
+            target.on_accepted();
+        }
+        assert_eq!(5, target.get());
+    }
+
+    #[test]
+    fn degenerate_bounds_are_clamped() {
+        let target = BatchSizeTarget::new(0, 0);
+        assert_eq!(1, target.get());
+        assert!(target.is_at_min());
+    }
+}
@@ -84,6 +84,46 @@ pub(crate) fn event_to_action(
     Ok(action)
 }
 
+/// Formats an `amount` of base units of an asset with `decimals` decimal places as a human
+/// readable decimal string, e.g. `1234567` with 6 decimals as `1.234567`.
+///
+/// Trailing zeros of the fractional part are dropped, as is the decimal point if the amount is a
+/// whole number.
+///
+/// Returns `None` if `10^decimals` does not fit into a `u128`.
+pub(crate) fn format_amount(amount: u128, decimals: u32) -> Option<String> {
+    let divisor = 10u128.checked_pow(decimals)?;
+    let whole = amount.checked_div(divisor)?;
+    let fraction = amount.checked_rem(divisor)?;
+    if fraction == 0 {
+        return Some(whole.to_string());
+    }
+    let width = usize::try_from(decimals).ok()?;
+    let fraction = format!("{fraction:0width$}");
+    Some(format!("{whole}.{}", fraction.trim_end_matches('0')))
+}
+
+/// Returns the amount withdrawn by `action`, or `None` if it is not a withdrawal.
+pub(crate) fn withdrawal_amount(action: &Action) -> Option<u128> {
+    match action {
+        Action::BridgeUnlock(action) => Some(action.amount),
+        Action::Ics20Withdrawal(action) => Some(action.amount()),
+        _ => None,
+    }
+}
+
+/// Returns the address that `action` withdraws to, or `None` if it is not a withdrawal.
+///
+/// This is the sequencer address for bridge unlocks and the address on the destination chain for
+/// ICS20 withdrawals.
+pub(crate) fn withdrawal_destination(action: &Action) -> Option<String> {
+    match action {
+        Action::BridgeUnlock(action) => Some(action.to.to_string()),
+        Action::Ics20Withdrawal(action) => Some(action.destination_chain_address.clone()),
+        _ => None,
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub(crate) struct BridgeUnlockMemo {
     pub(crate) block_number: U64,
@@ -197,6 +237,35 @@ mod tests {
     use super::*;
     use crate::bridge_withdrawer::ethereum::astria_withdrawer_interface::SequencerWithdrawalFilter;
 
+    #[test]
+    fn format_amount_inserts_decimal_point() {
+        assert_eq!("1.234567", format_amount(1_234_567, 6).unwrap());
+        assert_eq!("1234567", format_amount(1_234_567, 0).unwrap());
+        assert_eq!("0", format_amount(0, 6).unwrap());
+    }
+
+    #[test]
+    fn format_amount_zero_pads_fraction() {
+        assert_eq!("0.000001", format_amount(1, 6).unwrap());
+        assert_eq!("12.000345", format_amount(12_000_345, 6).unwrap());
+    }
+
+    #[test]
+    fn format_amount_drops_trailing_zeros() {
+        assert_eq!("1.2", format_amount(1_200_000, 6).unwrap());
+        assert_eq!("1", format_amount(1_000_000, 6).unwrap());
+        assert_eq!("0.01", format_amount(10_000, 6).unwrap());
+    }
+
+    #[test]
+    fn format_amount_handles_extreme_values() {
+        assert_eq!(
+            "340282366920938463463.374607431768211455",
+            format_amount(u128::MAX, 18).unwrap()
+        );
+        assert_eq!(None, format_amount(1, 39));
+    }
+
     #[test]
     fn event_to_bridge_unlock() {
         let denom = default_native_asset();
@@ -1,4 +1,6 @@
 pub(crate) mod convert;
+pub(crate) mod processed_events;
+pub(crate) mod watchdog;
 pub(crate) mod watcher;
 
 #[rustfmt::skip]
@@ -0,0 +1,320 @@
+//! Replay protection for withdrawal events.
+//!
+//! The rollup node can deliver the same log more than once, for example when an event stream is
+//! re-subscribed and re-scans blocks it already returned. Each log is uniquely identified by the
+//! block it was emitted in, the transaction that emitted it, and its index in that block. The
+//! [`ProcessedEvents`] set records these identifiers so that the watcher turns every log into a
+//! sequencer action exactly once.
+//!
+//! Events at rollup heights that were already submitted to the sequencer are rejected outright,
+//! so the set only needs to hold identifiers above that watermark and is pruned whenever the
+//! watermark advances.
+//!
+//! If the set is opened from a file, the file is replaced whenever the set changes. A restarted
+//! watcher then resumes from the higher of the stored watermark and the rollup height after the
+//! last one recorded on the sequencer, so events the withdrawer saw executed are skipped even if
+//! the sequencer node it restarts against lags behind. Identifiers at or above that watermark
+//! belong to events whose withdrawals were not executed before the withdrawer stopped; they are
+//! forgotten on restart so that the watcher submits them when it reads them again.
+
+use std::{
+    collections::BTreeSet,
+    fs::File,
+    io::{
+        BufReader,
+        BufWriter,
+        ErrorKind,
+        Write as _,
+    },
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+use astria_eyre::eyre::{
+    self,
+    WrapErr as _,
+};
+use ethers::{
+    contract::LogMeta,
+    types::{
+        H256,
+        U256,
+    },
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use tracing::info;
+
+/// Uniquely identifies a log emitted on the rollup.
+///
+/// Ordered by block number first so that the set can be pruned by rollup height.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
+struct EventId {
+    block_number: u64,
+    block_hash: H256,
+    transaction_hash: H256,
+    log_index: U256,
+}
+
+impl EventId {
+    fn from_log_meta(meta: &LogMeta) -> Self {
+        Self {
+            block_number: meta.block_number.as_u64(),
+            block_hash: meta.block_hash,
+            transaction_hash: meta.transaction_hash,
+            log_index: meta.log_index,
+        }
+    }
+}
+
+/// The set as it is written to the file.
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct Snapshot {
+    watermark: u64,
+    ids: Vec<EventId>,
+}
+
+/// The set of withdrawal events that were already processed by the watcher.
+#[derive(Debug)]
+pub(crate) struct ProcessedEvents {
+    /// Events emitted below this rollup height are considered processed.
+    watermark: u64,
+    ids: BTreeSet<EventId>,
+    /// The file the set is kept in, if any.
+    path: Option<PathBuf>,
+}
+
+impl ProcessedEvents {
+    /// Creates an empty set which considers all events below `watermark` processed and is only
+    /// kept in memory.
+    pub(crate) fn new(watermark: u64) -> Self {
+        Self {
+            watermark,
+            ids: BTreeSet::new(),
+            path: None,
+        }
+    }
+
+    /// Opens the set kept in the file at `path`, considering all events below `watermark` or
+    /// below the watermark stored in the file processed.
+    ///
+    /// Starts from an empty set if the file does not exist.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be read or decoded, or if it cannot be replaced with
+    /// the opened set.
+    pub(crate) fn open<P: AsRef<Path>>(path: P, watermark: u64) -> eyre::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let stored = match File::open(&path) {
+            Ok(file) => serde_json::from_reader(BufReader::new(file))
+                .wrap_err("failed to parse processed withdrawal events")?,
+            Err(error) if error.kind() == ErrorKind::NotFound => Snapshot::default(),
+            Err(error) => {
+                return Err(error)
+                    .wrap_err_with(|| format!("failed to open file at path: {}", path.display()));
+            }
+        };
+        if stored.watermark > watermark {
+            info!(
+                stored_watermark = stored.watermark,
+                watermark,
+                "resuming from the stored watermark of processed withdrawal events, which is \
+                 above the rollup height to resume from according to the sequencer",
+            );
+        }
+        let processed = Self {
+            watermark: watermark.max(stored.watermark),
+            ids: BTreeSet::new(),
+            path: Some(path),
+        };
+        processed.persist()?;
+        Ok(processed)
+    }
+
+    /// Records the log described by `meta` as processed.
+    ///
+    /// Returns `false` if the log was already processed, either because it was recorded before
+    /// or because it was emitted below the watermark.
+    ///
+    /// # Errors
+    /// Returns an error if the set is kept in a file and the file cannot be replaced. The log is
+    /// not recorded in that case.
+    pub(crate) fn insert(&mut self, meta: &LogMeta) -> eyre::Result<bool> {
+        let id = EventId::from_log_meta(meta);
+        if id.block_number < self.watermark || !self.ids.insert(id) {
+            return Ok(false);
+        }
+        if let Err(error) = self.persist() {
+            self.ids.remove(&id);
+            return Err(error);
+        }
+        Ok(true)
+    }
+
+    /// Returns the rollup height below which all events are considered processed.
+    pub(crate) fn watermark(&self) -> u64 {
+        self.watermark
+    }
+
+    /// Advances the watermark to `watermark`, pruning all events emitted below it.
+    ///
+    /// Does nothing if `watermark` is not above the current one.
+    ///
+    /// # Errors
+    /// Returns an error if the set is kept in a file and the file cannot be replaced.
+    pub(crate) fn prune_below(&mut self, watermark: u64) -> eyre::Result<()> {
+        if watermark <= self.watermark {
+            return Ok(());
+        }
+        self.watermark = watermark;
+        self.ids.retain(|id| id.block_number >= watermark);
+        self.persist()
+    }
+
+    /// Replaces the content of the file the set is kept in, if any.
+    ///
+    /// The file is replaced atomically, so that a watcher stopping while it is written finds
+    /// either the previous or the new content on restart.
+    fn persist(&self) -> eyre::Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        let snapshot = Snapshot {
+            watermark: self.watermark,
+            ids: self.ids.iter().copied().collect(),
+        };
+        let tmp_path = path.with_extension("tmp");
+        let file = File::create(&tmp_path)
+            .wrap_err_with(|| format!("failed to create file at path: {}", tmp_path.display()))?;
+        let mut writer = BufWriter::new(file);
+        serde_json::to_writer(&mut writer, &snapshot)
+            .wrap_err("failed to serialize processed withdrawal events")?;
+        writer
+            .write_all(b"\n")
+            .wrap_err("failed to write line terminator")?;
+        writer
+            .into_inner()
+            .wrap_err("failed to flush processed withdrawal events")?
+            .sync_all()
+            .wrap_err("failed to sync processed withdrawal events to disk")?;
+        std::fs::rename(&tmp_path, path).wrap_err_with(|| {
+            format!(
+                "failed to replace processed withdrawal events at path: {}",
+                path.display()
+            )
+        })
+    }
+
+    #[cfg(test)]
+    fn len(&self) -> usize {
+        self.ids.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ethers::types::{
+        Address,
+        U64,
+    };
+
+    use super::*;
+
+    fn log_meta(block_number: u64, log_index: u64) -> LogMeta {
+        LogMeta {
+            address: Address::zero(),
+            block_number: U64::from(block_number),
+            block_hash: H256::repeat_byte(u8::try_from(block_number).unwrap()),
+            transaction_hash: H256::repeat_byte(0xaa),
+            transaction_index: U64::zero(),
+            log_index: U256::from(log_index),
+        }
+    }
+
+    #[test]
+    fn log_duplicated_across_two_scans_is_processed_once() {
+        let mut processed = ProcessedEvents::new(0);
+        let scan = [log_meta(1, 0), log_meta(1, 1), log_meta(2, 0)];
+
+        let first_scan: Vec<_> = scan
+            .iter()
+            .map(|meta| processed.insert(meta).unwrap())
+            .collect();
+        assert_eq!(vec![true, true, true], first_scan);
+
+        let second_scan: Vec<_> = scan
+            .iter()
+            .map(|meta| processed.insert(meta).unwrap())
+            .collect();
+        assert_eq!(vec![false, false, false], second_scan);
+    }
+
+    #[test]
+    fn same_log_index_in_another_block_is_a_distinct_event() {
+        let mut processed = ProcessedEvents::new(0);
+        assert!(processed.insert(&log_meta(1, 0)).unwrap());
+        assert!(processed.insert(&log_meta(2, 0)).unwrap());
+    }
+
+    #[test]
+    fn pruning_drops_events_below_watermark_and_rejects_them_afterwards() {
+        let mut processed = ProcessedEvents::new(0);
+        for block_number in 1..=4 {
+            assert!(processed.insert(&log_meta(block_number, 0)).unwrap());
+        }
+
+        processed.prune_below(3).unwrap();
+        assert_eq!(2, processed.len());
+        assert!(!processed.insert(&log_meta(1, 0)).unwrap());
+        assert!(!processed.insert(&log_meta(2, 1)).unwrap());
+        assert!(!processed.insert(&log_meta(3, 0)).unwrap());
+        assert!(processed.insert(&log_meta(5, 0)).unwrap());
+
+        // a lower watermark does not move it back
+        processed.prune_below(1).unwrap();
+        assert!(!processed.insert(&log_meta(2, 2)).unwrap());
+    }
+
+    #[test]
+    fn log_duplicated_across_two_scans_is_processed_once_when_kept_in_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("processed_events.json");
+        let mut processed = ProcessedEvents::open(&path, 0).unwrap();
+        assert!(processed.insert(&log_meta(1, 0)).unwrap());
+        assert!(!processed.insert(&log_meta(1, 0)).unwrap());
+
+        let stored: Snapshot =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(vec![EventId::from_log_meta(&log_meta(1, 0))], stored.ids);
+    }
+
+    #[test]
+    fn reopened_set_resumes_from_the_higher_watermark() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("processed_events.json");
+        let mut processed = ProcessedEvents::open(&path, 0).unwrap();
+        for block_number in 1..=4 {
+            assert!(processed.insert(&log_meta(block_number, 0)).unwrap());
+        }
+        processed.prune_below(3).unwrap();
+        drop(processed);
+
+        // the sequencer lags behind the stored watermark
+        let mut reopened = ProcessedEvents::open(&path, 2).unwrap();
+        assert_eq!(3, reopened.watermark());
+        assert!(!reopened.insert(&log_meta(2, 0)).unwrap());
+
+        // events above the watermark were not executed and are processed again
+        assert_eq!(0, reopened.len());
+        assert!(reopened.insert(&log_meta(3, 0)).unwrap());
+        assert!(reopened.insert(&log_meta(4, 0)).unwrap());
+
+        // the sequencer is ahead of the stored watermark
+        let reopened = ProcessedEvents::open(&path, 5).unwrap();
+        assert_eq!(5, reopened.watermark());
+    }
+}
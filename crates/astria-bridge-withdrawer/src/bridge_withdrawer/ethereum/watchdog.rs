@@ -0,0 +1,61 @@
+//! Detection of silently stalled rollup providers.
+//!
+//! A websocket provider can stop delivering new blocks without ever returning an error. The
+//! withdrawer then looks healthy while making no progress. The [`StallWatchdog`] is reset every
+//! time the watcher observes a new rollup block and fires once no block was observed for its
+//! threshold, at which point the watcher reconnects to the rollup and resubscribes to its blocks
+//! and withdrawal events.
+
+use std::{
+    pin::Pin,
+    time::Duration,
+};
+
+use tokio::time::{
+    sleep,
+    Instant,
+    Sleep,
+};
+
+pub(crate) struct StallWatchdog {
+    threshold: Duration,
+    deadline: Pin<Box<Sleep>>,
+}
+
+impl StallWatchdog {
+    pub(crate) fn new(threshold: Duration) -> Self {
+        Self {
+            threshold,
+            deadline: Box::pin(sleep(threshold)),
+        }
+    }
+
+    /// Returns the time without progress after which the watchdog fires.
+    pub(crate) fn threshold(&self) -> Duration {
+        self.threshold
+    }
+
+    /// Records that the watcher made progress, restarting the countdown.
+    pub(crate) fn reset(&mut self) {
+        let deadline = Instant::now()
+            .checked_add(self.threshold)
+            .expect("the stall threshold is configured in milliseconds and must not overflow");
+        self.deadline.as_mut().reset(deadline);
+    }
+
+    /// Resolves once no progress was recorded for the threshold.
+    ///
+    /// This is cancel safe and is intended to be polled in a `select!` loop alongside the stream
+    /// of rollup blocks.
+    pub(crate) async fn stalled(&mut self) {
+        self.deadline.as_mut().await;
+    }
+}
+
+/// Waits for `watchdog` to fire, or forever if there is no watchdog.
+pub(crate) async fn stalled(watchdog: Option<&mut StallWatchdog>) {
+    match watchdog {
+        Some(watchdog) => watchdog.stalled().await,
+        None => std::future::pending().await,
+    }
+}
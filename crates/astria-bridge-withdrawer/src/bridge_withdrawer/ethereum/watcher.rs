@@ -21,7 +21,10 @@ use astria_eyre::{
 };
 use ethers::{
     contract::LogMeta,
-    core::types::Block,
+    core::types::{
+        Block,
+        H256,
+    },
     providers::{
         Middleware,
         Provider,
@@ -31,12 +34,14 @@ use ethers::{
     },
     utils::hex,
 };
+use futures::Stream;
 use tokio::{
     select,
     sync::mpsc,
 };
 use tokio_util::sync::CancellationToken;
 use tracing::{
+    debug,
     error,
     info,
     warn,
@@ -48,9 +53,16 @@ use crate::bridge_withdrawer::{
         astria_withdrawer_interface::IAstriaWithdrawer,
         convert::{
             event_to_action,
+            format_amount,
+            withdrawal_amount,
             EventWithMetadata,
             WithdrawalEvent,
         },
+        processed_events::ProcessedEvents,
+        watchdog::{
+            self,
+            StallWatchdog,
+        },
     },
     state::State,
     submitter,
@@ -65,6 +77,15 @@ pub(crate) struct Builder {
     pub(crate) state: Arc<State>,
     pub(crate) rollup_asset_denom: Denom,
     pub(crate) bridge_address: Address,
+    /// The time without a new rollup block after which the watcher reconnects to the rollup.
+    /// `None` disables the stall watchdog.
+    pub(crate) stall_threshold: Option<Duration>,
+    /// The path of the file in which the processed withdrawal events are kept across restarts.
+    /// `None` keeps them in memory only.
+    pub(crate) processed_events_path: Option<String>,
+    /// The startup info to use instead of the one sent by the submitter, for a submitter built
+    /// without startup.
+    pub(crate) startup_info: Option<SequencerStartupInfo>,
 }
 
 impl Builder {
@@ -77,6 +98,9 @@ impl Builder {
             state,
             rollup_asset_denom,
             bridge_address,
+            stall_threshold,
+            processed_events_path,
+            startup_info,
         } = self;
 
         let contract_address = address_from_string(&ethereum_contract_address)
@@ -100,6 +124,9 @@ impl Builder {
             bridge_address,
             state,
             shutdown_token: shutdown_token.clone(),
+            stall_threshold,
+            processed_events_path,
+            startup_info,
         })
     }
 }
@@ -113,74 +140,129 @@ pub(crate) struct Watcher {
     bridge_address: Address,
     state: Arc<State>,
     shutdown_token: CancellationToken,
+    stall_threshold: Option<Duration>,
+    processed_events_path: Option<String>,
+    startup_info: Option<SequencerStartupInfo>,
 }
 
 impl Watcher {
     pub(crate) async fn run(mut self) -> Result<()> {
-        let (provider, contract, fee_asset_id, asset_withdrawal_divisor, next_rollup_block_height) =
-            self.startup()
-                .await
-                .wrap_err("watcher failed to start up")?;
+        let (
+            mut provider,
+            mut contract,
+            fee_asset_id,
+            base_chain_asset_precision,
+            asset_withdrawal_divisor,
+            next_rollup_block_height,
+        ) = self
+            .startup()
+            .await
+            .wrap_err("watcher failed to start up")?;
 
         let Self {
-            contract_address: _contract_address,
-            ethereum_rpc_endpoint: _ethereum_rps_endpoint,
+            contract_address,
+            ethereum_rpc_endpoint,
             submitter_handle,
             rollup_asset_denom,
             bridge_address,
             state,
             shutdown_token,
+            stall_threshold,
+            processed_events_path,
+            startup_info: _,
         } = self;
 
+        let processed_events = match processed_events_path {
+            Some(path) => ProcessedEvents::open(path, next_rollup_block_height)
+                .wrap_err("failed to open processed withdrawal events")?,
+            None => ProcessedEvents::new(next_rollup_block_height),
+        };
+
         let (event_tx, event_rx) = mpsc::channel(100);
 
-        let batcher = Batcher {
+        let mut batcher = Batcher {
             event_rx,
-            provider,
             submitter_handle,
             shutdown_token: shutdown_token.clone(),
             fee_asset_id,
             rollup_asset_denom,
             bridge_address,
+            base_chain_asset_precision,
             asset_withdrawal_divisor,
+            stall_watchdog: stall_threshold.map(StallWatchdog::new),
+            processed_events,
+            curr_batch: Batch {
+                actions: Vec::new(),
+                rollup_height: 0,
+            },
+            state: state.clone(),
         };
 
-        tokio::task::spawn(batcher.run());
-
-        let sequencer_withdrawal_event_handler =
-            tokio::task::spawn(watch_for_sequencer_withdrawal_events(
-                contract.clone(),
-                event_tx.clone(),
-                next_rollup_block_height,
-            ));
-        let ics20_withdrawal_event_handler = tokio::task::spawn(watch_for_ics20_withdrawal_events(
-            contract,
-            event_tx.clone(),
-            next_rollup_block_height,
-        ));
-
         state.set_watcher_ready();
 
-        tokio::select! {
-            res = sequencer_withdrawal_event_handler => {
-                info!("sequencer withdrawal event handler exited");
-                res.context("sequencer withdrawal event handler exited")?
-            }
-            res = ics20_withdrawal_event_handler => {
-                info!("ics20 withdrawal event handler exited");
-                res.context("ics20 withdrawal event handler exited")?
-            }
-           () = shutdown_token.cancelled() => {
-                info!("watcher shutting down");
-                Ok(())
+        loop {
+            // events below the watermark were already submitted, and events above it that were
+            // seen before are skipped by the batcher, so resubscribing from it loses nothing
+            let from_block = batcher.processed_events.watermark();
+            let block_rx = provider
+                .subscribe_blocks()
+                .await
+                .wrap_err("failed to subscribe to blocks")?;
+            let mut sequencer_withdrawal_event_handler =
+                tokio::task::spawn(watch_for_sequencer_withdrawal_events(
+                    contract.clone(),
+                    event_tx.clone(),
+                    from_block,
+                ));
+            let mut ics20_withdrawal_event_handler = tokio::task::spawn(
+                watch_for_ics20_withdrawal_events(contract.clone(), event_tx.clone(), from_block),
+            );
+
+            let exit = tokio::select! {
+                res = batcher.run(block_rx) => res.wrap_err("batcher failed")?,
+                res = &mut sequencer_withdrawal_event_handler => {
+                    info!("sequencer withdrawal event handler exited");
+                    return res.context("sequencer withdrawal event handler exited")?;
+                }
+                res = &mut ics20_withdrawal_event_handler => {
+                    info!("ics20 withdrawal event handler exited");
+                    return res.context("ics20 withdrawal event handler exited")?;
+                }
+               () = shutdown_token.cancelled() => {
+                    info!("watcher shutting down");
+                    return Ok(());
+                }
+            };
+            sequencer_withdrawal_event_handler.abort();
+            ics20_withdrawal_event_handler.abort();
+
+            match exit {
+                BatcherExit::Stopped => {
+                    info!("batcher exited");
+                    return Ok(());
+                }
+                BatcherExit::Stalled => {
+                    warn!(
+                        from_block,
+                        "reconnecting to the rollup and resubscribing to blocks and withdrawal \
+                         events",
+                    );
+                    provider = Arc::new(
+                        connect_to_rollup(&ethereum_rpc_endpoint)
+                            .await
+                            .wrap_err("failed to reconnect to the rollup")?,
+                    );
+                    contract = IAstriaWithdrawer::new(contract_address, provider.clone());
+                }
             }
         }
     }
 
     /// Gets the startup data from the submitter and connects to the Ethereum node.
     ///
-    /// Returns the contract handle, the asset ID of the fee asset, the divisor for the asset
-    /// withdrawal amount, and the rollup block height to watch from.
+    /// Returns the contract handle, the asset ID of the fee asset, the number of decimals of the
+    /// asset on the sequencer, the divisor for the asset withdrawal amount, and the rollup block
+    /// height to watch from.
     ///
     /// # Errors
     /// - If the fee asset ID provided in the config is not a valid fee asset on the sequencer.
@@ -192,48 +274,25 @@ impl Watcher {
         Arc<Provider<Ws>>,
         IAstriaWithdrawer<Provider<Ws>>,
         asset::Id,
+        u32,
         u128,
         u64,
     )> {
-        // wait for submitter to be ready
+        // wait for submitter to be ready, unless the startup info was given directly
         let SequencerStartupInfo {
             fee_asset_id,
             next_batch_rollup_height,
-        } = self
-            .submitter_handle
-            .recv_startup_info()
-            .await
-            .wrap_err("failed to get sequencer startup info")?;
+        } = match self.startup_info.take() {
+            Some(startup_info) => startup_info,
+            None => self
+                .submitter_handle
+                .recv_startup_info()
+                .await
+                .wrap_err("failed to get sequencer startup info")?,
+        };
 
         // connect to eth node
-        let retry_config = tryhard::RetryFutureConfig::new(1024)
-            .exponential_backoff(Duration::from_millis(500))
-            .max_delay(Duration::from_secs(60))
-            .on_retry(
-                |attempt, next_delay: Option<Duration>, error: &ProviderError| {
-                    let wait_duration = next_delay
-                        .map(humantime::format_duration)
-                        .map(tracing::field::display);
-                    warn!(
-                        attempt,
-                        wait_duration,
-                        error = error as &dyn std::error::Error,
-                        "attempt to connect to rollup node failed; retrying after backoff",
-                    );
-                    futures::future::ready(())
-                },
-            );
-
-        let provider = tryhard::retry_fn(|| {
-            let url = self.ethereum_rpc_endpoint.clone();
-            async move {
-                let websocket_client = Ws::connect_with_reconnects(url, 0).await?;
-                Ok(Provider::new(websocket_client))
-            }
-        })
-        .with_config(retry_config)
-        .await
-        .wrap_err("failed connecting to rollup after several retries; giving up")?;
+        let provider = connect_to_rollup(&self.ethereum_rpc_endpoint).await?;
         let provider = Arc::new(provider);
 
         // get contract handle
@@ -257,12 +316,48 @@ impl Watcher {
             provider.clone(),
             contract,
             fee_asset_id,
+            base_chain_asset_precision,
             asset_withdrawal_divisor,
             next_batch_rollup_height,
         ))
     }
 }
 
+/// Connects to the rollup node at `ethereum_rpc_endpoint`, retrying with exponential backoff.
+///
+/// # Errors
+/// Returns an error if the rollup node cannot be connected to after several retries.
+async fn connect_to_rollup(ethereum_rpc_endpoint: &str) -> eyre::Result<Provider<Ws>> {
+    let retry_config = tryhard::RetryFutureConfig::new(1024)
+        .exponential_backoff(Duration::from_millis(500))
+        .max_delay(Duration::from_secs(60))
+        .on_retry(
+            |attempt, next_delay: Option<Duration>, error: &ProviderError| {
+                let wait_duration = next_delay
+                    .map(humantime::format_duration)
+                    .map(tracing::field::display);
+                warn!(
+                    attempt,
+                    wait_duration,
+                    error = error as &dyn std::error::Error,
+                    "attempt to connect to rollup node failed; retrying after backoff",
+                );
+                futures::future::ready(())
+            },
+        );
+
+    tryhard::retry_fn(|| {
+        let url = ethereum_rpc_endpoint.to_string();
+        async move {
+            let websocket_client = Ws::connect_with_reconnects(url, 0).await?;
+            Ok(Provider::new(websocket_client))
+        }
+    })
+    .with_config(retry_config)
+    .await
+    .wrap_err("failed connecting to rollup after several retries; giving up")
+}
+
 async fn watch_for_sequencer_withdrawal_events(
     contract: IAstriaWithdrawer<Provider<Ws>>,
     event_tx: mpsc::Sender<(WithdrawalEvent, LogMeta)>,
@@ -323,94 +418,172 @@ async fn watch_for_ics20_withdrawal_events(
     Ok(())
 }
 
+/// Why [`Batcher::run`] returned.
+#[derive(Debug, PartialEq, Eq)]
+enum BatcherExit {
+    /// Shutdown was requested, or one of the batcher's inputs was closed.
+    Stopped,
+    /// No new rollup block was observed within the stall threshold.
+    Stalled,
+}
+
 struct Batcher {
     event_rx: mpsc::Receiver<(WithdrawalEvent, LogMeta)>,
-    provider: Arc<Provider<Ws>>,
     submitter_handle: submitter::Handle,
     shutdown_token: CancellationToken,
     fee_asset_id: asset::Id,
     rollup_asset_denom: Denom,
     bridge_address: Address,
+    base_chain_asset_precision: u32,
     asset_withdrawal_divisor: u128,
+    stall_watchdog: Option<StallWatchdog>,
+    processed_events: ProcessedEvents,
+    /// The withdrawals of the rollup height currently being read, kept across resubscriptions.
+    curr_batch: Batch,
+    state: Arc<State>,
 }
 
 impl Batcher {
-    pub(crate) async fn run(mut self) -> Result<()> {
-        let mut block_rx = self
-            .provider
-            .subscribe_blocks()
-            .await
-            .wrap_err("failed to subscribe to blocks")?;
-
-        let mut curr_batch = Batch {
-            actions: Vec::new(),
-            rollup_height: 0,
-        };
+    /// Batches withdrawal events by rollup height and sends every completed batch to the
+    /// submitter, using `blocks` to learn when no more events can arrive for a rollup height.
+    ///
+    /// Returns [`BatcherExit::Stalled`] if the stall watchdog fires, so that the caller can
+    /// resubscribe and call this method again with a fresh stream of blocks. The batch being
+    /// assembled is kept across calls.
+    async fn run<S>(&mut self, blocks: S) -> Result<BatcherExit>
+    where
+        S: Stream<Item = Block<H256>>,
+    {
+        let mut blocks = std::pin::pin!(blocks);
+        if let Some(stall_watchdog) = self.stall_watchdog.as_mut() {
+            stall_watchdog.reset();
+        }
 
         loop {
             select! {
                 () = self.shutdown_token.cancelled() => {
                     info!("batcher shutting down");
-                    break;
+                    return Ok(BatcherExit::Stopped);
                 }
-                block = block_rx.next() => {
+                () = watchdog::stalled(self.stall_watchdog.as_mut()) => {
+                    let threshold = self
+                        .stall_watchdog
+                        .as_ref()
+                        .map(StallWatchdog::threshold)
+                        .unwrap_or_default();
+                    let last_progress = self
+                        .state
+                        .subscribe()
+                        .borrow()
+                        .watcher_last_progress()
+                        .map_or_else(
+                            || "never".to_string(),
+                            |time| humantime::format_rfc3339(time).to_string(),
+                        );
+                    warn!(
+                        threshold = %humantime::format_duration(threshold),
+                        last_progress,
+                        "no new rollup block observed within threshold; the rollup provider \
+                         appears stalled",
+                    );
+                    return Ok(BatcherExit::Stalled);
+                }
+                block = blocks.next() => {
                     if let Some(Block { number, .. }) = block {
+                        if let Some(stall_watchdog) = self.stall_watchdog.as_mut() {
+                            stall_watchdog.reset();
+                        }
+                        self.state.set_watcher_last_progress(std::time::SystemTime::now());
+                        self.prune_processed_events()?;
+
                         let Some(block_number) = number else {
                             // don't think this should happen
                             warn!("block number missing; skipping");
                             continue;
                         };
 
-                        if block_number.as_u64() > curr_batch.rollup_height {
-                            if !curr_batch.actions.is_empty() {
-                                self.submitter_handle.send_batch(curr_batch)
+                        if block_number.as_u64() > self.curr_batch.rollup_height {
+                            let full_batch = std::mem::replace(&mut self.curr_batch, Batch {
+                                actions: Vec::new(),
+                                rollup_height: block_number.as_u64(),
+                            });
+                            if !full_batch.actions.is_empty() {
+                                self.submitter_handle.send_batch(full_batch)
                                     .await
                                     .wrap_err("failed to send batched events; receiver dropped?")?;
                             }
-
-                            curr_batch = Batch {
-                                actions: Vec::new(),
-                                rollup_height: block_number.as_u64(),
-                            };
                         }
                     } else {
                         error!("block stream closed; shutting down batcher");
-                        break;
+                        return Ok(BatcherExit::Stopped);
                     }
                 }
                 item = self.event_rx.recv() => {
                     if let Some((event, meta)) = item {
+                        if !self
+                            .processed_events
+                            .insert(&meta)
+                            .wrap_err("failed to record withdrawal event as processed")?
+                        {
+                            warn!(
+                                rollup_height = meta.block_number.as_u64(),
+                                transaction_hash = %meta.transaction_hash,
+                                log_index = %meta.log_index,
+                                "skipping withdrawal event that was already processed",
+                            );
+                            continue;
+                        }
                         let event_with_metadata = EventWithMetadata {
                             event,
                             block_number: meta.block_number,
                             transaction_hash: meta.transaction_hash,
                         };
                         let action = event_to_action(event_with_metadata, self.fee_asset_id, self.rollup_asset_denom.clone(), self.asset_withdrawal_divisor, self.bridge_address).wrap_err("failed to convert event to action")?;
+                        if let Some(amount) = withdrawal_amount(&action) {
+                            debug!(
+                                rollup_height = meta.block_number.as_u64(),
+                                amount = format_amount(amount, self.base_chain_asset_precision).as_deref().unwrap_or("<overflow>"),
+                                asset = %self.rollup_asset_denom,
+                                "parsed withdrawal event",
+                            );
+                        }
 
-                        if meta.block_number.as_u64() == curr_batch.rollup_height {
+                        if meta.block_number.as_u64() == self.curr_batch.rollup_height {
                             // block number was the same; add event to current batch
-                            curr_batch.actions.push(action);
+                            self.curr_batch.actions.push(action);
                         } else {
                             // block number increased; send current batch and start a new one
-                            if !curr_batch.actions.is_empty() {
-                                self.submitter_handle.send_batch(curr_batch)
+                            let full_batch = std::mem::replace(&mut self.curr_batch, Batch {
+                                actions: vec![action],
+                                rollup_height: meta.block_number.as_u64(),
+                            });
+                            if !full_batch.actions.is_empty() {
+                                self.submitter_handle.send_batch(full_batch)
                                     .await
                                     .wrap_err("failed to send batched events; receiver dropped?")?;
                             }
-
-                            curr_batch = Batch {
-                                actions: vec![action],
-                                rollup_height: meta.block_number.as_u64(),
-                            };
                         }
                     } else {
                         error!("event receiver dropped; shutting down batcher");
-                        break;
+                        return Ok(BatcherExit::Stopped);
                     }
                 }
             }
         }
+    }
 
+    /// Prunes processed events at rollup heights that were already submitted to the sequencer.
+    fn prune_processed_events(&mut self) -> Result<()> {
+        let last_submitted = self
+            .state
+            .subscribe()
+            .borrow()
+            .last_rollup_height_submitted();
+        if let Some(height) = last_submitted {
+            self.processed_events
+                .prune_below(height.saturating_add(1))
+                .wrap_err("failed to prune processed withdrawal events")?;
+        }
         Ok(())
     }
 }
@@ -449,6 +622,7 @@ mod tests {
         },
         utils::hex,
     };
+    use futures::StreamExt as _;
     use tokio::sync::oneshot;
 
     use super::*;
@@ -466,6 +640,89 @@ mod tests {
         },
     };
 
+    const STALL_THRESHOLD: Duration = Duration::from_secs(10);
+    const BLOCK_TIME: Duration = Duration::from_secs(2);
+
+    /// Returns a batcher with a stall watchdog, together with the sender of its withdrawal events,
+    /// which must be kept alive for the batcher to keep running.
+    fn make_batcher() -> (Batcher, mpsc::Sender<(WithdrawalEvent, LogMeta)>) {
+        let (event_tx, event_rx) = mpsc::channel(100);
+        let (batch_tx, _) = mpsc::channel(100);
+        let denom = default_native_asset();
+        let batcher = Batcher {
+            event_rx,
+            submitter_handle: submitter::Handle::new(None, batch_tx),
+            shutdown_token: CancellationToken::new(),
+            fee_asset_id: denom.id(),
+            rollup_asset_denom: denom,
+            bridge_address: crate::astria_address([1u8; 20]),
+            base_chain_asset_precision: 18,
+            asset_withdrawal_divisor: 1,
+            stall_watchdog: Some(StallWatchdog::new(STALL_THRESHOLD)),
+            processed_events: ProcessedEvents::new(0),
+            curr_batch: Batch {
+                actions: Vec::new(),
+                rollup_height: 0,
+            },
+            state: Arc::new(State::new()),
+        };
+        (batcher, event_tx)
+    }
+
+    /// Returns a stream of rollup blocks `numbers`, one every `BLOCK_TIME`.
+    fn blocks_every_block_time(
+        numbers: std::ops::RangeInclusive<u64>,
+    ) -> impl Stream<Item = Block<H256>> {
+        futures::stream::iter(numbers).then(|number| async move {
+            tokio::time::sleep(BLOCK_TIME).await;
+            Block {
+                number: Some(number.into()),
+                ..Block::default()
+            }
+        })
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn batcher_reports_stall_after_provider_stops_advancing() {
+        let (mut batcher, _event_tx) = make_batcher();
+        let state = batcher.state.subscribe();
+
+        // a provider that produces three blocks and then silently stalls
+        let blocks = blocks_every_block_time(1..=3).chain(futures::stream::pending());
+        let started = tokio::time::Instant::now();
+        let exit = batcher.run(blocks).await.unwrap();
+
+        assert_eq!(BatcherExit::Stalled, exit);
+        // three blocks were observed, after which the watchdog waited for the full threshold
+        assert!(started.elapsed() >= Duration::from_secs(16));
+        assert!(state.borrow().watcher_last_progress().is_some());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn batcher_does_not_report_stall_while_provider_advances() {
+        let (mut batcher, _event_tx) = make_batcher();
+
+        // the stream ends after the last block, which stops the batcher without a stall
+        let exit = batcher.run(blocks_every_block_time(1..=10)).await.unwrap();
+
+        assert_eq!(BatcherExit::Stopped, exit);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn batcher_can_be_run_again_after_stall() {
+        let (mut batcher, _event_tx) = make_batcher();
+
+        let stalled = blocks_every_block_time(1..=3).chain(futures::stream::pending());
+        assert_eq!(BatcherExit::Stalled, batcher.run(stalled).await.unwrap());
+
+        // the watchdog is rearmed for the resubscribed stream instead of firing immediately
+        let resubscribed = blocks_every_block_time(4..=6);
+        assert_eq!(
+            BatcherExit::Stopped,
+            batcher.run(resubscribed).await.unwrap()
+        );
+    }
+
     #[test]
     fn address_from_string_prefix() {
         let address = address_from_string("0x1234567890123456789012345678901234567890").unwrap();
@@ -570,7 +827,7 @@ mod tests {
 
         let (batch_tx, mut batch_rx) = mpsc::channel(100);
         let (startup_tx, startup_rx) = oneshot::channel();
-        let submitter_handle = submitter::Handle::new(startup_rx, batch_tx);
+        let submitter_handle = submitter::Handle::new(Some(startup_rx), batch_tx);
         startup_tx
             .send(SequencerStartupInfo {
                 fee_asset_id: denom.id(),
@@ -586,6 +843,9 @@ mod tests {
             state: Arc::new(State::new()),
             rollup_asset_denom: denom,
             bridge_address,
+            stall_threshold: None,
+            processed_events_path: None,
+            startup_info: None,
         }
         .build()
         .unwrap();
@@ -595,7 +855,7 @@ mod tests {
         // make another tx to trigger anvil to make another block
         send_sequencer_withdraw_transaction(&contract, value, recipient).await;
 
-        let batch = batch_rx.recv().await.unwrap();
+        let batch = batch_rx.recv().await.unwrap().batch;
         assert_eq!(batch.actions.len(), 1);
         let Action::BridgeUnlock(action) = &batch.actions[0] else {
             panic!(
@@ -662,7 +922,7 @@ mod tests {
 
         let (batch_tx, mut batch_rx) = mpsc::channel(100);
         let (startup_tx, startup_rx) = oneshot::channel();
-        let submitter_handle = submitter::Handle::new(startup_rx, batch_tx);
+        let submitter_handle = submitter::Handle::new(Some(startup_rx), batch_tx);
         startup_tx
             .send(SequencerStartupInfo {
                 fee_asset_id: denom.id(),
@@ -678,6 +938,9 @@ mod tests {
             state: Arc::new(State::new()),
             rollup_asset_denom: denom,
             bridge_address,
+            stall_threshold: None,
+            processed_events_path: None,
+            startup_info: None,
         }
         .build()
         .unwrap();
@@ -687,7 +950,7 @@ mod tests {
         // make another tx to trigger anvil to make another block
         send_ics20_withdraw_transaction(&contract, value, recipient).await;
 
-        let mut batch = batch_rx.recv().await.unwrap();
+        let mut batch = batch_rx.recv().await.unwrap().batch;
         assert_eq!(batch.actions.len(), 1);
         let Action::Ics20Withdrawal(ref mut action) = batch.actions[0] else {
             panic!(
@@ -784,7 +1047,7 @@ mod tests {
 
         let (batch_tx, mut batch_rx) = mpsc::channel(100);
         let (startup_tx, startup_rx) = oneshot::channel();
-        let submitter_handle = submitter::Handle::new(startup_rx, batch_tx);
+        let submitter_handle = submitter::Handle::new(Some(startup_rx), batch_tx);
         startup_tx
             .send(SequencerStartupInfo {
                 fee_asset_id: denom.id(),
@@ -800,6 +1063,9 @@ mod tests {
             state: Arc::new(State::new()),
             rollup_asset_denom: denom,
             bridge_address,
+            stall_threshold: None,
+            processed_events_path: None,
+            startup_info: None,
         }
         .build()
         .unwrap();
@@ -809,7 +1075,7 @@ mod tests {
         // make another tx to trigger anvil to make another block
         send_sequencer_withdraw_transaction_erc20(&contract, value, recipient).await;
 
-        let batch = batch_rx.recv().await.unwrap();
+        let batch = batch_rx.recv().await.unwrap().batch;
         assert_eq!(batch.actions.len(), 1);
         let Action::BridgeUnlock(action) = &batch.actions[0] else {
             panic!(
@@ -886,7 +1152,7 @@ mod tests {
 
         let (batch_tx, mut batch_rx) = mpsc::channel(100);
         let (startup_tx, startup_rx) = oneshot::channel();
-        let submitter_handle = submitter::Handle::new(startup_rx, batch_tx);
+        let submitter_handle = submitter::Handle::new(Some(startup_rx), batch_tx);
         startup_tx
             .send(SequencerStartupInfo {
                 fee_asset_id: asset::Id::from_str_unchecked("transfer/channel-0/utia"),
@@ -902,6 +1168,9 @@ mod tests {
             state: Arc::new(State::new()),
             rollup_asset_denom: denom,
             bridge_address,
+            stall_threshold: None,
+            processed_events_path: None,
+            startup_info: None,
         }
         .build()
         .unwrap();
@@ -911,7 +1180,7 @@ mod tests {
         // make another tx to trigger anvil to make another block
         send_ics20_withdraw_transaction_astria_bridgeable_erc20(&contract, value, recipient).await;
 
-        let mut batch = batch_rx.recv().await.unwrap();
+        let mut batch = batch_rx.recv().await.unwrap().batch;
         assert_eq!(batch.actions.len(), 1);
         let Action::Ics20Withdrawal(ref mut action) = batch.actions[0] else {
             panic!(
@@ -33,7 +33,10 @@ use tracing::{
     info,
 };
 
-pub(crate) use self::state::StateSnapshot;
+pub(crate) use self::state::{
+    DeadletteredBatch,
+    StateSnapshot,
+};
 use self::{
     ethereum::watcher,
     state::State,
@@ -80,23 +83,61 @@ impl BridgeWithdrawer {
             ethereum_rpc_endpoint,
             rollup_asset_denomination,
             min_expected_fee_asset_balance,
+            min_batch_size,
+            max_batch_size,
+            startup_max_attempts,
+            startup_retry_backoff_ms,
+            no_sequencer_startup,
+            start_rollup_height,
+            watcher_stall_threshold_ms,
+            audit_log_path,
+            deadletter_log_path,
+            destination_withdrawal_cap,
+            destination_withdrawal_cap_window_ms,
+            deferred_withdrawals_path,
+            processed_events_path,
             ..
         } = cfg;
 
         let state = Arc::new(State::new());
 
+        let rollup_asset_denom = rollup_asset_denomination
+            .parse::<Denom>()
+            .wrap_err("failed to parse ROLLUP_ASSET_DENOMINATION as Denom")?;
+
+        let fee_asset_id = asset::Id::from_str_unchecked(&fee_asset_denomination);
+
         // make submitter object
-        let (submitter, submitter_handle) = submitter::Builder {
+        let submitter_builder = submitter::Builder {
             shutdown_token: shutdown_handle.token(),
             sequencer_cometbft_endpoint,
             sequencer_chain_id,
             sequencer_key_path,
+            startup_max_attempts,
+            startup_retry_backoff: Duration::from_millis(startup_retry_backoff_ms),
             state: state.clone(),
-            expected_fee_asset_id: asset::Id::from_str_unchecked(&fee_asset_denomination),
+            expected_fee_asset_id: fee_asset_id,
             min_expected_fee_asset_balance: u128::from(min_expected_fee_asset_balance),
+            min_batch_size,
+            max_batch_size,
+            audit_log_path: (!audit_log_path.is_empty()).then_some(audit_log_path),
+            deadletter_log_path: (!deadletter_log_path.is_empty()).then_some(deadletter_log_path),
+            rollup_asset_denom: rollup_asset_denom.clone(),
+            destination_withdrawal_cap: (destination_withdrawal_cap > 0)
+                .then(|| u128::from(destination_withdrawal_cap)),
+            destination_withdrawal_cap_window: Duration::from_millis(
+                destination_withdrawal_cap_window_ms,
+            ),
+            deferred_withdrawals_path: (!deferred_withdrawals_path.is_empty())
+                .then_some(deferred_withdrawals_path),
+            clock: None,
             metrics,
+        };
+        let (submitter, submitter_handle) = if no_sequencer_startup {
+            submitter_builder.build_without_startup()
+        } else {
+            submitter_builder.build()
         }
-        .build()
         .wrap_err("failed to initialize submitter")?;
 
         let sequencer_bridge_address = Address::try_from_bech32m(&cfg.sequencer_bridge_address)
@@ -108,10 +149,16 @@ impl BridgeWithdrawer {
             submitter_handle,
             shutdown_token: shutdown_handle.token(),
             state: state.clone(),
-            rollup_asset_denom: rollup_asset_denomination
-                .parse::<Denom>()
-                .wrap_err("failed to parse ROLLUP_ASSET_DENOMINATION as Denom")?,
+            rollup_asset_denom,
             bridge_address: sequencer_bridge_address,
+            stall_threshold: (watcher_stall_threshold_ms > 0)
+                .then(|| Duration::from_millis(watcher_stall_threshold_ms)),
+            processed_events_path: (!processed_events_path.is_empty())
+                .then_some(processed_events_path),
+            startup_info: no_sequencer_startup.then_some(SequencerStartupInfo {
+                fee_asset_id,
+                next_batch_rollup_height: start_rollup_height,
+            }),
         }
         .build()
         .wrap_err("failed to build ethereum watcher")?;
@@ -1,9 +1,17 @@
+use std::time::SystemTime;
+
 use tokio::sync::watch;
 
 pub(crate) struct State {
     inner: tokio::sync::watch::Sender<StateSnapshot>,
 }
 
+// The state is shared between the watcher, the submitter, and the API server through an `Arc`.
+const _: () = {
+    const fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<State>();
+};
+
 impl State {
     pub(super) fn new() -> Self {
         let (inner, _) = watch::channel(StateSnapshot::default());
@@ -20,6 +28,10 @@ impl State {
         self.inner.send_modify(StateSnapshot::set_submitter_ready);
     }
 
+    pub(super) fn push_deadletter(&self, batch: DeadletteredBatch) {
+        self.inner.send_modify(|state| state.deadletter.push(batch));
+    }
+
     pub(super) fn subscribe(&self) -> watch::Receiver<StateSnapshot> {
         self.inner.subscribe()
     }
@@ -43,6 +55,7 @@ forward_setter!(
     [set_last_rollup_height_submitted <- u64],
     [set_last_sequencer_height <- u64],
     [set_last_sequencer_tx_hash <- tendermint::Hash],
+    [set_watcher_last_progress <- SystemTime],
 );
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize)]
@@ -55,6 +68,26 @@ pub(crate) struct StateSnapshot {
     last_rollup_height_submitted: Option<u64>,
     last_sequencer_block: Option<u64>,
     last_sequencer_tx_hash: Option<tendermint::Hash>,
+
+    /// When the watcher last observed a new rollup block.
+    watcher_last_progress: Option<SystemTime>,
+
+    deadletter: Vec<DeadletteredBatch>,
+}
+
+/// A summary of a batch that was permanently rejected by the sequencer and is not resubmitted.
+///
+/// This is only kept in memory; the individual withdrawals are recorded in the deadletter log.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
+pub(crate) struct DeadletteredBatch {
+    /// The hex-encoded idempotency id of the batch.
+    pub(crate) batch_id: String,
+    /// The rollup block height the batch was derived from.
+    pub(crate) rollup_height: u64,
+    /// The number of actions in the batch that were not executed.
+    pub(crate) unexecuted_actions: usize,
+    /// Why the batch was rejected.
+    pub(crate) reason: String,
 }
 
 impl StateSnapshot {
@@ -74,6 +107,21 @@ impl StateSnapshot {
         self.sequencer_connected
     }
 
+    /// Returns when the watcher last observed a new rollup block, if it observed any.
+    pub(crate) fn watcher_last_progress(&self) -> Option<SystemTime> {
+        self.watcher_last_progress
+    }
+
+    /// Returns the last rollup height whose withdrawals were submitted to the sequencer.
+    pub(crate) fn last_rollup_height_submitted(&self) -> Option<u64> {
+        self.last_rollup_height_submitted
+    }
+
+    /// Returns the batches that were permanently rejected by the sequencer, oldest first.
+    pub(crate) fn deadletter(&self) -> &[DeadletteredBatch] {
+        &self.deadletter
+    }
+
     /// Sets the sequencer connection status to `connected`.
     fn set_sequencer_connected(&mut self, connected: bool) -> bool {
         let changed = self.sequencer_connected ^ connected;
@@ -100,4 +148,10 @@ impl StateSnapshot {
         self.last_sequencer_tx_hash = Some(hash);
         changed
     }
+
+    fn set_watcher_last_progress(&mut self, time: SystemTime) -> bool {
+        let changed = self.watcher_last_progress.map_or(true, |t| t != time);
+        self.watcher_last_progress = Some(time);
+        changed
+    }
 }
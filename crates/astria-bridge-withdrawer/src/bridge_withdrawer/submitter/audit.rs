@@ -0,0 +1,256 @@
+//! Append-only trails of the withdrawals executed on, or deadlettered before reaching, the
+//! sequencer.
+//!
+//! The [`AuditLog`] writes one JSON object per line (JSONL) for every withdrawal action in a
+//! transaction that was executed in a sequencer block. The [`DeadletterLog`] does the same for
+//! every withdrawal that the submitter gave up on. Fields are always written in the order they
+//! are declared in [`AuditRecord`] and [`DeadletterRecord`], so the format is stable across
+//! releases and can be consumed by line-oriented tooling.
+
+use std::{
+    fs::{
+        File,
+        OpenOptions,
+    },
+    io::{
+        BufWriter,
+        Write as _,
+    },
+    path::Path,
+};
+
+use astria_core::{
+    bridge::Ics20WithdrawalFromRollupMemo,
+    primitive::v1::asset::Denom,
+    protocol::transaction::v1alpha1::Action,
+};
+use astria_eyre::eyre::{
+    self,
+    WrapErr as _,
+};
+use serde::Serialize;
+use tracing::error;
+
+use crate::bridge_withdrawer::ethereum::convert::BridgeUnlockMemo;
+
+/// A single withdrawal as it was read from the rollup.
+#[derive(Debug, Serialize)]
+struct Withdrawal {
+    /// The rollup height at which the withdrawal was made.
+    rollup_height: u64,
+    /// The hex-encoded hash of the rollup transaction that emitted the withdrawal event.
+    rollup_transaction_hash: String,
+    /// Either `bridge_unlock` or `ics20_withdrawal`.
+    kind: &'static str,
+    /// The withdrawn amount as a decimal string, so that it survives JSON parsers that read all
+    /// numbers as 64 bit floats.
+    amount: String,
+    asset: String,
+    /// The sequencer address for bridge unlocks, or the address on the destination chain for
+    /// ICS20 withdrawals.
+    destination: String,
+}
+
+impl Withdrawal {
+    /// Returns the withdrawal `action`, or `None` if `action` is not a withdrawal.
+    ///
+    /// `rollup_asset` is recorded as the asset of bridge unlocks, which do not carry their asset.
+    fn from_action(action: &Action, rollup_asset: &Denom) -> eyre::Result<Option<Self>> {
+        let (rollup_height, rollup_transaction_hash, kind, amount, asset, destination) =
+            match action {
+                Action::BridgeUnlock(action) => {
+                    let memo: BridgeUnlockMemo = serde_json::from_slice(&action.memo)
+                        .wrap_err("failed to parse memo of bridge unlock action")?;
+                    (
+                        memo.block_number.as_u64(),
+                        hex::encode(memo.transaction_hash),
+                        "bridge_unlock",
+                        action.amount,
+                        rollup_asset.to_string(),
+                        action.to.to_string(),
+                    )
+                }
+                Action::Ics20Withdrawal(action) => {
+                    let memo: Ics20WithdrawalFromRollupMemo = serde_json::from_str(&action.memo)
+                        .wrap_err("failed to parse memo of ics20 withdrawal action")?;
+                    (
+                        memo.block_number,
+                        hex::encode(memo.transaction_hash),
+                        "ics20_withdrawal",
+                        action.amount(),
+                        action.denom().to_string(),
+                        action.destination_chain_address.clone(),
+                    )
+                }
+                _ => return Ok(None),
+            };
+        Ok(Some(Self {
+            rollup_height,
+            rollup_transaction_hash,
+            kind,
+            amount: amount.to_string(),
+            asset,
+            destination,
+        }))
+    }
+}
+
+/// A single executed withdrawal.
+#[derive(Debug, Serialize)]
+struct AuditRecord {
+    #[serde(flatten)]
+    withdrawal: Withdrawal,
+    /// The sequencer height at which the withdrawal was executed.
+    sequencer_height: u64,
+    /// The hex-encoded hash of the sequencer transaction that executed the withdrawal.
+    sequencer_transaction_hash: String,
+}
+
+/// A single withdrawal that was not executed and that the submitter gave up on.
+#[derive(Debug, Serialize)]
+struct DeadletterRecord {
+    #[serde(flatten)]
+    withdrawal: Withdrawal,
+    /// The hex-encoded idempotency id of the batch the withdrawal was part of.
+    batch_id: String,
+    /// Why the withdrawal was not executed.
+    reason: String,
+}
+
+/// Opens the JSONL file at `path` for appending, creating it if it does not exist.
+fn open_for_append(path: &Path) -> eyre::Result<BufWriter<File>> {
+    let file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .wrap_err_with(|| format!("failed to open file at path: {}", path.display()))?;
+    Ok(BufWriter::new(file))
+}
+
+/// Writes `record` to `writer` as a single line of JSON.
+fn write_line<T: Serialize>(writer: &mut BufWriter<File>, record: &T) -> eyre::Result<()> {
+    serde_json::to_writer(&mut *writer, record).wrap_err("failed to serialize record")?;
+    writer
+        .write_all(b"\n")
+        .wrap_err("failed to write line terminator")
+}
+
+/// A JSONL file that [`AuditRecord`]s are appended to.
+pub(crate) struct AuditLog {
+    writer: BufWriter<File>,
+    rollup_asset: Denom,
+}
+
+impl AuditLog {
+    /// Opens the audit log at `path` for appending, creating it if it does not exist.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be opened for appending.
+    pub(crate) fn open<P: AsRef<Path>>(path: P, rollup_asset: Denom) -> eyre::Result<Self> {
+        let writer = open_for_append(path.as_ref()).wrap_err("failed to open audit log")?;
+        Ok(Self {
+            writer,
+            rollup_asset,
+        })
+    }
+
+    /// Appends one record for every withdrawal in `actions`, which were executed in the sequencer
+    /// transaction `sequencer_transaction_hash` at `sequencer_height`.
+    ///
+    /// The records are flushed to the file before this method returns.
+    ///
+    /// # Errors
+    /// Returns an error if the memo of a withdrawal cannot be parsed, or if writing to or flushing
+    /// the file fails.
+    pub(crate) fn append(
+        &mut self,
+        actions: &[Action],
+        sequencer_height: u64,
+        sequencer_transaction_hash: &[u8],
+    ) -> eyre::Result<()> {
+        for action in actions {
+            let Some(withdrawal) = Withdrawal::from_action(action, &self.rollup_asset)? else {
+                continue;
+            };
+            let record = AuditRecord {
+                withdrawal,
+                sequencer_height,
+                sequencer_transaction_hash: hex::encode(sequencer_transaction_hash),
+            };
+            write_line(&mut self.writer, &record)
+                .wrap_err("failed to write record to audit log")?;
+        }
+        self.writer.flush().wrap_err("failed to flush audit log")
+    }
+}
+
+/// Records the withdrawals that the submitter gave up on.
+///
+/// Deadlettered withdrawals are not resubmitted while the withdrawer keeps running. After a
+/// restart, the submitter resumes from the rollup height of the bridge account's last executed
+/// transaction, so withdrawals deadlettered after that transaction are read from the rollup and
+/// submitted again; if they are rejected again, they are recorded again. Every deadlettered
+/// withdrawal that is not executed after a restart requires operator action: either submitting it
+/// to the sequencer by other means or refunding it on the rollup. To not lose track of them, every
+/// record is emitted as an error event and, if a path was configured, appended to a JSONL file.
+pub(crate) struct DeadletterLog {
+    writer: Option<BufWriter<File>>,
+    rollup_asset: Denom,
+}
+
+impl DeadletterLog {
+    /// Opens the deadletter log at `path` for appending, creating it if it does not exist. If
+    /// `path` is `None`, records are only emitted as error events.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be opened for appending.
+    pub(crate) fn open<P: AsRef<Path>>(path: Option<P>, rollup_asset: Denom) -> eyre::Result<Self> {
+        let writer = path
+            .map(|path| open_for_append(path.as_ref()))
+            .transpose()
+            .wrap_err("failed to open deadletter log")?;
+        Ok(Self {
+            writer,
+            rollup_asset,
+        })
+    }
+
+    /// Records every withdrawal in `actions`, which were part of the batch `batch_id` and will
+    /// not be executed because of `reason`.
+    ///
+    /// The records are flushed to the file before this method returns.
+    ///
+    /// # Errors
+    /// Returns an error if the memo of a withdrawal cannot be parsed, or if writing to or flushing
+    /// the file fails.
+    pub(crate) fn append(
+        &mut self,
+        actions: &[Action],
+        batch_id: &[u8],
+        reason: &str,
+    ) -> eyre::Result<()> {
+        for action in actions {
+            let Some(withdrawal) = Withdrawal::from_action(action, &self.rollup_asset)? else {
+                continue;
+            };
+            let record = DeadletterRecord {
+                withdrawal,
+                batch_id: hex::encode(batch_id),
+                reason: reason.to_string(),
+            };
+            error!(
+                withdrawal = %serde_json::to_string(&record)
+                    .wrap_err("failed to serialize deadletter record")?,
+                "withdrawal was deadlettered and is not resubmitted; it requires operator \
+                 action",
+            );
+            if let Some(writer) = &mut self.writer {
+                write_line(writer, &record).wrap_err("failed to write record to deadletter log")?;
+            }
+        }
+        if let Some(writer) = &mut self.writer {
+            writer.flush().wrap_err("failed to flush deadletter log")?;
+        }
+        Ok(())
+    }
+}
@@ -1,21 +1,44 @@
-use std::sync::Arc;
+use std::{
+    sync::Arc,
+    time::Duration,
+};
 
-use astria_core::primitive::v1::asset;
+use astria_core::primitive::v1::asset::{
+    self,
+    Denom,
+};
 use astria_eyre::eyre::{
     self,
+    ensure,
     Context as _,
+    OptionExt as _,
 };
-use tokio::sync::{
-    mpsc,
-    oneshot,
+use tokio::{
+    sync::{
+        mpsc,
+        oneshot,
+    },
+    time::Instant,
 };
 use tokio_util::sync::CancellationToken;
-use tracing::info;
+use tracing::{
+    info,
+    warn,
+};
 
 use super::state::State;
 use crate::{
     bridge_withdrawer::{
-        submitter::Batch,
+        batch::BatchSizeTarget,
+        submitter::{
+            clock::{
+                Clock,
+                SystemClock,
+            },
+            deferred::DeferredStore,
+            destination_cap::DestinationCap,
+            Batch,
+        },
         SequencerStartupInfo,
     },
     metrics::Metrics,
@@ -23,35 +46,128 @@ use crate::{
 
 const BATCH_QUEUE_SIZE: usize = 256;
 
+/// How long [`Handle::send_batch`] waits for space in a full queue before logging a warning and
+/// waiting again.
+const SEND_BATCH_WARNING_INTERVAL: Duration = Duration::from_secs(30);
+
+/// A [`Batch`] waiting in the submitter's queue, together with the time it was enqueued.
+#[derive(Debug)]
+pub(crate) struct QueuedBatch {
+    pub(crate) batch: Batch,
+    pub(crate) enqueued_at: Instant,
+}
+
 pub(crate) struct Handle {
     startup_info_rx: Option<oneshot::Receiver<SequencerStartupInfo>>,
-    batches_tx: mpsc::Sender<Batch>,
+    batches_tx: mpsc::Sender<QueuedBatch>,
+    /// Stamps queued batches with the time they were enqueued.
+    clock: Arc<dyn Clock>,
 }
 
+// The handle is moved into the watcher task and used from the batcher it spawns.
+const _: () = {
+    const fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<Handle>();
+};
+
 impl Handle {
+    #[cfg(test)]
     pub(crate) fn new(
-        startup_info_rx: oneshot::Receiver<SequencerStartupInfo>,
-        batches_tx: mpsc::Sender<Batch>,
+        startup_info_rx: Option<oneshot::Receiver<SequencerStartupInfo>>,
+        batches_tx: mpsc::Sender<QueuedBatch>,
+    ) -> Self {
+        Self::with_clock(startup_info_rx, batches_tx, Arc::new(SystemClock))
+    }
+
+    fn with_clock(
+        startup_info_rx: Option<oneshot::Receiver<SequencerStartupInfo>>,
+        batches_tx: mpsc::Sender<QueuedBatch>,
+        clock: Arc<dyn Clock>,
     ) -> Self {
         Self {
-            startup_info_rx: Some(startup_info_rx),
+            startup_info_rx,
             batches_tx,
+            clock,
+        }
+    }
+
+    fn queue(&self, batch: Batch) -> QueuedBatch {
+        QueuedBatch {
+            batch,
+            enqueued_at: self.clock.now(),
         }
     }
 
+    /// Waits for the submitter to send the information collected during its startup.
+    ///
+    /// # Errors
+    /// - if the startup info was already taken, or the submitter was built without startup
+    /// - if the submitter was dropped before completing startup
     pub(crate) async fn recv_startup_info(&mut self) -> eyre::Result<SequencerStartupInfo> {
         self.startup_info_rx
             .take()
-            .expect("startup info should only be taken once - this is a bug")
+            .ok_or_eyre(
+                "startup info is not available; it was either already taken or the submitter was \
+                 built without startup",
+            )?
             .await
             .wrap_err("failed to get startup info from submitter. channel was dropped.")
     }
 
-    pub(crate) async fn send_batch(&self, batch: Batch) -> eyre::Result<()> {
+    /// Queues `batch` for submission to the sequencer, waiting for space in the queue for as long
+    /// as it takes.
+    ///
+    /// A warning is logged every [`SEND_BATCH_WARNING_INTERVAL`] that the queue stays full, so
+    /// that a submitter falling behind does not go unnoticed.
+    ///
+    /// # Errors
+    /// Returns the unsent batch inside the error if the submitter has stopped receiving batches,
+    /// so that the caller can persist or retry it instead of losing the withdrawals.
+    pub(crate) async fn send_batch(
+        &self,
+        mut batch: Batch,
+    ) -> Result<(), mpsc::error::SendError<Batch>> {
+        use mpsc::error::SendTimeoutError;
+        loop {
+            match self
+                .send_batch_timeout(batch, SEND_BATCH_WARNING_INTERVAL)
+                .await
+            {
+                Ok(()) => return Ok(()),
+                Err(SendTimeoutError::Timeout(unsent)) => {
+                    warn!(
+                        rollup_height = unsent.rollup_height,
+                        waited = %humantime::format_duration(SEND_BATCH_WARNING_INTERVAL),
+                        "submitter queue is full; still waiting to queue batch",
+                    );
+                    batch = unsent;
+                }
+                Err(SendTimeoutError::Closed(unsent)) => {
+                    return Err(mpsc::error::SendError(unsent));
+                }
+            }
+        }
+    }
+
+    /// Queues `batch` for submission to the sequencer, waiting at most `timeout` for space in the
+    /// queue.
+    ///
+    /// # Errors
+    /// Returns the unsent batch inside the error if the queue stays full for longer than
+    /// `timeout`, or if the submitter has stopped receiving batches.
+    pub(crate) async fn send_batch_timeout(
+        &self,
+        batch: Batch,
+        timeout: Duration,
+    ) -> Result<(), mpsc::error::SendTimeoutError<Batch>> {
+        use mpsc::error::SendTimeoutError;
         self.batches_tx
-            .send(batch)
+            .send_timeout(self.queue(batch), timeout)
             .await
-            .wrap_err("failed to send batch")
+            .map_err(|err| match err {
+                SendTimeoutError::Timeout(queued) => SendTimeoutError::Timeout(queued.batch),
+                SendTimeoutError::Closed(queued) => SendTimeoutError::Closed(queued.batch),
+            })
     }
 }
 
@@ -60,37 +176,107 @@ pub(crate) struct Builder {
     pub(crate) sequencer_key_path: String,
     pub(crate) sequencer_chain_id: String,
     pub(crate) sequencer_cometbft_endpoint: String,
+    /// The number of times the startup handshake is attempted before the submitter gives up.
+    pub(crate) startup_max_attempts: u32,
+    /// The delay before the first retry of the startup handshake, doubled after every failure.
+    pub(crate) startup_retry_backoff: Duration,
     pub(crate) state: Arc<State>,
     pub(crate) expected_fee_asset_id: asset::Id,
     pub(crate) min_expected_fee_asset_balance: u128,
+    pub(crate) min_batch_size: usize,
+    pub(crate) max_batch_size: usize,
+    /// The path of the JSONL file that executed withdrawals are appended to. No audit log is
+    /// written if `None`.
+    pub(crate) audit_log_path: Option<String>,
+    /// The path of the JSONL file that deadlettered withdrawals are appended to. Deadlettered
+    /// withdrawals are only logged if `None`.
+    pub(crate) deadletter_log_path: Option<String>,
+    /// The asset withdrawn from the rollup, recorded in the audit and deadletter logs.
+    pub(crate) rollup_asset_denom: Denom,
+    /// The maximum amount withdrawn to a single destination within
+    /// `destination_withdrawal_cap_window`. Withdrawals are not limited if `None`.
+    pub(crate) destination_withdrawal_cap: Option<u128>,
+    pub(crate) destination_withdrawal_cap_window: Duration,
+    /// The path of the file that withdrawals deferred by the destination cap are kept in across
+    /// restarts. Required if `destination_withdrawal_cap` is set.
+    pub(crate) deferred_withdrawals_path: Option<String>,
+    /// The source of the current time. The actual time is used if `None`.
+    pub(crate) clock: Option<Arc<dyn Clock>>,
     pub(crate) metrics: &'static Metrics,
 }
 
 impl Builder {
     /// Instantiates an `Submitter`.
+    ///
+    /// The submitter runs its startup before accepting batches and sends the collected
+    /// [`SequencerStartupInfo`] through the returned [`Handle`].
     pub(crate) fn build(self) -> eyre::Result<(super::Submitter, Handle)> {
+        self.build_inner(true)
+    }
+
+    /// Instantiates an `Submitter` that skips startup and is ready as soon as it runs.
+    ///
+    /// No configuration values are checked against the sequencer and no startup info is sent, so
+    /// [`Handle::recv_startup_info`] on the returned handle returns an error. The watcher must be
+    /// given its startup info directly instead.
+    pub(crate) fn build_without_startup(self) -> eyre::Result<(super::Submitter, Handle)> {
+        self.build_inner(false)
+    }
+
+    fn build_inner(self, with_startup: bool) -> eyre::Result<(super::Submitter, Handle)> {
         let Self {
             shutdown_token,
             sequencer_key_path,
             sequencer_chain_id,
             sequencer_cometbft_endpoint,
+            startup_max_attempts,
+            startup_retry_backoff,
             state,
             expected_fee_asset_id,
             min_expected_fee_asset_balance,
+            min_batch_size,
+            max_batch_size,
+            audit_log_path,
+            deadletter_log_path,
+            rollup_asset_denom,
+            destination_withdrawal_cap,
+            destination_withdrawal_cap_window,
+            deferred_withdrawals_path,
+            clock,
             metrics,
         } = self;
 
+        ensure!(
+            destination_withdrawal_cap.is_none() || deferred_withdrawals_path.is_some(),
+            "a destination withdrawal cap requires a deferred withdrawals path, so that deferred \
+             withdrawals are not lost on restart"
+        );
+
         let signer = super::signer::SequencerKey::try_from_path(sequencer_key_path)
             .wrap_err("failed to load sequencer private ky")?;
         info!(address = %telemetry::display::hex(&signer.address), "loaded sequencer signer");
 
+        let audit_log = audit_log_path
+            .map(|path| super::audit::AuditLog::open(path, rollup_asset_denom.clone()))
+            .transpose()
+            .wrap_err("failed to open audit log")?;
+        let deadletter_log =
+            super::audit::DeadletterLog::open(deadletter_log_path, rollup_asset_denom)
+                .wrap_err("failed to open deadletter log")?;
+
         let sequencer_cometbft_client =
             sequencer_client::HttpClient::new(&*sequencer_cometbft_endpoint)
                 .wrap_err("failed constructing cometbft http client")?;
 
         let (batches_tx, batches_rx) = tokio::sync::mpsc::channel(BATCH_QUEUE_SIZE);
-        let (startup_tx, startup_rx) = tokio::sync::oneshot::channel();
-        let handle = Handle::new(startup_rx, batches_tx);
+        let (startup_tx, startup_rx) = if with_startup {
+            let (tx, rx) = tokio::sync::oneshot::channel();
+            (Some(tx), Some(rx))
+        } else {
+            (None, None)
+        };
+        let clock = clock.unwrap_or_else(|| Arc::new(SystemClock));
+        let handle = Handle::with_clock(startup_rx, batches_tx, clock.clone());
 
         Ok((
             super::Submitter {
@@ -101,8 +287,18 @@ impl Builder {
                 signer,
                 sequencer_chain_id,
                 startup_tx,
+                startup_max_attempts,
+                startup_retry_backoff,
                 expected_fee_asset_id,
                 min_expected_fee_asset_balance,
+                batch_size_target: BatchSizeTarget::new(min_batch_size, max_batch_size),
+                audit_log,
+                deadletter_log,
+                destination_cap: destination_withdrawal_cap
+                    .map(|cap| DestinationCap::new(cap, destination_withdrawal_cap_window)),
+                deferred_store: deferred_withdrawals_path.map(DeferredStore::new),
+                queue_dwell_warning_threshold: super::BATCH_QUEUE_DWELL_WARNING_THRESHOLD,
+                clock,
                 metrics,
             },
             handle,
@@ -0,0 +1,80 @@
+//! The source of the current time for the submitter's time-dependent logic.
+//!
+//! The submitter reads the time through a [`Clock`] instead of calling [`Instant::now`] directly,
+//! so that tests can swap in a [`MockClock`] and move time forward by hand.
+
+use std::fmt::Debug;
+#[cfg(test)]
+use std::{
+    sync::Mutex,
+    time::Duration,
+};
+
+use tokio::time::Instant;
+
+pub(crate) trait Clock: Debug + Send + Sync {
+    /// Returns the current time.
+    fn now(&self) -> Instant;
+}
+
+/// A [`Clock`] returning the actual time.
+#[derive(Debug, Default)]
+pub(crate) struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// A [`Clock`] that stands still until it is advanced.
+#[cfg(test)]
+#[derive(Debug)]
+pub(crate) struct MockClock {
+    now: Mutex<Instant>,
+}
+
+#[cfg(test)]
+impl MockClock {
+    /// Creates a clock standing at the actual time.
+    pub(crate) fn new() -> Self {
+        Self {
+            now: Mutex::new(Instant::now()),
+        }
+    }
+
+    /// Moves the clock forward by `duration`.
+    pub(crate) fn advance(&self, duration: Duration) {
+        let mut now = self.now.lock().unwrap();
+        *now = now
+            .checked_add(duration)
+            .expect("advancing the mock clock must not overflow");
+    }
+}
+
+#[cfg(test)]
+impl Clock for MockClock {
+    fn now(&self) -> Instant {
+        *self.now.lock().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{
+        Clock as _,
+        MockClock,
+    };
+
+    #[test]
+    fn mock_clock_only_moves_when_advanced() {
+        let clock = MockClock::new();
+        let start = clock.now();
+        assert_eq!(start, clock.now());
+
+        clock.advance(Duration::from_secs(5));
+        assert_eq!(Duration::from_secs(5), clock.now().duration_since(start));
+    }
+}
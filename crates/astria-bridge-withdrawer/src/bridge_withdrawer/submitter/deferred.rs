@@ -0,0 +1,251 @@
+//! Keeps the withdrawals deferred by the destination cap across restarts.
+//!
+//! Deferred withdrawals are submitted together with a later batch, so the bridge account's last
+//! transaction passes their rollup height before they are executed. A restarted withdrawer resumes
+//! after that height and never reads them from the rollup again. The [`DeferredStore`] therefore
+//! keeps every deferred withdrawal in a file that is replaced whenever the deferred withdrawals
+//! change.
+//!
+//! Withdrawals are marked with the rollup height of the transaction they are about to be submitted
+//! in. If the withdrawer stops before it learns the outcome of that transaction, the bridge
+//! account's last transaction settles it on restart: a withdrawal was executed if its transaction's
+//! rollup height is not above the rollup height of the bridge account's last transaction.
+
+use std::{
+    fs::File,
+    io::{
+        BufRead as _,
+        BufReader,
+        BufWriter,
+        ErrorKind,
+        Write as _,
+    },
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+use astria_core::{
+    generated::protocol::transaction::v1alpha1 as raw,
+    protocol::transaction::v1alpha1::Action,
+};
+use astria_eyre::eyre::{
+    self,
+    WrapErr as _,
+};
+use prost::Message as _;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+/// A single deferred withdrawal as it is written to the file.
+#[derive(Debug, Deserialize, Serialize)]
+struct DeferredRecord {
+    /// The hex-encoded protobuf encoding of the withdrawal action.
+    action: String,
+    /// The rollup height of the transaction the withdrawal is being submitted in, if any.
+    submitted_at_rollup_height: Option<u64>,
+}
+
+/// A JSONL file holding the withdrawals that were deferred but not yet executed.
+#[derive(Debug)]
+pub(crate) struct DeferredStore {
+    path: PathBuf,
+}
+
+impl DeferredStore {
+    pub(crate) fn new<P: AsRef<Path>>(path: P) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+        }
+    }
+
+    /// Reads the withdrawals that were not executed before the withdrawer stopped, given the
+    /// rollup height of the bridge account's last transaction.
+    ///
+    /// Returns an empty list if the file does not exist.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be read or contains records that cannot be decoded.
+    pub(crate) fn load(&self, last_rollup_height: Option<u64>) -> eyre::Result<Vec<Action>> {
+        let file = match File::open(&self.path) {
+            Ok(file) => file,
+            Err(error) if error.kind() == ErrorKind::NotFound => return Ok(vec![]),
+            Err(error) => {
+                return Err(error).wrap_err_with(|| {
+                    format!("failed to open file at path: {}", self.path.display())
+                });
+            }
+        };
+        let mut actions = Vec::new();
+        for line in BufReader::new(file).lines() {
+            let line = line.wrap_err("failed to read line of deferred withdrawals")?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let record: DeferredRecord =
+                serde_json::from_str(&line).wrap_err("failed to parse deferred withdrawal")?;
+            if record
+                .submitted_at_rollup_height
+                .zip(last_rollup_height)
+                .is_some_and(|(submitted_at, last)| submitted_at <= last)
+            {
+                continue;
+            }
+            let bytes = hex::decode(&record.action)
+                .wrap_err("failed to decode deferred withdrawal as hex")?;
+            let raw = raw::Action::decode(&*bytes)
+                .wrap_err("failed to decode deferred withdrawal as protobuf")?;
+            actions.push(
+                Action::try_from_raw(raw)
+                    .wrap_err("failed to convert deferred withdrawal from protobuf")?,
+            );
+        }
+        Ok(actions)
+    }
+
+    /// Replaces the content of the file with `deferred`, followed by `submitting` which are about
+    /// to be submitted in a transaction at `submitted_at_rollup_height`.
+    ///
+    /// The file is replaced atomically, so that a withdrawer stopping while it is written finds
+    /// either the previous or the new content on restart.
+    ///
+    /// # Errors
+    /// Returns an error if writing the new content or replacing the file fails.
+    pub(crate) fn write<'a>(
+        &self,
+        deferred: impl IntoIterator<Item = &'a Action>,
+        submitting: &[Action],
+        submitted_at_rollup_height: Option<u64>,
+    ) -> eyre::Result<()> {
+        let records = deferred
+            .into_iter()
+            .map(|action| (action, None))
+            .chain(
+                submitting
+                    .iter()
+                    .map(|action| (action, submitted_at_rollup_height)),
+            )
+            .map(|(action, submitted_at_rollup_height)| DeferredRecord {
+                action: hex::encode(action.to_raw().encode_to_vec()),
+                submitted_at_rollup_height,
+            });
+
+        let tmp_path = self.path.with_extension("tmp");
+        let file = File::create(&tmp_path)
+            .wrap_err_with(|| format!("failed to create file at path: {}", tmp_path.display()))?;
+        let mut writer = BufWriter::new(file);
+        for record in records {
+            serde_json::to_writer(&mut writer, &record)
+                .wrap_err("failed to serialize deferred withdrawal")?;
+            writer
+                .write_all(b"\n")
+                .wrap_err("failed to write line terminator")?;
+        }
+        writer
+            .into_inner()
+            .wrap_err("failed to flush deferred withdrawals")?
+            .sync_all()
+            .wrap_err("failed to sync deferred withdrawals to disk")?;
+        std::fs::rename(&tmp_path, &self.path).wrap_err_with(|| {
+            format!(
+                "failed to replace deferred withdrawals at path: {}",
+                self.path.display()
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::{
+        primitive::v1::asset,
+        protocol::transaction::v1alpha1::{
+            action::BridgeUnlockAction,
+            Action,
+        },
+    };
+
+    use super::DeferredStore;
+    use crate::bridge_withdrawer::ethereum::convert::{
+        withdrawal_amount,
+        withdrawal_destination,
+    };
+
+    fn make_bridge_unlock_action(to: u8, amount: u128) -> Action {
+        Action::BridgeUnlock(BridgeUnlockAction {
+            to: crate::astria_address([to; 20]),
+            amount,
+            memo: vec![],
+            fee_asset_id: asset::Id::from_str_unchecked("nria"),
+            bridge_address: None,
+        })
+    }
+
+    fn summarize(actions: &[Action]) -> Vec<(String, u128)> {
+        actions
+            .iter()
+            .map(|action| {
+                (
+                    withdrawal_destination(action).unwrap(),
+                    withdrawal_amount(action).unwrap(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn missing_file_holds_no_withdrawals() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = DeferredStore::new(dir.path().join("deferred.jsonl"));
+        assert!(store.load(None).unwrap().is_empty());
+    }
+
+    #[test]
+    fn deferred_withdrawals_survive_reload() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = DeferredStore::new(dir.path().join("deferred.jsonl"));
+        let deferred = [
+            make_bridge_unlock_action(1, 60),
+            make_bridge_unlock_action(2, 40),
+        ];
+        store.write(&deferred, &[], None).unwrap();
+        assert_eq!(summarize(&deferred), summarize(&store.load(None).unwrap()));
+
+        // a later write replaces the earlier one
+        store.write(&deferred[1..], &[], None).unwrap();
+        assert_eq!(
+            summarize(&deferred[1..]),
+            summarize(&store.load(Some(100)).unwrap())
+        );
+    }
+
+    #[test]
+    fn submitted_withdrawals_are_dropped_if_their_transaction_was_executed() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = DeferredStore::new(dir.path().join("deferred.jsonl"));
+        let deferred = [make_bridge_unlock_action(1, 60)];
+        let submitting = [make_bridge_unlock_action(2, 40)];
+        store.write(&deferred, &submitting, Some(5)).unwrap();
+
+        // the transaction at rollup height 5 was not executed
+        for last_rollup_height in [None, Some(4)] {
+            assert_eq!(
+                summarize(&[
+                    make_bridge_unlock_action(1, 60),
+                    make_bridge_unlock_action(2, 40),
+                ]),
+                summarize(&store.load(last_rollup_height).unwrap()),
+            );
+        }
+        // the transaction at rollup height 5 was executed
+        for last_rollup_height in [5, 6] {
+            assert_eq!(
+                summarize(&deferred),
+                summarize(&store.load(Some(last_rollup_height)).unwrap()),
+            );
+        }
+    }
+}
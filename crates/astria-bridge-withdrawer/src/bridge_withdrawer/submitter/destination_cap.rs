@@ -0,0 +1,343 @@
+//! Limits on the amount withdrawn to a single destination address.
+//!
+//! A [`DestinationCap`] tracks the withdrawals executed to every destination over a rolling
+//! window. Withdrawals that would push the total sent to their destination within the window above
+//! the cap are throttled, while withdrawals to other destinations are unaffected. This bounds the
+//! damage a compromised rollup can do by draining the bridge account into a single address.
+//!
+//! Throttled withdrawals are not dropped: they are deferred for one window and then released to
+//! be admitted again, together with the withdrawals of a later batch. The submitter keeps them in
+//! a [`super::deferred::DeferredStore`] so that they survive restarts.
+
+use std::{
+    collections::{
+        HashMap,
+        VecDeque,
+    },
+    time::Duration,
+};
+
+use astria_core::protocol::transaction::v1alpha1::Action;
+use tokio::time::Instant;
+
+use crate::bridge_withdrawer::ethereum::convert::{
+    withdrawal_amount,
+    withdrawal_destination,
+};
+
+#[derive(Debug)]
+pub(crate) struct DestinationCap {
+    cap: u128,
+    window: Duration,
+    /// The time and amount of every withdrawal executed within the window, by destination.
+    executed: HashMap<String, VecDeque<(Instant, u128)>>,
+    /// Throttled withdrawals together with the time at which they are released, in the order in
+    /// which they were throttled.
+    deferred: VecDeque<(Instant, Action)>,
+}
+
+impl DestinationCap {
+    pub(crate) fn new(cap: u128, window: Duration) -> Self {
+        Self {
+            cap,
+            window,
+            executed: HashMap::new(),
+            deferred: VecDeque::new(),
+        }
+    }
+
+    /// Splits `actions` into those admitted at `now` and those throttled for exceeding the cap of
+    /// their destination.
+    ///
+    /// The withdrawals in `alongside` were admitted earlier for the same transaction and count
+    /// towards the cap of their destination like executed withdrawals do. Admitted withdrawals
+    /// themselves only count towards the cap once they are recorded with
+    /// [`DestinationCap::record`]. Actions that are not withdrawals are always admitted.
+    pub(crate) fn admit(
+        &mut self,
+        alongside: &[Action],
+        actions: Vec<Action>,
+        now: Instant,
+    ) -> (Vec<Action>, Vec<Action>) {
+        self.evict_expired(now);
+        let mut pending: HashMap<String, u128> = HashMap::new();
+        for action in alongside {
+            if let (Some(destination), Some(amount)) =
+                (withdrawal_destination(action), withdrawal_amount(action))
+            {
+                let total = pending.entry(destination).or_default();
+                *total = total.saturating_add(amount);
+            }
+        }
+        actions.into_iter().partition(|action| {
+            let (Some(destination), Some(amount)) =
+                (withdrawal_destination(action), withdrawal_amount(action))
+            else {
+                return true;
+            };
+            let executed = self
+                .executed
+                .get(&destination)
+                .into_iter()
+                .flatten()
+                .fold(0u128, |total, (_, amount)| total.saturating_add(*amount));
+            let pending = pending.entry(destination).or_default();
+            if executed.saturating_add(*pending).saturating_add(amount) > self.cap {
+                return false;
+            }
+            *pending = pending.saturating_add(amount);
+            true
+        })
+    }
+
+    /// Counts the withdrawals among `actions`, executed at `now`, towards the cap of their
+    /// destination until they fall out of the window.
+    pub(crate) fn record(&mut self, actions: &[Action], now: Instant) {
+        for action in actions {
+            if let (Some(destination), Some(amount)) =
+                (withdrawal_destination(action), withdrawal_amount(action))
+            {
+                self.executed
+                    .entry(destination)
+                    .or_default()
+                    .push_back((now, amount));
+            }
+        }
+    }
+
+    /// Defers the throttled `actions` until one window after `now`.
+    pub(crate) fn defer(&mut self, actions: Vec<Action>, now: Instant) {
+        let release_at = now.checked_add(self.window).unwrap_or(now);
+        self.deferred
+            .extend(actions.into_iter().map(|action| (release_at, action)));
+    }
+
+    /// Puts released `actions` that could not be submitted back in front of the deferred
+    /// withdrawals, so that they are released again right away.
+    pub(crate) fn return_released(&mut self, actions: Vec<Action>, now: Instant) {
+        for action in actions.into_iter().rev() {
+            self.deferred.push_front((now, action));
+        }
+    }
+
+    /// Takes the deferred withdrawals whose release time has come by `now`.
+    pub(crate) fn release(&mut self, now: Instant) -> Vec<Action> {
+        let mut released = Vec::new();
+        while self
+            .deferred
+            .front()
+            .is_some_and(|(release_at, _)| *release_at <= now)
+        {
+            let (_, action) = self
+                .deferred
+                .pop_front()
+                .expect("the front of the deferred withdrawals was just checked");
+            released.push(action);
+        }
+        released
+    }
+
+    /// Returns all deferred withdrawals, regardless of their release time.
+    pub(crate) fn deferred(&self) -> impl Iterator<Item = &Action> {
+        self.deferred.iter().map(|(_, action)| action)
+    }
+
+    /// Forgets all withdrawals executed more than `window` before `now`.
+    fn evict_expired(&mut self, now: Instant) {
+        let window = self.window;
+        self.executed.retain(|_, executed| {
+            while executed
+                .front()
+                .is_some_and(|(at, _)| now.saturating_duration_since(*at) >= window)
+            {
+                executed.pop_front();
+            }
+            !executed.is_empty()
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use astria_core::{
+        primitive::v1::asset,
+        protocol::transaction::v1alpha1::{
+            action::BridgeUnlockAction,
+            Action,
+        },
+    };
+    use tokio::time::Instant;
+
+    use super::DestinationCap;
+    use crate::bridge_withdrawer::ethereum::convert::{
+        withdrawal_amount,
+        withdrawal_destination,
+    };
+
+    const WINDOW: Duration = Duration::from_secs(60);
+    const ALMOST_WINDOW: Duration = Duration::from_secs(59);
+
+    fn make_bridge_unlock_action(to: u8, amount: u128) -> Action {
+        Action::BridgeUnlock(BridgeUnlockAction {
+            to: crate::astria_address([to; 20]),
+            amount,
+            memo: vec![],
+            fee_asset_id: asset::Id::from_str_unchecked("nria"),
+            bridge_address: None,
+        })
+    }
+
+    /// Returns the destination and amount of every withdrawal, since `Action` is not `PartialEq`.
+    fn summarize(actions: &[Action]) -> Vec<(String, u128)> {
+        actions
+            .iter()
+            .map(|action| {
+                (
+                    withdrawal_destination(action).unwrap(),
+                    withdrawal_amount(action).unwrap(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn destination_exceeding_cap_is_throttled_while_others_proceed() {
+        let mut cap = DestinationCap::new(100, WINDOW);
+        let now = Instant::now();
+
+        let (admitted, throttled) = cap.admit(
+            &[],
+            vec![
+                make_bridge_unlock_action(1, 60),
+                make_bridge_unlock_action(2, 60),
+                make_bridge_unlock_action(1, 60),
+                make_bridge_unlock_action(2, 40),
+            ],
+            now,
+        );
+        assert_eq!(
+            summarize(&[
+                make_bridge_unlock_action(1, 60),
+                make_bridge_unlock_action(2, 60),
+                make_bridge_unlock_action(2, 40),
+            ]),
+            summarize(&admitted),
+        );
+        assert_eq!(
+            summarize(&[make_bridge_unlock_action(1, 60)]),
+            summarize(&throttled),
+        );
+        cap.record(&admitted, now);
+
+        // the first destination has room for 40 more, the second none, and the third is untouched
+        let (admitted, throttled) = cap.admit(
+            &[],
+            vec![
+                make_bridge_unlock_action(1, 40),
+                make_bridge_unlock_action(2, 1),
+                make_bridge_unlock_action(3, 100),
+            ],
+            now.checked_add(Duration::from_secs(1)).unwrap(),
+        );
+        assert_eq!(
+            summarize(&[
+                make_bridge_unlock_action(1, 40),
+                make_bridge_unlock_action(3, 100),
+            ]),
+            summarize(&admitted),
+        );
+        assert_eq!(
+            summarize(&[make_bridge_unlock_action(2, 1)]),
+            summarize(&throttled),
+        );
+    }
+
+    #[test]
+    fn withdrawals_outside_window_no_longer_count_towards_cap() {
+        let mut cap = DestinationCap::new(100, WINDOW);
+        let now = Instant::now();
+
+        let (admitted, throttled) = cap.admit(&[], vec![make_bridge_unlock_action(1, 100)], now);
+        assert!(throttled.is_empty());
+        cap.record(&admitted, now);
+        let (_, throttled) = cap.admit(
+            &[],
+            vec![make_bridge_unlock_action(1, 1)],
+            now.checked_add(ALMOST_WINDOW).unwrap(),
+        );
+        assert_eq!(1, throttled.len());
+        let (admitted, throttled) = cap.admit(
+            &[],
+            vec![make_bridge_unlock_action(1, 100)],
+            now.checked_add(WINDOW).unwrap(),
+        );
+        assert_eq!(1, admitted.len());
+        assert!(throttled.is_empty());
+    }
+
+    #[test]
+    fn admitted_withdrawals_only_count_towards_cap_once_recorded() {
+        let mut cap = DestinationCap::new(100, WINDOW);
+        let now = Instant::now();
+
+        // a withdrawal that was admitted but never executed does not use up the cap
+        let (admitted, _) = cap.admit(&[], vec![make_bridge_unlock_action(1, 100)], now);
+        assert_eq!(1, admitted.len());
+        let (admitted, _) = cap.admit(&[], vec![make_bridge_unlock_action(1, 100)], now);
+        assert_eq!(1, admitted.len());
+
+        // but it does if it is submitted alongside
+        let (_, throttled) = cap.admit(&admitted, vec![make_bridge_unlock_action(1, 1)], now);
+        assert_eq!(1, throttled.len());
+
+        cap.record(&admitted, now);
+        let (_, throttled) = cap.admit(&[], vec![make_bridge_unlock_action(1, 1)], now);
+        assert_eq!(1, throttled.len());
+    }
+
+    #[test]
+    fn deferred_withdrawals_are_released_after_window() {
+        let mut cap = DestinationCap::new(100, WINDOW);
+        let now = Instant::now();
+
+        cap.defer(
+            vec![
+                make_bridge_unlock_action(1, 60),
+                make_bridge_unlock_action(2, 60),
+            ],
+            now,
+        );
+        assert!(cap
+            .release(now.checked_add(ALMOST_WINDOW).unwrap())
+            .is_empty());
+
+        let released = cap.release(now.checked_add(WINDOW).unwrap());
+        assert_eq!(
+            summarize(&[
+                make_bridge_unlock_action(1, 60),
+                make_bridge_unlock_action(2, 60),
+            ]),
+            summarize(&released),
+        );
+        assert!(cap.deferred().next().is_none());
+
+        // released withdrawals that are returned are released again right away
+        let later = now.checked_add(WINDOW).unwrap();
+        cap.defer(vec![make_bridge_unlock_action(3, 10)], later);
+        cap.return_released(released, later);
+        let released = cap.release(later);
+        assert_eq!(
+            summarize(&[
+                make_bridge_unlock_action(1, 60),
+                make_bridge_unlock_action(2, 60),
+            ]),
+            summarize(&released),
+        );
+        assert_eq!(
+            summarize(&[make_bridge_unlock_action(3, 10)]),
+            summarize(&cap.deferred().cloned().collect::<Vec<_>>()),
+        );
+    }
+}
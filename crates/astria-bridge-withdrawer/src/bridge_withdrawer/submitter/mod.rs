@@ -1,4 +1,5 @@
 use std::{
+    collections::VecDeque,
     sync::Arc,
     time::Duration,
 };
@@ -7,6 +8,7 @@ use astria_core::{
     bridge::Ics20WithdrawalFromRollupMemo,
     primitive::v1::asset,
     protocol::{
+        abci::AbciErrorCode,
         asset::v1alpha1::AllowedFeeAssetIdsResponse,
         bridge::v1alpha1::BridgeAccountLastTxHashResponse,
         transaction::v1alpha1::{
@@ -18,13 +20,22 @@ use astria_core::{
 };
 use astria_eyre::eyre::{
     self,
+    bail,
     ensure,
     eyre,
     Context,
     OptionExt,
 };
+use audit::{
+    AuditLog,
+    DeadletterLog,
+};
 pub(crate) use builder::Builder;
 pub(super) use builder::Handle;
+use builder::QueuedBatch;
+use clock::Clock;
+use deferred::DeferredStore;
+use destination_cap::DestinationCap;
 use prost::Message as _;
 use sequencer_client::{
     tendermint_rpc::{
@@ -65,8 +76,12 @@ use tracing::{
 };
 
 use super::{
-    batch::Batch,
+    batch::{
+        Batch,
+        BatchSizeTarget,
+    },
     state,
+    DeadletteredBatch,
     SequencerStartupInfo,
 };
 use crate::{
@@ -74,34 +89,91 @@ use crate::{
     metrics::Metrics,
 };
 
+mod audit;
 mod builder;
+mod clock;
+mod deferred;
+mod destination_cap;
 mod signer;
 #[cfg(test)]
 mod tests;
 
+/// The interval at which the sequencer node's sync status is polled during startup.
+const SEQUENCER_SYNC_POLL_INTERVAL: Duration = Duration::from_millis(500);
+/// The maximum time to wait for the sequencer node to finish catching up during startup.
+const SEQUENCER_SYNC_TIMEOUT: Duration = Duration::from_secs(300);
+/// Batches that waited in the submitter's queue for longer than this are reported as a sign that
+/// the submitter is not keeping up with the watcher.
+const BATCH_QUEUE_DWELL_WARNING_THRESHOLD: Duration = Duration::from_secs(30);
+/// The upper bound on the delay between two attempts of the startup handshake.
+const STARTUP_RETRY_MAX_BACKOFF: Duration = Duration::from_secs(60);
+/// ABCI codes with which the sequencer rejects a transaction in `check_tx` that no resubmission
+/// of the same actions can succeed with. Batches rejected with these codes are moved to the
+/// deadletter queue instead of halting the submitter.
+///
+/// `INVALID_NONCE` is deliberately absent: every submission fetches the latest nonce, so the same
+/// actions can succeed when they are submitted again.
+const NON_RETRIABLE_CHECK_TX_CODES: [AbciErrorCode; 1] = [AbciErrorCode::INVALID_PARAMETER];
+
 pub(super) struct Submitter {
     shutdown_token: CancellationToken,
     state: Arc<State>,
-    batches_rx: mpsc::Receiver<Batch>,
+    batches_rx: mpsc::Receiver<QueuedBatch>,
     sequencer_cometbft_client: sequencer_client::HttpClient,
     signer: SequencerKey,
     sequencer_chain_id: String,
-    startup_tx: oneshot::Sender<SequencerStartupInfo>,
+    /// Where to send the information collected during startup. If `None`, the submitter skips
+    /// startup and is ready immediately.
+    startup_tx: Option<oneshot::Sender<SequencerStartupInfo>>,
+    /// The number of times the startup handshake is attempted before the submitter gives up.
+    startup_max_attempts: u32,
+    /// The delay before the startup handshake is attempted again, doubled after every failure.
+    startup_retry_backoff: Duration,
     expected_fee_asset_id: asset::Id,
     min_expected_fee_asset_balance: u128,
+    batch_size_target: BatchSizeTarget,
+    /// Where executed withdrawals are recorded. If `None`, no audit trail is written.
+    audit_log: Option<AuditLog>,
+    /// Where withdrawals that the submitter gave up on are recorded for operators.
+    deadletter_log: DeadletterLog,
+    /// Limits the amount withdrawn to a single destination. If `None`, withdrawals are not
+    /// limited.
+    destination_cap: Option<DestinationCap>,
+    /// Where withdrawals deferred by `destination_cap` are kept across restarts.
+    deferred_store: Option<DeferredStore>,
+    /// Batches that waited in the queue for longer than this trigger a warning.
+    queue_dwell_warning_threshold: Duration,
+    /// The source of the current time for queue dwell times and destination caps.
+    clock: Arc<dyn Clock>,
     metrics: &'static Metrics,
 }
 
 impl Submitter {
     pub(super) async fn run(mut self) -> eyre::Result<()> {
-        // call startup
-        let startup = self
-            .startup()
-            .await
-            .wrap_err("submitter failed to start up")?;
-        self.startup_tx
-            .send(startup)
-            .map_err(|_startup| eyre!("failed to send startup info to watcher"))?;
+        if let Some(startup_tx) = self.startup_tx.take() {
+            let Some(startup) = self
+                .startup_with_retries()
+                .await
+                .wrap_err("submitter failed to start up")?
+            else {
+                info!("received shutdown signal during startup; shutting down");
+                return Ok(());
+            };
+            startup_tx
+                .send(startup)
+                .map_err(|_startup| eyre!("failed to send startup info to watcher"))?;
+        } else {
+            info!("submitter was built without startup; assuming it is ready");
+            if self.deferred_store.is_some() {
+                let last_rollup_height = self
+                    .get_last_rollup_height()
+                    .await
+                    .wrap_err("failed to get last rollup block height")?;
+                self.restore_deferred(last_rollup_height)
+                    .wrap_err("failed to restore deferred withdrawals")?;
+            }
+            self.state.set_submitter_ready();
+        }
 
         let reason = loop {
             select!(
@@ -113,20 +185,12 @@ impl Submitter {
                 }
 
                 batch = self.batches_rx.recv() => {
-                    let Some(Batch { actions, rollup_height }) = batch else {
+                    let Some(queued) = batch else {
                         info!("received None from batch channel, shutting down");
                         break Err(eyre!("batch channel closed"));
                     };
                     // if batch submission fails, halt the submitter
-                    if let Err(e) = process_batch(
-                        self.sequencer_cometbft_client.clone(),
-                        &self.signer,
-                        self.state.clone(),
-                        &self.sequencer_chain_id,
-                        actions,
-                        rollup_height,
-                        self.metrics
-                    ).await {
+                    if let Err(e) = self.process_queued_batch(queued).await {
                         break Err(e);
                     }
                 }
@@ -149,8 +213,138 @@ impl Submitter {
         Ok(())
     }
 
-    /// Confirms configuration values against the sequencer node and then syncs the next sequencer
-    /// nonce and rollup block according to the latest on-chain state.
+    /// Submits `queued` together with the batches waiting behind it in the queue.
+    ///
+    /// Batches that can be merged into `queued` are submitted with it. Each batch that cannot is
+    /// in turn submitted together with the mergeable batches behind it, until the queue is empty.
+    async fn process_queued_batch(&mut self, mut queued: QueuedBatch) -> eyre::Result<()> {
+        loop {
+            self.record_queue_dwell(&queued.batch, self.dwell_since(queued.enqueued_at));
+            let (batch, unmerged) = self.merge_queued_batches(queued.batch);
+            self.process_batch(batch).await?;
+            let Some(next) = unmerged else {
+                return Ok(());
+            };
+            queued = next;
+        }
+    }
+
+    /// Drains the queue, merging the drained batches into `batch` for as long as the merged batch
+    /// still fits into a single transaction at the current batch size target.
+    ///
+    /// Returns the merged batch and the first drained batch that could not be merged, if any.
+    fn merge_queued_batches(&mut self, mut batch: Batch) -> (Batch, Option<QueuedBatch>) {
+        while let Ok(queued) = self.batches_rx.try_recv() {
+            let QueuedBatch {
+                batch: other,
+                enqueued_at,
+            } = queued;
+            if batch.actions.len().saturating_add(other.actions.len())
+                > self.batch_size_target.get()
+            {
+                return (
+                    batch,
+                    Some(QueuedBatch {
+                        batch: other,
+                        enqueued_at,
+                    }),
+                );
+            }
+            let other_rollup_height = other.rollup_height;
+            match batch.try_merge(other) {
+                Ok(merged) => {
+                    debug!(
+                        rollup.height = other_rollup_height,
+                        merged_actions = merged.actions.len(),
+                        "merged queued batch into the batch being processed",
+                    );
+                    self.record_queue_dwell(&merged, self.dwell_since(enqueued_at));
+                    batch = merged;
+                }
+                Err((unchanged, other)) => {
+                    return (
+                        unchanged,
+                        Some(QueuedBatch {
+                            batch: other,
+                            enqueued_at,
+                        }),
+                    );
+                }
+            }
+        }
+        (batch, None)
+    }
+
+    /// Returns how long a batch enqueued at `enqueued_at` has been waiting.
+    fn dwell_since(&self, enqueued_at: Instant) -> Duration {
+        self.clock.now().saturating_duration_since(enqueued_at)
+    }
+
+    /// Records how long `batch` waited in the queue before the submitter picked it up, warning if
+    /// it waited for longer than the configured threshold.
+    fn record_queue_dwell(&self, batch: &Batch, dwell: Duration) {
+        self.metrics.record_batch_queue_dwell_time(dwell);
+        if dwell > self.queue_dwell_warning_threshold {
+            warn!(
+                rollup_height = batch.rollup_height,
+                dwell = %humantime::format_duration(dwell),
+                threshold = %humantime::format_duration(self.queue_dwell_warning_threshold),
+                "batch waited in the submitter queue for longer than the warning threshold; the \
+                 submitter is not keeping up with the watcher",
+            );
+        }
+    }
+
+    /// Runs [`Submitter::startup`], attempting it up to `startup_max_attempts` times with
+    /// exponential backoff in between.
+    ///
+    /// Returns `None` if shutdown was requested before startup succeeded.
+    ///
+    /// # Errors
+    /// Returns the error of the last attempt if all attempts failed.
+    async fn startup_with_retries(&mut self) -> eyre::Result<Option<SequencerStartupInfo>> {
+        let shutdown_token = self.shutdown_token.clone();
+        let max_attempts = self.startup_max_attempts.max(1);
+        let mut backoff = self.startup_retry_backoff;
+        let mut attempt: u32 = 1;
+        loop {
+            let res = select! {
+                biased;
+                () = shutdown_token.cancelled() => return Ok(None),
+                res = self.startup() => res,
+            };
+            let error = match res {
+                Ok(startup) => return Ok(Some(startup)),
+                Err(error) if attempt >= max_attempts => {
+                    return Err(error).wrap_err_with(|| {
+                        format!("startup handshake failed after {max_attempts} attempts")
+                    });
+                }
+                Err(error) => error,
+            };
+            warn!(
+                attempt,
+                max_attempts,
+                wait_duration = %humantime::format_duration(backoff),
+                %error,
+                "startup handshake failed; retrying after backoff",
+            );
+            select! {
+                biased;
+                () = shutdown_token.cancelled() => return Ok(None),
+                () = tokio::time::sleep(backoff) => {}
+            }
+            backoff = backoff.saturating_mul(2).min(STARTUP_RETRY_MAX_BACKOFF);
+            attempt = attempt.saturating_add(1);
+        }
+    }
+
+    /// Waits for the sequencer node to be synced, confirms configuration values against it and
+    /// then syncs the next sequencer nonce and rollup block according to the latest on-chain state.
+    /// Withdrawals deferred by the destination cap before the last restart are deferred again.
+    ///
+    /// No batches are submitted before this method returns, so waiting for the sequencer node to
+    /// finish catching up ensures that nonces and balances are read from an up-to-date state.
     ///
     /// Configuration values checked:
     /// - `self.chain_id` matches the value returned from the sequencer node's genesis
@@ -171,10 +365,16 @@ impl Submitter {
     ///
     /// # Errors
     ///
+    /// - the sequencer node is still catching up after `SEQUENCER_SYNC_TIMEOUT`
     /// - `self.chain_id` does not match the value returned from the sequencer node
     /// - `self.fee_asset_id` is not a valid fee asset on the sequencer node
     /// - `self.sequencer_key.address` does not have a sufficient balance of `self.fee_asset_id`.
+    /// - the deferred withdrawals cannot be restored
     async fn startup(&mut self) -> eyre::Result<SequencerStartupInfo> {
+        wait_for_sequencer_sync(self.sequencer_cometbft_client.clone(), self.state.clone())
+            .await
+            .wrap_err("sequencer node did not finish syncing")?;
+
         let actual_chain_id =
             get_sequencer_chain_id(self.sequencer_cometbft_client.clone(), self.state.clone())
                 .await
@@ -216,10 +416,14 @@ impl Submitter {
         );
 
         // sync to latest on-chain state
-        let next_batch_rollup_height = self
-            .get_next_rollup_height()
+        let last_rollup_height = self
+            .get_last_rollup_height()
             .await
-            .wrap_err("failed to get next rollup block height")?;
+            .wrap_err("failed to get last rollup block height")?;
+        let next_batch_rollup_height = last_rollup_height.unwrap_or(1);
+
+        self.restore_deferred(last_rollup_height)
+            .wrap_err("failed to restore deferred withdrawals")?;
 
         self.state.set_submitter_ready();
 
@@ -240,7 +444,7 @@ impl Submitter {
     /// in the batch.
     ///
     /// # Returns
-    /// The next batch rollup height to process.
+    /// The rollup height of the last batch, or `None` if the bridge account has no transactions.
     ///
     /// # Errors
     ///
@@ -250,19 +454,18 @@ impl Submitter {
     ///    the sequencer logic)
     /// 3. The last transaction by the bridge account did not contain a withdrawal action
     /// 4. The memo of the last transaction by the bridge account could not be parsed
-    async fn get_next_rollup_height(&mut self) -> eyre::Result<u64> {
+    async fn get_last_rollup_height(&mut self) -> eyre::Result<Option<u64>> {
         let signed_transaction = self
             .get_last_transaction()
             .await
             .wrap_err("failed to get the bridge account's last sequencer transaction")?;
-        let next_batch_rollup_height = if let Some(signed_transaction) = signed_transaction {
-            rollup_height_from_signed_transaction(&signed_transaction).wrap_err(
-                "failed to extract rollup height from last transaction by the bridge account",
-            )?
-        } else {
-            1
-        };
-        Ok(next_batch_rollup_height)
+        signed_transaction
+            .map(|signed_transaction| {
+                rollup_height_from_signed_transaction(&signed_transaction).wrap_err(
+                    "failed to extract rollup height from last transaction by the bridge account",
+                )
+            })
+            .transpose()
     }
 
     async fn get_last_transaction(&self) -> eyre::Result<Option<SignedTransaction>> {
@@ -315,9 +518,294 @@ impl Submitter {
 
         Ok(Some(tx))
     }
+
+    /// Submits the actions of a batch to the sequencer, splitting them across as many
+    /// transactions as the adaptive `batch_size_target` requires.
+    ///
+    /// The withdrawals of one rollup height are never split across transactions: startup resumes
+    /// from the last rollup height found in the bridge account's last transaction, so a height
+    /// that was only partially submitted before a restart would never be completed. A transaction
+    /// holds the withdrawals of as many whole rollup heights as fit into the target, and at least
+    /// those of one height even if they exceed it.
+    ///
+    /// A transaction rejected by the sequencer for being too large shrinks the target and its
+    /// actions are resubmitted in smaller chunks. Every executed transaction grows the target
+    /// again, and its actions are appended to the audit log if one is configured. It is an error
+    /// if the withdrawals of a single rollup height are rejected for being too large.
+    ///
+    /// If a transaction is rejected for a non-retriable reason, all actions of the batch not yet
+    /// executed are moved to the deadletter queue and are not resubmitted while the withdrawer
+    /// keeps running. Deadlettered withdrawals require operator action and are recorded in the
+    /// deadletter log for that purpose; `state` only keeps a summary of every deadlettered batch.
+    /// Deadlettering is not recorded on the sequencer: if the withdrawer restarts before a later
+    /// transaction of the bridge account is executed, it resumes from the rollup height of the
+    /// last executed one and submits the deadlettered rollup heights again.
+    ///
+    /// Withdrawals exceeding the cap of their destination are deferred for one window. Once
+    /// released, they are submitted in the first transaction of a later batch, so that the
+    /// greatest rollup height in the bridge account's last transaction never goes backwards.
+    /// Deferred withdrawals are written to the deferred store whenever they change, and released
+    /// withdrawals are marked with the rollup height of the transaction they are submitted in
+    /// before it is sent, so that they are neither lost nor submitted twice across a restart.
+    async fn process_batch(&mut self, batch: Batch) -> eyre::Result<()> {
+        let batch_id = batch.idempotency_id();
+        debug!(
+            batch.id = %telemetry::display::hex(&batch_id),
+            rollup.height = batch.rollup_height,
+            "processing batch",
+        );
+        let Batch {
+            mut actions,
+            rollup_height,
+            ..
+        } = batch;
+        // released withdrawals that were deferred by earlier batches, submitted ahead of this
+        // batch's withdrawals in its first transaction
+        let mut carried = Vec::new();
+        let mut deferred_more = false;
+        if let Some(destination_cap) = &mut self.destination_cap {
+            let now = self.clock.now();
+            let released = destination_cap.release(now);
+            let (released, throttled_again) = destination_cap.admit(&[], released, now);
+            let (admitted, throttled) = destination_cap.admit(&released, actions, now);
+            carried = released;
+            actions = admitted;
+            let throttled: Vec<_> = throttled_again.into_iter().chain(throttled).collect();
+            if !throttled.is_empty() {
+                warn!(
+                    rollup.height = rollup_height,
+                    throttled_actions = throttled.len(),
+                    "withdrawals exceed the cap of their destination; deferring them until the \
+                     cap window has passed"
+                );
+                destination_cap.defer(throttled, now);
+                deferred_more = true;
+            }
+        }
+        if deferred_more {
+            self.persist_deferred(&carried, None)?;
+        }
+        let mut heights = group_by_rollup_height(actions, rollup_height)
+            .wrap_err("failed to read the rollup heights of the batch's withdrawals")?;
+        if heights.is_empty() && !carried.is_empty() {
+            // released withdrawals are from earlier rollup heights and must not be submitted on
+            // their own; they wait for the next batch instead
+            if let Some(destination_cap) = &mut self.destination_cap {
+                destination_cap.return_released(std::mem::take(&mut carried), self.clock.now());
+            }
+        }
+        while !heights.is_empty() {
+            let chunk: Vec<_> = heights
+                .drain(..whole_heights_within(&heights, self.batch_size_target.get()))
+                .collect();
+            let chunk_rollup_height = chunk
+                .last()
+                .map(|(height, _)| *height)
+                .expect("at least one rollup height is always taken from a non-empty queue");
+            let actions: Vec<_> = carried
+                .iter()
+                .cloned()
+                .chain(
+                    chunk
+                        .iter()
+                        .flat_map(|(_, actions)| actions.iter().cloned()),
+                )
+                .collect();
+            if !carried.is_empty() {
+                self.persist_deferred(&carried, Some(chunk_rollup_height))?;
+            }
+            match submit_actions(
+                self.sequencer_cometbft_client.clone(),
+                &self.signer,
+                self.state.clone(),
+                &self.sequencer_chain_id,
+                actions.clone(),
+                chunk_rollup_height,
+                self.metrics,
+            )
+            .await?
+            {
+                SubmissionOutcome::Executed {
+                    sequencer_height,
+                    transaction_hash,
+                } => {
+                    self.batch_size_target.on_accepted();
+                    if let Some(destination_cap) = &mut self.destination_cap {
+                        destination_cap.record(&actions, self.clock.now());
+                    }
+                    if !carried.is_empty() {
+                        carried.clear();
+                        self.persist_deferred(&[], None)?;
+                    }
+                    if let Some(audit_log) = &mut self.audit_log {
+                        audit_log
+                            .append(&actions, sequencer_height, transaction_hash.as_bytes())
+                            .wrap_err("failed to append executed withdrawals to audit log")?;
+                    }
+                }
+                SubmissionOutcome::TooLarge
+                    if chunk.len() > 1 && !self.batch_size_target.is_at_min() =>
+                {
+                    self.batch_size_target.on_too_large();
+                    warn!(
+                        rollup.height = chunk_rollup_height,
+                        batch_size_target = self.batch_size_target.get(),
+                        "transaction was rejected as too large; retrying with fewer actions"
+                    );
+                    for height in chunk.into_iter().rev() {
+                        heights.push_front(height);
+                    }
+                }
+                SubmissionOutcome::TooLarge => {
+                    return Err(eyre!(
+                        "transaction was rejected as too large even though it cannot be split any \
+                         further without splitting a rollup height"
+                    ));
+                }
+                SubmissionOutcome::Rejected {
+                    reason,
+                } => {
+                    let unexecuted: Vec<_> = actions
+                        .into_iter()
+                        .chain(heights.into_iter().flat_map(|(_, actions)| actions))
+                        .collect();
+                    self.deadletter(&batch_id, rollup_height, &unexecuted, &reason)?;
+                    if !carried.is_empty() {
+                        self.persist_deferred(&[], None)?;
+                    }
+                    return Ok(());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Defers the withdrawals in the deferred store again that were not executed before the
+    /// submitter last stopped.
+    ///
+    /// `last_rollup_height` is the rollup height of the bridge account's last transaction, which
+    /// settles whether withdrawals submitted right before the stop were executed. The executed
+    /// withdrawals counting towards the caps are not kept across restarts, so restored withdrawals
+    /// are deferred for another full window.
+    ///
+    /// The deferred store is rewritten before the withdrawals are deferred, so that restoring them
+    /// again after a failed attempt does not defer them twice.
+    fn restore_deferred(&mut self, last_rollup_height: Option<u64>) -> eyre::Result<()> {
+        let Some(store) = &self.deferred_store else {
+            return Ok(());
+        };
+        let actions = store
+            .load(last_rollup_height)
+            .wrap_err("failed to load deferred withdrawals")?;
+        if actions.is_empty() {
+            return Ok(());
+        }
+        let Some(destination_cap) = &mut self.destination_cap else {
+            bail!(
+                "found {} deferred withdrawals, but the destination withdrawal cap is disabled; \
+                 enable it again to submit them",
+                actions.len()
+            );
+        };
+        store
+            .write(destination_cap.deferred().chain(&actions), &[], None)
+            .wrap_err("failed to write deferred withdrawals")?;
+        info!(
+            deferred_actions = actions.len(),
+            "restored withdrawals deferred before the last restart; deferring them for another \
+             cap window"
+        );
+        destination_cap.defer(actions, self.clock.now());
+        Ok(())
+    }
+
+    /// Writes the withdrawals deferred by the destination cap to the deferred store, followed by
+    /// `carried`, which are about to be submitted in a transaction at `rollup_height`.
+    fn persist_deferred(&self, carried: &[Action], rollup_height: Option<u64>) -> eyre::Result<()> {
+        let (Some(store), Some(destination_cap)) = (&self.deferred_store, &self.destination_cap)
+        else {
+            return Ok(());
+        };
+        store
+            .write(destination_cap.deferred(), carried, rollup_height)
+            .wrap_err("failed to write deferred withdrawals")
+    }
+
+    /// Records `actions` of the batch `batch_id` in the deadletter log and adds a summary of them
+    /// to the deadletter queue in `state`.
+    fn deadletter(
+        &mut self,
+        batch_id: &[u8],
+        rollup_height: u64,
+        actions: &[Action],
+        reason: &str,
+    ) -> eyre::Result<()> {
+        self.deadletter_log
+            .append(actions, batch_id, reason)
+            .wrap_err("failed to record deadlettered withdrawals")?;
+        self.state.push_deadletter(DeadletteredBatch {
+            batch_id: hex::encode(batch_id),
+            rollup_height,
+            unexecuted_actions: actions.len(),
+            reason: reason.to_string(),
+        });
+        Ok(())
+    }
 }
 
-async fn process_batch(
+/// The outcome of a transaction that was not rejected outright by the sequencer.
+enum SubmissionOutcome {
+    /// The transaction was executed in a sequencer block.
+    Executed {
+        sequencer_height: u64,
+        transaction_hash: tendermint::Hash,
+    },
+    /// The transaction was rejected in `check_tx` for being too large.
+    TooLarge,
+    /// The transaction was rejected in `check_tx` for a reason that resubmitting it cannot fix.
+    Rejected { reason: String },
+}
+
+/// Groups `actions` by the rollup height recorded in their memos, keeping their order.
+///
+/// Actions without a rollup height in their memo are assigned to the height before them, or to
+/// `first_rollup_height` if they come first.
+fn group_by_rollup_height(
+    actions: Vec<Action>,
+    first_rollup_height: u64,
+) -> eyre::Result<VecDeque<(u64, Vec<Action>)>> {
+    let mut heights: VecDeque<(u64, Vec<Action>)> = VecDeque::new();
+    for action in actions {
+        let height = match rollup_height_of_action(&action).transpose()? {
+            Some(height) => height,
+            None => heights
+                .back()
+                .map_or(first_rollup_height, |(height, _)| *height),
+        };
+        match heights.back_mut() {
+            Some((last_height, actions)) if *last_height == height => actions.push(action),
+            _ => heights.push_back((height, vec![action])),
+        }
+    }
+    Ok(heights)
+}
+
+/// Returns how many of the leading rollup heights in `heights` fit into a transaction of at most
+/// `target` actions, but at least one if `heights` is not empty.
+fn whole_heights_within(heights: &VecDeque<(u64, Vec<Action>)>, target: usize) -> usize {
+    let mut taken: usize = 0;
+    let mut len: usize = 0;
+    for (_, actions) in heights {
+        len = len.saturating_add(actions.len());
+        if taken > 0 && len > target {
+            break;
+        }
+        taken = taken.saturating_add(1);
+    }
+    taken
+}
+
+async fn submit_actions(
     sequencer_cometbft_client: sequencer_client::HttpClient,
     sequencer_key: &SequencerKey,
     state: Arc<State>,
@@ -325,7 +813,7 @@ async fn process_batch(
     actions: Vec<Action>,
     rollup_height: u64,
     metrics: &'static Metrics,
-) -> eyre::Result<()> {
+) -> eyre::Result<SubmissionOutcome> {
     // get nonce and make unsigned transaction
     let nonce = get_latest_nonce(
         sequencer_cometbft_client.clone(),
@@ -362,7 +850,27 @@ async fn process_batch(
     )
     .await
     .context("failed to submit transaction to to cometbft")?;
-    if let tendermint::abci::Code::Err(check_tx_code) = rsp.check_tx.code {
+    if rsp.check_tx.code == tendermint::abci::Code::from(AbciErrorCode::TRANSACTION_TOO_LARGE) {
+        Ok(SubmissionOutcome::TooLarge)
+    } else if NON_RETRIABLE_CHECK_TX_CODES
+        .into_iter()
+        .any(|code| rsp.check_tx.code == tendermint::abci::Code::from(code))
+    {
+        error!(
+            abci.code = rsp.check_tx.code.value(),
+            abci.log = rsp.check_tx.log,
+            rollup.height = rollup_height,
+            "transaction was permanently rejected by the sequencer; moving batch to deadletter \
+             queue"
+        );
+        Ok(SubmissionOutcome::Rejected {
+            reason: format!(
+                "check_tx failed with code {}: {}",
+                rsp.check_tx.code.value(),
+                rsp.check_tx.log
+            ),
+        })
+    } else if let tendermint::abci::Code::Err(check_tx_code) = rsp.check_tx.code {
         error!(
             abci.code = check_tx_code,
             abci.log = rsp.check_tx.log,
@@ -393,7 +901,10 @@ async fn process_batch(
         state.set_last_rollup_height_submitted(rollup_height);
         state.set_last_sequencer_height(rsp.height.value());
         state.set_last_sequencer_tx_hash(rsp.hash);
-        Ok(())
+        Ok(SubmissionOutcome::Executed {
+            sequencer_height: rsp.height.value(),
+            transaction_hash: rsp.hash,
+        })
     }
 }
 
@@ -510,6 +1021,47 @@ async fn submit_tx(
     res
 }
 
+/// Polls the sequencer node's status until it reports that it is no longer catching up.
+///
+/// # Errors
+///
+/// - if the sequencer node did not finish catching up within `SEQUENCER_SYNC_TIMEOUT`
+#[instrument(skip_all)]
+async fn wait_for_sequencer_sync(
+    client: sequencer_client::HttpClient,
+    state: Arc<State>,
+) -> eyre::Result<()> {
+    use sequencer_client::Client as _;
+
+    let mut interval = tokio::time::interval(SEQUENCER_SYNC_POLL_INTERVAL);
+    tokio::time::timeout(SEQUENCER_SYNC_TIMEOUT, async move {
+        loop {
+            interval.tick().await;
+            match client.status().await {
+                Ok(status) => {
+                    state.set_sequencer_connected(true);
+                    if !status.sync_info.catching_up {
+                        break;
+                    }
+                    info!(
+                        sequencer.height = status.sync_info.latest_block_height.value(),
+                        "sequencer node is catching up; waiting before submitting transactions",
+                    );
+                }
+                Err(error) => {
+                    state.set_sequencer_connected(false);
+                    warn!(
+                        error = &error as &dyn std::error::Error,
+                        "failed to fetch sequencer node status; retrying",
+                    );
+                }
+            }
+        }
+    })
+    .await
+    .wrap_err("timed out waiting for sequencer node to finish catching up")
+}
+
 #[instrument(skip_all)]
 async fn get_sequencer_chain_id(
     client: sequencer_client::HttpClient,
@@ -705,30 +1257,40 @@ async fn get_tx(
     res
 }
 
+/// Returns the rollup height recorded in the memo of `action`, or `None` if `action` is not a
+/// withdrawal.
+fn rollup_height_of_action(action: &Action) -> Option<eyre::Result<u64>> {
+    match action {
+        Action::BridgeUnlock(action) => Some(
+            serde_json::from_slice::<BridgeUnlockMemo>(&action.memo)
+                .map(|memo| memo.block_number.as_u64())
+                .wrap_err("failed to parse memo of bridge unlock action"),
+        ),
+        Action::Ics20Withdrawal(action) => Some(
+            serde_json::from_str::<Ics20WithdrawalFromRollupMemo>(&action.memo)
+                .map(|memo| memo.block_number)
+                .wrap_err("failed to parse memo of ics20 withdrawal action"),
+        ),
+        _ => None,
+    }
+}
+
 fn rollup_height_from_signed_transaction(
     signed_transaction: &SignedTransaction,
 ) -> eyre::Result<u64> {
-    // find the last batch's rollup block height
-    let withdrawal_action = signed_transaction
-        .actions()
-        .iter()
-        .find(|action| matches!(action, Action::BridgeUnlock(_) | Action::Ics20Withdrawal(_)))
-        .ok_or_eyre("last transaction by the bridge account did not contain a withdrawal action")?;
-
-    let last_batch_rollup_height = match withdrawal_action {
-        Action::BridgeUnlock(action) => {
-            let memo: BridgeUnlockMemo = serde_json::from_slice(&action.memo)
-                .wrap_err("failed to parse memo from last transaction by the bridge account")?;
-            Some(memo.block_number.as_u64())
-        }
-        Action::Ics20Withdrawal(action) => {
-            let memo: Ics20WithdrawalFromRollupMemo = serde_json::from_str(&action.memo)
-                .wrap_err("failed to parse memo from last transaction by the bridge account")?;
-            Some(memo.block_number)
-        }
-        _ => None,
+    // find the last batch's rollup block height. Merged batches carry withdrawals from several
+    // rollup heights, so the greatest of them is the height of the last batch.
+    let mut last_batch_rollup_height = None;
+    for action in signed_transaction.actions() {
+        let Some(rollup_height) = rollup_height_of_action(action) else {
+            continue;
+        };
+        let rollup_height = rollup_height
+            .wrap_err("failed to parse memo from last transaction by the bridge account")?;
+        last_batch_rollup_height = last_batch_rollup_height.max(Some(rollup_height));
     }
-    .expect("action is already checked to be either BridgeUnlock or Ics20Withdrawal");
+    let last_batch_rollup_height = last_batch_rollup_height
+        .ok_or_eyre("last transaction by the bridge account did not contain a withdrawal action")?;
 
     info!(
         last_batch.tx_hash = %telemetry::display::hex(&signed_transaction.sha256_of_proto_encoding()),
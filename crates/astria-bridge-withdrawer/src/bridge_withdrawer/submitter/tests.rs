@@ -1,7 +1,10 @@
 use std::{
     collections::HashMap,
     io::Write as _,
-    sync::Arc,
+    sync::{
+        Arc,
+        Mutex,
+    },
     time::Duration,
     vec,
 };
@@ -20,6 +23,7 @@ use astria_core::{
         ASTRIA_ADDRESS_PREFIX,
     },
     protocol::{
+        abci::AbciErrorCode,
         account::v1alpha1::AssetBalance,
         bridge::v1alpha1::BridgeAccountLastTxHashResponse,
         transaction::v1alpha1::{
@@ -67,7 +71,23 @@ use tendermint_rpc::{
 };
 use tokio::task::JoinHandle;
 use tokio_util::sync::CancellationToken;
-use tracing::debug;
+use tracing::{
+    debug,
+    field::{
+        Field,
+        Visit,
+    },
+    Event,
+    Level,
+    Subscriber,
+};
+use tracing_subscriber::{
+    layer::{
+        Context as LayerContext,
+        SubscriberExt as _,
+    },
+    Layer,
+};
 use wiremock::{
     matchers::{
         body_partial_json,
@@ -80,10 +100,27 @@ use wiremock::{
     ResponseTemplate,
 };
 
-use super::Submitter;
+use super::{
+    audit::{
+        AuditLog,
+        DeadletterLog,
+    },
+    clock::{
+        Clock,
+        MockClock,
+    },
+    deferred::DeferredStore,
+    destination_cap::DestinationCap,
+    rollup_height_from_signed_transaction,
+    Submitter,
+    BATCH_QUEUE_DWELL_WARNING_THRESHOLD,
+};
 use crate::{
     bridge_withdrawer::{
-        batch::Batch,
+        batch::{
+            Batch,
+            BatchSizeTarget,
+        },
         ethereum::convert::BridgeUnlockMemo,
         state,
         submitter,
@@ -95,8 +132,50 @@ const SEQUENCER_CHAIN_ID: &str = "test_sequencer-1000";
 const DEFAULT_LAST_ROLLUP_HEIGHT: u64 = 1;
 const DEFAULT_LAST_SEQUENCER_HEIGHT: u64 = 0;
 const DEFAULT_SEQUENCER_NONCE: u32 = 0;
+const STARTUP_MAX_ATTEMPTS: u32 = 3;
 const DEFAULT_IBC_DENOM: &str = "transfer/channel-0/utia";
 
+/// Copied from the sequencer-relayer blackbox tests.
+const STATUS_RESPONSE: &str = r#"
+{
+  "node_info": {
+    "protocol_version": {
+      "p2p": "8",
+      "block": "11",
+      "app": "0"
+    },
+    "id": "a1d3bbddb7800c6da2e64169fec281494e963ba3",
+    "listen_addr": "tcp://0.0.0.0:26656",
+    "network": "test",
+    "version": "0.38.6",
+    "channels": "40202122233038606100",
+    "moniker": "fullnode",
+    "other": {
+      "tx_index": "on",
+      "rpc_address": "tcp://0.0.0.0:26657"
+    }
+  },
+  "sync_info": {
+    "latest_block_hash": "A4202E4E367712AC2A797860265A7EBEA8A3ACE513CB0105C2C9058449641202",
+    "latest_app_hash": "BCC9C9B82A49EC37AADA41D32B4FBECD2441563703955413195BDA2236775A68",
+    "latest_block_height": "452605",
+    "latest_block_time": "2024-05-09T15:59:17.849713071Z",
+    "earliest_block_hash": "C34B7B0B82423554B844F444044D7D08A026D6E413E6F72848DB2F8C77ACE165",
+    "earliest_app_hash": "6B776065775471CEF46AC75DE09A4B869A0E0EB1D7725A04A342C0E46C16F472",
+    "earliest_block_height": "1",
+    "earliest_block_time": "2024-04-23T00:49:11.964127Z",
+    "catching_up": false
+  },
+  "validator_info": {
+    "address": "0B46F33BA2FA5C2E2AD4C4C4E5ECE3F1CA03D195",
+    "pub_key": {
+      "type": "tendermint/PubKeyEd25519",
+      "value": "bA6GipHUijVuiYhv+4XymdePBsn8EeTqjGqNQrBGZ4I="
+    },
+    "voting_power": "0"
+  }
+}"#;
+
 static TELEMETRY: Lazy<()> = Lazy::new(|| {
     if std::env::var_os("TEST_LOG").is_some() {
         let filter_directives = std::env::var("RUST_LOG").unwrap_or_else(|_| "info".into());
@@ -125,6 +204,17 @@ struct TestSubmitter {
 
 impl TestSubmitter {
     async fn setup() -> Self {
+        Self::setup_with(submitter::Builder::build, None).await
+    }
+
+    async fn setup_without_startup() -> Self {
+        Self::setup_with(submitter::Builder::build_without_startup, None).await
+    }
+
+    async fn setup_with(
+        build: fn(submitter::Builder) -> eyre::Result<(Submitter, submitter::Handle)>,
+        clock: Option<Arc<dyn Clock>>,
+    ) -> Self {
         Lazy::force(&TELEMETRY);
 
         // set up external resources
@@ -150,17 +240,27 @@ impl TestSubmitter {
 
         let metrics = Box::leak(Box::new(Metrics::new()));
 
-        let (submitter, submitter_handle) = submitter::Builder {
+        let (submitter, submitter_handle) = build(submitter::Builder {
             shutdown_token: shutdown_token.clone(),
             sequencer_key_path,
             sequencer_chain_id: SEQUENCER_CHAIN_ID.to_string(),
             sequencer_cometbft_endpoint,
+            startup_max_attempts: STARTUP_MAX_ATTEMPTS,
+            startup_retry_backoff: Duration::from_millis(10),
             state,
             expected_fee_asset_id: default_native_asset().id(),
             min_expected_fee_asset_balance: 1_000_000,
+            min_batch_size: 1,
+            max_batch_size: 256,
+            audit_log_path: None,
+            deadletter_log_path: None,
+            rollup_asset_denom: default_native_asset(),
+            destination_withdrawal_cap: None,
+            destination_withdrawal_cap_window: Duration::from_secs(86_400),
+            deferred_withdrawals_path: None,
+            clock,
             metrics,
-        }
-        .build()
+        })
         .unwrap();
 
         Self {
@@ -247,6 +347,10 @@ async fn register_default_last_bridge_tx_guard(cometbft_mock: &MockServer) -> Mo
 
 async fn register_startup_guards(cometbft_mock: &MockServer) -> HashMap<String, MockGuard> {
     HashMap::from([
+        (
+            "sequencer_sync".to_string(),
+            register_sequencer_status_guard(cometbft_mock, false).await,
+        ),
         (
             "chain_id".to_string(),
             register_default_chain_id_guard(cometbft_mock).await,
@@ -305,6 +409,10 @@ fn make_ics20_withdrawal_action() -> Action {
 }
 
 fn make_bridge_unlock_action() -> Action {
+    make_bridge_unlock_action_at(DEFAULT_LAST_ROLLUP_HEIGHT)
+}
+
+fn make_bridge_unlock_action_at(rollup_height: u64) -> Action {
     let denom = default_native_asset();
     let inner = BridgeUnlockAction {
         to: Address::builder()
@@ -314,7 +422,7 @@ fn make_bridge_unlock_action() -> Action {
             .unwrap(),
         amount: 99,
         memo: serde_json::to_vec(&BridgeUnlockMemo {
-            block_number: DEFAULT_LAST_ROLLUP_HEIGHT.into(),
+            block_number: rollup_height.into(),
             transaction_hash: [1u8; 32].into(),
         })
         .unwrap(),
@@ -352,6 +460,32 @@ fn make_tx_commit_check_tx_failure_response() -> tx_commit::Response {
     }
 }
 
+fn make_tx_commit_non_retriable_check_tx_failure_response() -> tx_commit::Response {
+    tx_commit::Response {
+        check_tx: CheckTx {
+            code: AbciErrorCode::INVALID_PARAMETER.into(),
+            log: "invalid action".to_string(),
+            ..CheckTx::default()
+        },
+        tx_result: ExecTxResult::default(),
+        hash: vec![0u8; 32].try_into().unwrap(),
+        height: Height::default(),
+    }
+}
+
+fn make_tx_commit_too_large_response() -> tx_commit::Response {
+    tx_commit::Response {
+        check_tx: CheckTx {
+            code: AbciErrorCode::TRANSACTION_TOO_LARGE.into(),
+            log: "transaction too large".to_string(),
+            ..CheckTx::default()
+        },
+        tx_result: ExecTxResult::default(),
+        hash: vec![0u8; 32].try_into().unwrap(),
+        height: Height::default(),
+    }
+}
+
 fn make_tx_commit_deliver_tx_failure_response() -> tx_commit::Response {
     tx_commit::Response {
         check_tx: CheckTx::default(),
@@ -368,6 +502,7 @@ fn make_last_bridge_tx_hash_response() -> BridgeAccountLastTxHashResponse {
     BridgeAccountLastTxHashResponse {
         height: DEFAULT_LAST_ROLLUP_HEIGHT,
         tx_hash: Some([0u8; 32]),
+        block_hash: None,
     }
 }
 
@@ -476,6 +611,26 @@ async fn register_genesis_chain_id_response(chain_id: &str, server: &MockServer)
         .await
 }
 
+async fn register_sequencer_status_guard(server: &MockServer, catching_up: bool) -> MockGuard {
+    use tendermint_rpc::endpoint::status;
+
+    let mut status_response: status::Response = serde_json::from_str(STATUS_RESPONSE).unwrap();
+    status_response.sync_info.catching_up = catching_up;
+
+    let wrapper =
+        response::Wrapper::new_with_id(tendermint_rpc::Id::Num(1), Some(status_response), None);
+    Mock::given(body_partial_json(json!({"method": "status"})))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(&wrapper)
+                .append_header("Content-Type", "application/json"),
+        )
+        .up_to_n_times(1)
+        .expect(1)
+        .mount_as_scoped(server)
+        .await
+}
+
 async fn register_allowed_fee_asset_ids_response(
     fee_asset_ids: Vec<asset::Id>,
     cometbft_mock: &MockServer,
@@ -576,6 +731,31 @@ async fn register_get_nonce_response(server: &MockServer, response: NonceRespons
         .await
 }
 
+/// Registers a nonce response that is expected to be fetched `times` times.
+async fn register_repeated_get_nonce_response(
+    server: &MockServer,
+    response: NonceResponse,
+    times: u64,
+) -> MockGuard {
+    let response = tendermint_rpc::endpoint::abci_query::Response {
+        response: tendermint_rpc::endpoint::abci_query::AbciQuery {
+            value: response.encode_to_vec(),
+            ..Default::default()
+        },
+    };
+    let wrapper = response::Wrapper::new_with_id(tendermint_rpc::Id::Num(1), Some(response), None);
+    Mock::given(body_partial_json(json!({"method": "abci_query"})))
+        .and(body_string_contains("accounts/nonce"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(&wrapper)
+                .append_header("Content-Type", "application/json"),
+        )
+        .expect(times)
+        .mount_as_scoped(server)
+        .await
+}
+
 async fn register_tx_guard(server: &MockServer, response: tx::Response) -> MockGuard {
     let wrapper = response::Wrapper::new_with_id(tendermint_rpc::Id::Num(1), Some(response), None);
     Mock::given(body_partial_json(json!({"method": "tx"})))
@@ -625,6 +805,145 @@ async fn submitter_startup_success() {
     let _submitter = TestSubmitter::spawn().await;
 }
 
+/// Registers responses that make one startup handshake fail because the sequencer reports a
+/// different chain id than configured.
+async fn register_failing_startup_guards(
+    cometbft_mock: &MockServer,
+    attempt: u32,
+) -> HashMap<String, MockGuard> {
+    HashMap::from([
+        (
+            format!("failing_sequencer_sync_{attempt}"),
+            register_sequencer_status_guard(cometbft_mock, false).await,
+        ),
+        (
+            format!("failing_chain_id_{attempt}"),
+            register_genesis_chain_id_response("other-chain-1", cometbft_mock).await,
+        ),
+    ])
+}
+
+/// Test that a startup handshake that fails twice is retried until it succeeds, after which the
+/// submitter runs
+#[tokio::test]
+async fn submitter_startup_is_retried_until_it_succeeds() {
+    let mut test_submitter = TestSubmitter::setup().await;
+
+    // mocks respond in the order they were mounted, so the first two handshakes see the wrong
+    // chain id and only the third sees the default responses
+    let mut guards = HashMap::new();
+    for attempt in 1..=2 {
+        guards
+            .extend(register_failing_startup_guards(&test_submitter.cometbft_mock, attempt).await);
+    }
+    guards.extend(register_startup_guards(&test_submitter.cometbft_mock).await);
+    guards.extend(register_sync_guards(&test_submitter.cometbft_mock).await);
+    test_submitter.startup_and_spawn_with_guards(guards).await;
+
+    assert!(
+        !test_submitter
+            .submitter_task_handle
+            .as_ref()
+            .unwrap()
+            .is_finished(),
+        "the submitter must keep running after a successful startup"
+    );
+}
+
+/// Test that the submitter exits with an error once all attempts of the startup handshake failed
+#[tokio::test]
+async fn submitter_startup_gives_up_after_max_attempts() {
+    let mut test_submitter = TestSubmitter::setup().await;
+
+    let mut guards = Vec::new();
+    for attempt in 1..=STARTUP_MAX_ATTEMPTS {
+        guards.push(register_failing_startup_guards(&test_submitter.cometbft_mock, attempt).await);
+    }
+
+    let submitter = test_submitter.submitter.take().unwrap();
+    let error = tokio::time::timeout(Duration::from_secs(1), submitter.run())
+        .await
+        .expect("the submitter must give up on startup in time")
+        .expect_err("the submitter must fail to start up");
+    assert!(
+        format!("{error:#}").contains(&format!("after {STARTUP_MAX_ATTEMPTS} attempts")),
+        "unexpected error: {error:#}"
+    );
+}
+
+/// Test that the submitter does not broadcast any transactions while the sequencer node is still
+/// catching up, and resumes once it reports being synced.
+#[tokio::test]
+async fn submitter_waits_for_sequencer_sync_before_submitting() {
+    let mut test_submitter = TestSubmitter::setup().await;
+
+    let catching_up_guard =
+        register_sequencer_status_guard(&test_submitter.cometbft_mock, true).await;
+    let nonce_guard = register_get_nonce_response(
+        &test_submitter.cometbft_mock,
+        NonceResponse {
+            height: 1,
+            nonce: 0,
+        },
+    )
+    .await;
+    let broadcast_guard = register_broadcast_tx_commit_response(
+        &test_submitter.cometbft_mock,
+        make_tx_commit_success_response(),
+    )
+    .await;
+
+    // queue a batch before the submitter has started up
+    let batch = make_batch_with_bridge_unlock_and_ics20_withdrawal();
+    test_submitter
+        .submitter_handle
+        .send_batch(batch)
+        .await
+        .unwrap();
+
+    let submitter = test_submitter.submitter.take().unwrap();
+    let _submitter_task = tokio::spawn(submitter.run());
+
+    tokio::time::timeout(
+        Duration::from_millis(100),
+        catching_up_guard.wait_until_satisfied(),
+    )
+    .await
+    .unwrap();
+    assert!(
+        broadcast_guard.received_requests().await.is_empty(),
+        "no transaction must be broadcast while the sequencer node is catching up"
+    );
+
+    // the sequencer node is now synced; startup proceeds and the queued batch is broadcast
+    let startup_guards = register_startup_guards(&test_submitter.cometbft_mock).await;
+    let sync_guards = register_sync_guards(&test_submitter.cometbft_mock).await;
+    for (name, guard) in startup_guards.into_iter().chain(sync_guards) {
+        tokio::time::timeout(Duration::from_secs(2), guard.wait_until_satisfied())
+            .await
+            .wrap_err(format!("{name} guard not satisfied in time."))
+            .unwrap();
+    }
+    test_submitter
+        .submitter_handle
+        .recv_startup_info()
+        .await
+        .unwrap();
+
+    tokio::time::timeout(
+        Duration::from_millis(100),
+        nonce_guard.wait_until_satisfied(),
+    )
+    .await
+    .unwrap();
+    tokio::time::timeout(
+        Duration::from_millis(100),
+        broadcast_guard.wait_until_satisfied(),
+    )
+    .await
+    .unwrap();
+}
+
 /// Sanity check to check that batch submission works
 #[tokio::test]
 async fn submitter_submit_success() {
@@ -792,3 +1111,1062 @@ async fn submitter_submit_deliver_tx_failure() {
     .unwrap()
     .unwrap();
 }
+
+/// Test that a batch rejected for a non-retriable reason is moved to the deadletter queue, that
+/// its withdrawals are written to the deadletter log, that it is not resubmitted, and that the
+/// submitter keeps running.
+#[tokio::test]
+async fn submitter_moves_non_retriable_rejection_to_deadletter() {
+    let deadletter_file = NamedTempFile::new().unwrap();
+    let mut test_submitter = TestSubmitter::setup().await;
+    let submitter = test_submitter.submitter.as_mut().unwrap();
+    submitter.deadletter_log =
+        DeadletterLog::open(Some(deadletter_file.path()), default_native_asset()).unwrap();
+    let mut state = submitter.state.subscribe();
+    test_submitter.startup_and_spawn().await;
+    let TestSubmitter {
+        submitter_handle,
+        cometbft_mock,
+        submitter_task_handle,
+        ..
+    } = test_submitter;
+
+    // set up guards on mock cometbft
+    let nonce_guard = register_get_nonce_response(
+        &cometbft_mock,
+        NonceResponse {
+            height: 1,
+            nonce: 0,
+        },
+    )
+    .await;
+
+    let broadcast_guard = register_broadcast_tx_commit_response(
+        &cometbft_mock,
+        make_tx_commit_non_retriable_check_tx_failure_response(),
+    )
+    .await;
+
+    // send batch to submitter
+    let batch = make_batch_with_bridge_unlock_and_ics20_withdrawal();
+    let expected_batch_id = hex::encode(batch.idempotency_id());
+    let expected_rollup_height = batch.rollup_height;
+    submitter_handle.send_batch(batch).await.unwrap();
+
+    // wait for the nonce and broadcast guards to be satisfied
+    tokio::time::timeout(
+        Duration::from_millis(100),
+        nonce_guard.wait_until_satisfied(),
+    )
+    .await
+    .unwrap();
+    tokio::time::timeout(
+        Duration::from_millis(100),
+        broadcast_guard.wait_until_satisfied(),
+    )
+    .await
+    .unwrap();
+
+    // the batch is moved to the deadletter queue
+    let snapshot = tokio::time::timeout(
+        Duration::from_millis(100),
+        state.wait_for(|state| !state.deadletter().is_empty()),
+    )
+    .await
+    .unwrap()
+    .unwrap()
+    .clone();
+    let deadletter = snapshot.deadletter();
+    assert_eq!(1, deadletter.len());
+    assert_eq!(expected_batch_id, deadletter[0].batch_id);
+    assert_eq!(expected_rollup_height, deadletter[0].rollup_height);
+    assert_eq!(2, deadletter[0].unexecuted_actions);
+    assert!(deadletter[0].reason.contains("invalid action"));
+
+    // every withdrawal of the batch is recorded for operators
+    let records: Vec<serde_json::Value> = std::fs::read_to_string(deadletter_file.path())
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(
+        vec!["ics20_withdrawal", "bridge_unlock"],
+        records
+            .iter()
+            .map(|record| record["kind"].as_str().unwrap())
+            .collect::<Vec<_>>(),
+    );
+    for record in &records {
+        assert_eq!(expected_batch_id, record["batch_id"]);
+        assert_eq!(DEFAULT_LAST_ROLLUP_HEIGHT, record["rollup_height"]);
+        assert!(record["reason"]
+            .as_str()
+            .unwrap()
+            .contains("invalid action"));
+    }
+
+    // the submitter neither retries the batch nor halts
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert!(!submitter_task_handle.unwrap().is_finished());
+}
+
+#[tokio::test]
+async fn send_batch_to_closed_channel_returns_batch() {
+    let (_startup_tx, startup_rx) = tokio::sync::oneshot::channel();
+    let (batches_tx, batches_rx) = tokio::sync::mpsc::channel(1);
+    let handle = submitter::Handle::new(Some(startup_rx), batches_tx);
+    drop(batches_rx);
+
+    let batch = make_batch_with_bridge_unlock_and_ics20_withdrawal();
+    let expected_id = batch.idempotency_id();
+    let expected_rollup_height = batch.rollup_height;
+
+    let unsent = handle.send_batch(batch).await.unwrap_err().0;
+    assert_eq!(expected_id, unsent.idempotency_id());
+    assert_eq!(expected_rollup_height, unsent.rollup_height);
+}
+
+#[tokio::test]
+async fn send_batch_timeout_returns_batch_if_queue_stays_full() {
+    let (_startup_tx, startup_rx) = tokio::sync::oneshot::channel();
+    let (batches_tx, _batches_rx) = tokio::sync::mpsc::channel(1);
+    let handle = submitter::Handle::new(Some(startup_rx), batches_tx);
+
+    // fill the queue
+    handle
+        .send_batch(make_batch_with_bridge_unlock_and_ics20_withdrawal())
+        .await
+        .unwrap();
+
+    let batch = make_batch_with_bridge_unlock_and_ics20_withdrawal();
+    let expected_id = batch.idempotency_id();
+    let error = handle
+        .send_batch_timeout(batch, Duration::from_millis(10))
+        .await
+        .unwrap_err();
+    let tokio::sync::mpsc::error::SendTimeoutError::Timeout(unsent) = error else {
+        panic!("expected the send to time out, got: {error:?}");
+    };
+    assert_eq!(expected_id, unsent.idempotency_id());
+}
+
+#[tokio::test]
+async fn send_batch_timeout_succeeds_if_slot_frees_up_before_deadline() {
+    let (_startup_tx, startup_rx) = tokio::sync::oneshot::channel();
+    let (batches_tx, mut batches_rx) = tokio::sync::mpsc::channel(1);
+    let handle = submitter::Handle::new(Some(startup_rx), batches_tx);
+
+    // fill the queue, then free up the slot after a short delay
+    handle
+        .send_batch(make_batch_with_bridge_unlock_and_ics20_withdrawal())
+        .await
+        .unwrap();
+    let receiver = tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        let first = batches_rx.recv().await.unwrap();
+        let second = batches_rx.recv().await.unwrap();
+        (first, second)
+    });
+
+    let mut batch = make_batch_with_bridge_unlock_and_ics20_withdrawal();
+    batch.rollup_height = 11;
+    handle
+        .send_batch_timeout(batch, Duration::from_secs(1))
+        .await
+        .unwrap();
+
+    let (first, second) = receiver.await.unwrap();
+    assert_eq!(10, first.batch.rollup_height);
+    assert_eq!(11, second.batch.rollup_height);
+}
+
+#[tokio::test(start_paused = true)]
+async fn send_batch_keeps_waiting_on_a_full_queue_past_the_warning_interval() {
+    let (_startup_tx, startup_rx) = tokio::sync::oneshot::channel();
+    let (batches_tx, mut batches_rx) = tokio::sync::mpsc::channel(1);
+    let handle = submitter::Handle::new(Some(startup_rx), batches_tx);
+
+    // fill the queue, then free up the slot only after several warning intervals have passed
+    handle
+        .send_batch(make_batch_with_bridge_unlock_and_ics20_withdrawal())
+        .await
+        .unwrap();
+    let receiver = tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_secs(100)).await;
+        let first = batches_rx.recv().await.unwrap();
+        let second = batches_rx.recv().await.unwrap();
+        (first, second)
+    });
+
+    let mut batch = make_batch_with_bridge_unlock_and_ics20_withdrawal();
+    batch.rollup_height = 11;
+    handle.send_batch(batch).await.unwrap();
+
+    let (first, second) = receiver.await.unwrap();
+    assert_eq!(10, first.batch.rollup_height);
+    assert_eq!(11, second.batch.rollup_height);
+}
+
+/// Test that a submitter built without startup is ready immediately and submits batches without
+/// querying the sequencer for its startup information
+#[tokio::test]
+async fn submitter_without_startup_submits_batch() {
+    let mut test_submitter = TestSubmitter::setup_without_startup().await;
+    let submitter = test_submitter.submitter.take().unwrap();
+    let mut state = submitter.state.subscribe();
+    let _submitter_task = tokio::spawn(submitter.run());
+
+    // no startup guards are registered, so the submitter would fail if it ran startup
+    tokio::time::timeout(
+        Duration::from_millis(100),
+        state.wait_for(state::StateSnapshot::is_ready),
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    assert!(test_submitter
+        .submitter_handle
+        .recv_startup_info()
+        .await
+        .is_err());
+
+    let nonce_guard = register_get_nonce_response(
+        &test_submitter.cometbft_mock,
+        NonceResponse {
+            height: 1,
+            nonce: 0,
+        },
+    )
+    .await;
+    let broadcast_guard = register_broadcast_tx_commit_response(
+        &test_submitter.cometbft_mock,
+        make_tx_commit_success_response(),
+    )
+    .await;
+
+    test_submitter
+        .submitter_handle
+        .send_batch(make_batch_with_bridge_unlock_and_ics20_withdrawal())
+        .await
+        .unwrap();
+
+    tokio::time::timeout(
+        Duration::from_millis(100),
+        nonce_guard.wait_until_satisfied(),
+    )
+    .await
+    .unwrap();
+    tokio::time::timeout(
+        Duration::from_millis(100),
+        broadcast_guard.wait_until_satisfied(),
+    )
+    .await
+    .unwrap();
+}
+
+/// Test that every withdrawal of an executed transaction is appended to the audit log as a line
+/// of JSON.
+#[tokio::test]
+async fn executed_batch_is_written_to_audit_log() {
+    let audit_file = NamedTempFile::new().unwrap();
+    let mut test_submitter = TestSubmitter::setup_without_startup().await;
+    let mut submitter = test_submitter.submitter.take().unwrap();
+    submitter.audit_log = Some(AuditLog::open(audit_file.path(), default_native_asset()).unwrap());
+    let _submitter_task = tokio::spawn(submitter.run());
+
+    let nonce_guard = register_get_nonce_response(
+        &test_submitter.cometbft_mock,
+        NonceResponse {
+            height: 1,
+            nonce: 0,
+        },
+    )
+    .await;
+    let broadcast_guard = register_broadcast_tx_commit_response(
+        &test_submitter.cometbft_mock,
+        make_tx_commit_success_response(),
+    )
+    .await;
+
+    test_submitter
+        .submitter_handle
+        .send_batch(make_batch_with_bridge_unlock_and_ics20_withdrawal())
+        .await
+        .unwrap();
+
+    for guard in [&nonce_guard, &broadcast_guard] {
+        tokio::time::timeout(Duration::from_millis(100), guard.wait_until_satisfied())
+            .await
+            .unwrap();
+    }
+
+    let lines = tokio::time::timeout(Duration::from_millis(100), async {
+        loop {
+            let contents = std::fs::read_to_string(audit_file.path()).unwrap();
+            if contents.lines().count() == 2 {
+                break contents;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("both withdrawals must be written to the audit log");
+    let records: Vec<serde_json::Value> = lines
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+
+    let committed = make_tx_commit_success_response();
+    let sequencer_height = committed.height.value();
+    let sequencer_transaction_hash = hex::encode(committed.hash.as_bytes());
+    let Action::BridgeUnlock(bridge_unlock) = make_bridge_unlock_action() else {
+        unreachable!("the helper constructs a bridge unlock action");
+    };
+    assert_eq!(
+        vec![
+            json!({
+                "rollup_height": DEFAULT_LAST_ROLLUP_HEIGHT,
+                "rollup_transaction_hash": hex::encode([2u8; 32]),
+                "kind": "ics20_withdrawal",
+                "amount": "99",
+                "asset": DEFAULT_IBC_DENOM,
+                "destination": "address",
+                "sequencer_height": sequencer_height,
+                "sequencer_transaction_hash": sequencer_transaction_hash,
+            }),
+            json!({
+                "rollup_height": DEFAULT_LAST_ROLLUP_HEIGHT,
+                "rollup_transaction_hash": hex::encode([1u8; 32]),
+                "kind": "bridge_unlock",
+                "amount": "99",
+                "asset": default_native_asset().to_string(),
+                "destination": bridge_unlock.to.to_string(),
+                "sequencer_height": sequencer_height,
+                "sequencer_transaction_hash": sequencer_transaction_hash,
+            }),
+        ],
+        records,
+    );
+}
+
+/// Test that withdrawals exceeding the cap of their destination are deferred while withdrawals to
+/// other destinations are submitted, and that they are submitted with the next batch once the cap
+/// window has passed.
+#[tokio::test]
+async fn withdrawals_exceeding_destination_cap_are_deferred() {
+    const CAP_WINDOW: Duration = Duration::from_secs(86_400);
+
+    let clock = Arc::new(MockClock::new());
+    let mut test_submitter = TestSubmitter::setup_with(
+        submitter::Builder::build_without_startup,
+        Some(clock.clone()),
+    )
+    .await;
+    let mut submitter = test_submitter.submitter.take().unwrap();
+    submitter.destination_cap = Some(DestinationCap::new(100, CAP_WINDOW));
+    let state = submitter.state.subscribe();
+    let _submitter_task = tokio::spawn(submitter.run());
+
+    let nonce_guard = register_get_nonce_response(
+        &test_submitter.cometbft_mock,
+        NonceResponse {
+            height: 1,
+            nonce: 0,
+        },
+    )
+    .await;
+    let broadcast_guard = register_broadcast_tx_commit_response(
+        &test_submitter.cometbft_mock,
+        make_tx_commit_success_response(),
+    )
+    .await;
+
+    // the second bridge unlock of 99 to the same address exceeds the cap of 100
+    let mut batch = make_batch_with_bridge_unlock_and_ics20_withdrawal();
+    batch.actions.push(make_bridge_unlock_action());
+    test_submitter
+        .submitter_handle
+        .send_batch(batch)
+        .await
+        .unwrap();
+
+    for guard in [&nonce_guard, &broadcast_guard] {
+        tokio::time::timeout(Duration::from_millis(100), guard.wait_until_satisfied())
+            .await
+            .unwrap();
+    }
+
+    let requests = broadcast_guard.received_requests().await;
+    let signed_transaction = signed_tx_from_request(&requests[0]);
+    let expected_batch = make_batch_with_bridge_unlock_and_ics20_withdrawal();
+    assert_eq!(
+        expected_batch.actions.len(),
+        signed_transaction.actions().len()
+    );
+    expected_batch
+        .actions
+        .iter()
+        .zip(signed_transaction.actions())
+        .for_each(|(expected, actual)| compare_actions(expected, actual));
+    drop((nonce_guard, broadcast_guard));
+
+    clock.advance(CAP_WINDOW);
+    let nonce_guard = register_get_nonce_response(
+        &test_submitter.cometbft_mock,
+        NonceResponse {
+            height: 2,
+            nonce: 1,
+        },
+    )
+    .await;
+    let broadcast_guard = register_broadcast_tx_commit_response(
+        &test_submitter.cometbft_mock,
+        make_tx_commit_success_response(),
+    )
+    .await;
+
+    let Action::BridgeUnlock(mut to_other_destination) = make_bridge_unlock_action_at(11) else {
+        unreachable!("the helper constructs a bridge unlock action");
+    };
+    to_other_destination.to = Address::builder()
+        .array([1u8; 20])
+        .prefix(ASTRIA_ADDRESS_PREFIX)
+        .try_build()
+        .unwrap();
+    let to_other_destination = Action::BridgeUnlock(to_other_destination);
+    test_submitter
+        .submitter_handle
+        .send_batch(Batch {
+            actions: vec![to_other_destination.clone()],
+            rollup_height: 11,
+        })
+        .await
+        .unwrap();
+
+    for guard in [&nonce_guard, &broadcast_guard] {
+        tokio::time::timeout(Duration::from_millis(100), guard.wait_until_satisfied())
+            .await
+            .unwrap();
+    }
+
+    // the deferred withdrawal goes first, in the same transaction as the next batch
+    let requests = broadcast_guard.received_requests().await;
+    let signed_transaction = signed_tx_from_request(&requests[0]);
+    let expected_actions = [make_bridge_unlock_action(), to_other_destination];
+    assert_eq!(expected_actions.len(), signed_transaction.actions().len());
+    expected_actions
+        .iter()
+        .zip(signed_transaction.actions())
+        .for_each(|(expected, actual)| compare_actions(expected, actual));
+    assert!(state.borrow().deadletter().is_empty());
+}
+
+/// Test that withdrawals deferred by the destination cap are not lost if the submitter stops
+/// without shutting down, and that the restarted submitter submits them with a later batch.
+#[tokio::test]
+async fn deferred_withdrawals_are_submitted_after_restart() {
+    const CAP_WINDOW: Duration = Duration::from_secs(86_400);
+
+    let deferred_dir = tempfile::tempdir().unwrap();
+    let deferred_path = deferred_dir.path().join("deferred.jsonl");
+
+    let mut test_submitter =
+        TestSubmitter::setup_with(submitter::Builder::build, Some(Arc::new(MockClock::new())))
+            .await;
+    let submitter = test_submitter.submitter.as_mut().unwrap();
+    submitter.destination_cap = Some(DestinationCap::new(100, CAP_WINDOW));
+    submitter.deferred_store = Some(DeferredStore::new(&deferred_path));
+    test_submitter.startup_and_spawn().await;
+
+    let nonce_guard = register_get_nonce_response(
+        &test_submitter.cometbft_mock,
+        NonceResponse {
+            height: 1,
+            nonce: 0,
+        },
+    )
+    .await;
+    let broadcast_guard = register_broadcast_tx_commit_response(
+        &test_submitter.cometbft_mock,
+        make_tx_commit_success_response(),
+    )
+    .await;
+
+    // the second bridge unlock of 99 to the same address exceeds the cap of 100
+    let mut batch = make_batch_with_bridge_unlock_and_ics20_withdrawal();
+    batch.actions.push(make_bridge_unlock_action());
+    test_submitter
+        .submitter_handle
+        .send_batch(batch)
+        .await
+        .unwrap();
+
+    for guard in [&nonce_guard, &broadcast_guard] {
+        tokio::time::timeout(Duration::from_millis(100), guard.wait_until_satisfied())
+            .await
+            .unwrap();
+    }
+    let requests = broadcast_guard.received_requests().await;
+    let executed_tx = signed_tx_from_request(&requests[0]);
+    assert_eq!(
+        make_batch_with_bridge_unlock_and_ics20_withdrawal()
+            .actions
+            .len(),
+        executed_tx.actions().len()
+    );
+    drop((nonce_guard, broadcast_guard));
+
+    // stop the submitter without shutting it down
+    test_submitter.submitter_task_handle.take().unwrap().abort();
+
+    // restart, with the executed transaction as the bridge account's last one
+    let clock = Arc::new(MockClock::new());
+    let mut restarted =
+        TestSubmitter::setup_with(submitter::Builder::build, Some(clock.clone())).await;
+    let submitter = restarted.submitter.as_mut().unwrap();
+    submitter.destination_cap = Some(DestinationCap::new(100, CAP_WINDOW));
+    submitter.deferred_store = Some(DeferredStore::new(&deferred_path));
+    let last_tx_guard = register_tx_guard(
+        &restarted.cometbft_mock,
+        tx::Response {
+            hash: executed_tx
+                .sha256_of_proto_encoding()
+                .to_vec()
+                .try_into()
+                .unwrap(),
+            height: DEFAULT_LAST_SEQUENCER_HEIGHT.try_into().unwrap(),
+            index: 0,
+            tx_result: ExecTxResult {
+                code: abci::Code::Ok,
+                ..ExecTxResult::default()
+            },
+            tx: executed_tx.into_raw().encode_to_vec(),
+            proof: None,
+        },
+    )
+    .await;
+    let mut startup_guards = register_startup_guards(&restarted.cometbft_mock).await;
+    startup_guards.insert(
+        "tx_hash".to_string(),
+        register_default_last_bridge_tx_hash_guard(&restarted.cometbft_mock).await,
+    );
+    startup_guards.insert("last_bridge_tx".to_string(), last_tx_guard);
+    restarted
+        .startup_and_spawn_with_guards(startup_guards)
+        .await;
+
+    clock.advance(CAP_WINDOW);
+    let nonce_guard = register_get_nonce_response(
+        &restarted.cometbft_mock,
+        NonceResponse {
+            height: 2,
+            nonce: 1,
+        },
+    )
+    .await;
+    let broadcast_guard = register_broadcast_tx_commit_response(
+        &restarted.cometbft_mock,
+        make_tx_commit_success_response(),
+    )
+    .await;
+
+    let Action::BridgeUnlock(mut to_other_destination) = make_bridge_unlock_action_at(11) else {
+        unreachable!("the helper constructs a bridge unlock action");
+    };
+    to_other_destination.to = Address::builder()
+        .array([1u8; 20])
+        .prefix(ASTRIA_ADDRESS_PREFIX)
+        .try_build()
+        .unwrap();
+    let to_other_destination = Action::BridgeUnlock(to_other_destination);
+    restarted
+        .submitter_handle
+        .send_batch(Batch {
+            actions: vec![to_other_destination.clone()],
+            rollup_height: 11,
+        })
+        .await
+        .unwrap();
+
+    for guard in [&nonce_guard, &broadcast_guard] {
+        tokio::time::timeout(Duration::from_millis(100), guard.wait_until_satisfied())
+            .await
+            .unwrap();
+    }
+
+    // the deferred withdrawal goes first, in the same transaction as the next batch
+    let requests = broadcast_guard.received_requests().await;
+    let signed_transaction = signed_tx_from_request(&requests[0]);
+    let expected_actions = [make_bridge_unlock_action(), to_other_destination];
+    assert_eq!(expected_actions.len(), signed_transaction.actions().len());
+    expected_actions
+        .iter()
+        .zip(signed_transaction.actions())
+        .for_each(|(expected, actual)| compare_actions(expected, actual));
+}
+
+/// Records the messages of all events emitted at `WARN` level.
+#[derive(Clone, Default)]
+struct WarningMessages(Arc<Mutex<Vec<String>>>);
+
+impl WarningMessages {
+    fn contains(&self, needle: &str) -> bool {
+        self.0
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|message| message.contains(needle))
+    }
+}
+
+impl Visit for WarningMessages {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            self.0.lock().unwrap().push(format!("{value:?}"));
+        }
+    }
+}
+
+impl<S: Subscriber> Layer<S> for WarningMessages {
+    fn on_event(&self, event: &Event<'_>, _ctx: LayerContext<'_, S>) {
+        if *event.metadata().level() == Level::WARN {
+            event.record(&mut self.clone());
+        }
+    }
+}
+
+/// Test that a batch queued behind a slow submission warns once it has waited in the queue for
+/// longer than the threshold.
+#[tokio::test]
+async fn batch_waiting_behind_slow_submission_triggers_warning() {
+    let warnings = WarningMessages::default();
+    let _guard =
+        tracing::subscriber::set_default(tracing_subscriber::registry().with(warnings.clone()));
+
+    let mut test_submitter = TestSubmitter::setup_without_startup().await;
+    let mut submitter = test_submitter.submitter.take().unwrap();
+    submitter.queue_dwell_warning_threshold = Duration::from_millis(50);
+    let _submitter_task = tokio::spawn(submitter.run());
+
+    let nonce_response = tendermint_rpc::endpoint::abci_query::Response {
+        response: tendermint_rpc::endpoint::abci_query::AbciQuery {
+            value: NonceResponse {
+                height: 1,
+                nonce: 0,
+            }
+            .encode_to_vec(),
+            ..Default::default()
+        },
+    };
+    let nonce_guard = Mock::given(body_partial_json(json!({"method": "abci_query"})))
+        .and(body_string_contains("accounts/nonce"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(response::Wrapper::new_with_id(
+                    tendermint_rpc::Id::Num(1),
+                    Some(nonce_response),
+                    None,
+                ))
+                .append_header("Content-Type", "application/json"),
+        )
+        .expect(2)
+        .mount_as_scoped(&test_submitter.cometbft_mock)
+        .await;
+    // the sequencer takes longer to commit the first transaction than the second batch may wait
+    let broadcast_guard = Mock::given(body_partial_json(json!({
+        "method": "broadcast_tx_commit"
+    })))
+    .respond_with(
+        ResponseTemplate::new(200)
+            .set_body_json(response::Wrapper::new_with_id(
+                tendermint_rpc::Id::Num(1),
+                Some(make_tx_commit_success_response()),
+                None,
+            ))
+            .append_header("Content-Type", "application/json")
+            .set_delay(Duration::from_millis(200)),
+    )
+    .expect(2)
+    .mount_as_scoped(&test_submitter.cometbft_mock)
+    .await;
+
+    for _ in 0..2 {
+        test_submitter
+            .submitter_handle
+            .send_batch(make_batch_with_bridge_unlock_and_ics20_withdrawal())
+            .await
+            .unwrap();
+    }
+
+    for guard in [&nonce_guard, &broadcast_guard] {
+        tokio::time::timeout(Duration::from_secs(1), guard.wait_until_satisfied())
+            .await
+            .unwrap();
+    }
+    assert!(warnings.contains("waited in the submitter queue"));
+}
+
+/// Test that advancing the clock past the warning threshold while a batch waits in the queue
+/// triggers the queue dwell warning, without waiting for the threshold in real time.
+#[tokio::test]
+async fn advancing_clock_while_batch_is_queued_triggers_warning() {
+    let warnings = WarningMessages::default();
+    let _guard =
+        tracing::subscriber::set_default(tracing_subscriber::registry().with(warnings.clone()));
+
+    let clock = Arc::new(MockClock::new());
+    let mut test_submitter = TestSubmitter::setup_with(
+        submitter::Builder::build_without_startup,
+        Some(clock.clone()),
+    )
+    .await;
+
+    let nonce_guard = register_get_nonce_response(
+        &test_submitter.cometbft_mock,
+        NonceResponse {
+            height: 1,
+            nonce: 0,
+        },
+    )
+    .await;
+    let broadcast_guard = register_broadcast_tx_commit_response(
+        &test_submitter.cometbft_mock,
+        make_tx_commit_success_response(),
+    )
+    .await;
+
+    // the batch is queued before the submitter runs, so it waits for exactly as long as the
+    // clock is advanced
+    test_submitter
+        .submitter_handle
+        .send_batch(make_batch_with_bridge_unlock_and_ics20_withdrawal())
+        .await
+        .unwrap();
+    clock.advance(BATCH_QUEUE_DWELL_WARNING_THRESHOLD.saturating_add(Duration::from_secs(1)));
+
+    let submitter = test_submitter.submitter.take().unwrap();
+    let _submitter_task = tokio::spawn(submitter.run());
+
+    for guard in [&nonce_guard, &broadcast_guard] {
+        tokio::time::timeout(Duration::from_millis(100), guard.wait_until_satisfied())
+            .await
+            .unwrap();
+    }
+    assert!(warnings.contains("waited in the submitter queue"));
+}
+
+/// Test that the withdrawals of one rollup height are submitted in a single transaction even if
+/// they exceed the batch size target.
+#[tokio::test]
+async fn withdrawals_of_one_rollup_height_are_not_split_across_transactions() {
+    let mut test_submitter = TestSubmitter::setup_without_startup().await;
+    let mut submitter = test_submitter.submitter.take().unwrap();
+    submitter.batch_size_target = BatchSizeTarget::new(1, 1);
+    let _submitter_task = tokio::spawn(submitter.run());
+
+    let nonce_guard = register_get_nonce_response(
+        &test_submitter.cometbft_mock,
+        NonceResponse {
+            height: 1,
+            nonce: 0,
+        },
+    )
+    .await;
+    let broadcast_guard = register_broadcast_tx_commit_response(
+        &test_submitter.cometbft_mock,
+        make_tx_commit_success_response(),
+    )
+    .await;
+
+    let batch = Batch {
+        actions: vec![
+            make_bridge_unlock_action_at(5),
+            make_bridge_unlock_action_at(5),
+        ],
+        rollup_height: 5,
+    };
+    test_submitter
+        .submitter_handle
+        .send_batch(batch)
+        .await
+        .unwrap();
+
+    for guard in [&nonce_guard, &broadcast_guard] {
+        tokio::time::timeout(Duration::from_millis(100), guard.wait_until_satisfied())
+            .await
+            .unwrap();
+    }
+    let requests = broadcast_guard.received_requests().await;
+    assert_eq!(2, signed_tx_from_request(&requests[0]).actions().len());
+}
+
+/// Test that a transaction rejected as too large shrinks the batch size target and that its
+/// withdrawals are resubmitted in smaller transactions, each holding whole rollup heights.
+#[tokio::test]
+async fn too_large_transaction_is_resubmitted_in_smaller_chunks() {
+    let mut test_submitter = TestSubmitter::setup_without_startup().await;
+    let mut submitter = test_submitter.submitter.take().unwrap();
+    submitter.batch_size_target = BatchSizeTarget::new(1, 4);
+    let _submitter_task = tokio::spawn(submitter.run());
+
+    let nonce_guard = register_repeated_get_nonce_response(
+        &test_submitter.cometbft_mock,
+        NonceResponse {
+            height: 1,
+            nonce: 0,
+        },
+        3,
+    )
+    .await;
+    // the first transaction is rejected as too large, all later ones are executed
+    let too_large_guard = Mock::given(body_partial_json(json!({
+        "method": "broadcast_tx_commit"
+    })))
+    .respond_with(
+        ResponseTemplate::new(200)
+            .set_body_json(response::Wrapper::new_with_id(
+                tendermint_rpc::Id::Num(1),
+                Some(make_tx_commit_too_large_response()),
+                None,
+            ))
+            .append_header("Content-Type", "application/json"),
+    )
+    .up_to_n_times(1)
+    .expect(1)
+    .mount_as_scoped(&test_submitter.cometbft_mock)
+    .await;
+    let executed_guard = Mock::given(body_partial_json(json!({
+        "method": "broadcast_tx_commit"
+    })))
+    .respond_with(
+        ResponseTemplate::new(200)
+            .set_body_json(response::Wrapper::new_with_id(
+                tendermint_rpc::Id::Num(1),
+                Some(make_tx_commit_success_response()),
+                None,
+            ))
+            .append_header("Content-Type", "application/json"),
+    )
+    .expect(2)
+    .mount_as_scoped(&test_submitter.cometbft_mock)
+    .await;
+
+    // the batches of rollup heights 5 to 8, merged into one
+    let batch = Batch {
+        actions: (5..=8).map(make_bridge_unlock_action_at).collect(),
+        rollup_height: 8,
+    };
+    test_submitter
+        .submitter_handle
+        .send_batch(batch)
+        .await
+        .unwrap();
+
+    for guard in [&nonce_guard, &too_large_guard, &executed_guard] {
+        tokio::time::timeout(Duration::from_millis(100), guard.wait_until_satisfied())
+            .await
+            .unwrap();
+    }
+
+    // all four rollup heights fit the initial target of four actions
+    let rejected_tx = signed_tx_from_request(&too_large_guard.received_requests().await[0]);
+    assert_eq!(4, rejected_tx.actions().len());
+
+    // the target was halved to two actions, so the rollup heights are resubmitted in two
+    // transactions of two rollup heights each
+    let executed_txs: Vec<_> = executed_guard
+        .received_requests()
+        .await
+        .iter()
+        .map(signed_tx_from_request)
+        .collect();
+    assert_eq!(
+        vec![2, 2],
+        executed_txs
+            .iter()
+            .map(|tx| tx.actions().len())
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec![6, 8],
+        executed_txs
+            .iter()
+            .map(|tx| rollup_height_from_signed_transaction(tx).unwrap())
+            .collect::<Vec<_>>()
+    );
+}
+
+/// Test that the submitter stops with an error if a transaction holding the withdrawals of a
+/// single rollup height is rejected as too large, since it cannot be split any further.
+#[tokio::test]
+async fn too_large_transaction_of_single_rollup_height_is_an_error() {
+    let mut test_submitter = TestSubmitter::setup_without_startup().await;
+    let mut submitter = test_submitter.submitter.take().unwrap();
+    submitter.batch_size_target = BatchSizeTarget::new(1, 4);
+    let submitter_task = tokio::spawn(submitter.run());
+
+    let nonce_guard = register_get_nonce_response(
+        &test_submitter.cometbft_mock,
+        NonceResponse {
+            height: 1,
+            nonce: 0,
+        },
+    )
+    .await;
+    let broadcast_guard = register_broadcast_tx_commit_response(
+        &test_submitter.cometbft_mock,
+        make_tx_commit_too_large_response(),
+    )
+    .await;
+
+    let batch = Batch {
+        actions: vec![
+            make_bridge_unlock_action_at(5),
+            make_bridge_unlock_action_at(5),
+        ],
+        rollup_height: 5,
+    };
+    test_submitter
+        .submitter_handle
+        .send_batch(batch)
+        .await
+        .unwrap();
+
+    for guard in [&nonce_guard, &broadcast_guard] {
+        tokio::time::timeout(Duration::from_millis(100), guard.wait_until_satisfied())
+            .await
+            .unwrap();
+    }
+    let error = tokio::time::timeout(Duration::from_millis(100), submitter_task)
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap_err();
+    assert!(
+        error.to_string().contains("too large"),
+        "unexpected error: {error:#}"
+    );
+}
+
+/// Test that a submitter stopping between the transactions of a merged batch loses no
+/// withdrawals: the first transaction holds all withdrawals of its rollup height, and the restarted
+/// submitter resumes from that height, so the watcher fetches the unsubmitted height again.
+#[tokio::test]
+async fn restart_between_transactions_of_merged_batch_resumes_before_unsubmitted_height() {
+    let mut test_submitter = TestSubmitter::setup_without_startup().await;
+    let mut submitter = test_submitter.submitter.take().unwrap();
+    submitter.batch_size_target = BatchSizeTarget::new(1, 1);
+    let submitter_task = tokio::spawn(submitter.run());
+
+    let nonce_guard = register_repeated_get_nonce_response(
+        &test_submitter.cometbft_mock,
+        NonceResponse {
+            height: 1,
+            nonce: 0,
+        },
+        2,
+    )
+    .await;
+    // the first transaction is executed, then the submitter stops on the second one
+    let executed_guard = Mock::given(body_partial_json(json!({
+        "method": "broadcast_tx_commit"
+    })))
+    .respond_with(
+        ResponseTemplate::new(200)
+            .set_body_json(response::Wrapper::new_with_id(
+                tendermint_rpc::Id::Num(1),
+                Some(make_tx_commit_success_response()),
+                None,
+            ))
+            .append_header("Content-Type", "application/json"),
+    )
+    .up_to_n_times(1)
+    .expect(1)
+    .mount_as_scoped(&test_submitter.cometbft_mock)
+    .await;
+    let stopped_guard = register_broadcast_tx_commit_response(
+        &test_submitter.cometbft_mock,
+        make_tx_commit_check_tx_failure_response(),
+    )
+    .await;
+
+    // the batches of rollup heights 5 and 6, merged into one
+    let batch = Batch {
+        actions: vec![
+            make_bridge_unlock_action_at(5),
+            make_bridge_unlock_action_at(5),
+            make_bridge_unlock_action_at(6),
+        ],
+        rollup_height: 6,
+    };
+    test_submitter
+        .submitter_handle
+        .send_batch(batch)
+        .await
+        .unwrap();
+
+    for guard in [&nonce_guard, &executed_guard, &stopped_guard] {
+        tokio::time::timeout(Duration::from_millis(100), guard.wait_until_satisfied())
+            .await
+            .unwrap();
+    }
+    tokio::time::timeout(Duration::from_millis(100), submitter_task)
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap();
+
+    let executed_tx = signed_tx_from_request(&executed_guard.received_requests().await[0]);
+    assert_eq!(2, executed_tx.actions().len());
+    assert_eq!(
+        5,
+        rollup_height_from_signed_transaction(&executed_tx).unwrap()
+    );
+
+    // restart, with the executed transaction as the bridge account's last one
+    let mut restarted = TestSubmitter::setup().await;
+    let last_tx_guard = register_tx_guard(
+        &restarted.cometbft_mock,
+        tx::Response {
+            hash: executed_tx
+                .sha256_of_proto_encoding()
+                .to_vec()
+                .try_into()
+                .unwrap(),
+            height: DEFAULT_LAST_SEQUENCER_HEIGHT.try_into().unwrap(),
+            index: 0,
+            tx_result: ExecTxResult {
+                code: abci::Code::Ok,
+                ..ExecTxResult::default()
+            },
+            tx: executed_tx.into_raw().encode_to_vec(),
+            proof: None,
+        },
+    )
+    .await;
+    let mut startup_guards = register_startup_guards(&restarted.cometbft_mock).await;
+    startup_guards.insert(
+        "tx_hash".to_string(),
+        register_default_last_bridge_tx_hash_guard(&restarted.cometbft_mock).await,
+    );
+    startup_guards.insert("last_bridge_tx".to_string(), last_tx_guard);
+    let submitter = restarted.submitter.take().unwrap();
+    let _restarted_task = tokio::spawn(submitter.run());
+    for (name, guard) in startup_guards {
+        tokio::time::timeout(Duration::from_millis(100), guard.wait_until_satisfied())
+            .await
+            .wrap_err(format!("{name} guard not satisfied in time."))
+            .unwrap();
+    }
+
+    let startup = restarted
+        .submitter_handle
+        .recv_startup_info()
+        .await
+        .unwrap();
+    assert!(
+        startup.next_batch_rollup_height <= 6,
+        "rollup height 6 was never submitted, but the restarted submitter resumes from height {}",
+        startup.next_batch_rollup_height,
+    );
+}
@@ -19,6 +19,25 @@ pub struct Config {
     pub fee_asset_denomination: String,
     // The minimum expected balance of the fee asset in the bridge account.
     pub min_expected_fee_asset_balance: u64,
+    // The lower bound on the number of withdrawal actions submitted in a single sequencer
+    // transaction.
+    pub min_batch_size: usize,
+    // The upper bound on the number of withdrawal actions submitted in a single sequencer
+    // transaction.
+    pub max_batch_size: usize,
+    // The number of times the submitter attempts its startup handshake with the sequencer before
+    // giving up. Values below 1 are treated as 1.
+    pub startup_max_attempts: u32,
+    // The delay in milliseconds before the first retry of the startup handshake. The delay is
+    // doubled after every failed attempt.
+    pub startup_retry_backoff_ms: u64,
+    // Skips the startup handshake with the sequencer. The watcher then reads withdrawal events
+    // from `start_rollup_height` instead of the rollup height of the bridge account's last
+    // transaction.
+    pub no_sequencer_startup: bool,
+    // The rollup height from which the watcher reads withdrawal events if
+    // `no_sequencer_startup` is set. Ignored otherwise.
+    pub start_rollup_height: u64,
     // The asset denomination being withdrawn from the rollup.
     pub rollup_asset_denomination: String,
     // The bridge address corresponding to the bridged rollup asset on the sequencer.
@@ -27,6 +46,27 @@ pub struct Config {
     pub ethereum_contract_address: String,
     // The rpc endpoint of the evm rollup.
     pub ethereum_rpc_endpoint: String,
+    // The path of a file to which every executed withdrawal is appended as a JSON line. Empty to
+    // disable the audit log.
+    pub audit_log_path: String,
+    // The path of a file to which every withdrawal that the withdrawer gave up on is appended as a
+    // JSON line. Empty to only log deadlettered withdrawals.
+    pub deadletter_log_path: String,
+    // The maximum amount withdrawn to a single destination address within
+    // `destination_withdrawal_cap_window_ms`. Set to 0 to disable.
+    pub destination_withdrawal_cap: u64,
+    // The length in milliseconds of the rolling window over which withdrawals to a destination
+    // count towards its cap.
+    pub destination_withdrawal_cap_window_ms: u64,
+    // The path of a file in which withdrawals deferred by the destination withdrawal cap are kept
+    // across restarts. Required if `destination_withdrawal_cap` is set.
+    pub deferred_withdrawals_path: String,
+    // The path of a file in which the withdrawal events already processed by the watcher are kept
+    // across restarts. Empty to keep them in memory only.
+    pub processed_events_path: String,
+    // The time in milliseconds without a new rollup block after which the watcher reconnects to
+    // the rollup. Set to 0 to disable.
+    pub watcher_stall_threshold_ms: u64,
     // The socket address at which the bridge service will server healthz, readyz, and status
     // calls.
     pub api_addr: String,
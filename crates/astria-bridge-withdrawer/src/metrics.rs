@@ -21,6 +21,7 @@ pub(crate) struct Metrics {
     current_nonce: Gauge,
     sequencer_submission_failure_count: Counter,
     sequencer_submission_latency: Histogram,
+    batch_queue_dwell_time: Histogram,
 }
 
 impl Metrics {
@@ -64,6 +65,13 @@ impl Metrics {
         );
         let sequencer_submission_latency = histogram!(SEQUENCER_SUBMISSION_LATENCY);
 
+        describe_histogram!(
+            BATCH_QUEUE_DWELL_TIME,
+            Unit::Seconds,
+            "The time a batch waited in the submitter queue before being picked up for submission"
+        );
+        let batch_queue_dwell_time = histogram!(BATCH_QUEUE_DWELL_TIME);
+
         Self {
             nonce_fetch_count,
             nonce_fetch_failure_count,
@@ -71,6 +79,7 @@ impl Metrics {
             current_nonce,
             sequencer_submission_failure_count,
             sequencer_submission_latency,
+            batch_queue_dwell_time,
         }
     }
 
@@ -97,9 +106,14 @@ impl Metrics {
     pub(crate) fn increment_sequencer_submission_failure_count(&self) {
         self.sequencer_submission_failure_count.increment(1);
     }
+
+    pub(crate) fn record_batch_queue_dwell_time(&self, dwell_time: Duration) {
+        self.batch_queue_dwell_time.record(dwell_time);
+    }
 }
 
 metric_names!(pub const METRICS_NAMES:
+    BATCH_QUEUE_DWELL_TIME,
     CURRENT_NONCE,
     NONCE_FETCH_COUNT,
     NONCE_FETCH_FAILURE_COUNT,
@@ -111,6 +125,7 @@ metric_names!(pub const METRICS_NAMES:
 #[cfg(test)]
 mod tests {
     use super::{
+        BATCH_QUEUE_DWELL_TIME,
         CURRENT_NONCE,
         NONCE_FETCH_COUNT,
         NONCE_FETCH_FAILURE_COUNT,
@@ -129,6 +144,7 @@ mod tests {
 
     #[test]
     fn metrics_are_as_expected() {
+        assert_const(BATCH_QUEUE_DWELL_TIME, "batch_queue_dwell_time");
         assert_const(CURRENT_NONCE, "current_nonce");
         assert_const(NONCE_FETCH_COUNT, "nonce_fetch_count");
         assert_const(NONCE_FETCH_FAILURE_COUNT, "nonce_fetch_failure_count");
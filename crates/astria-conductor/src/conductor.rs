@@ -29,7 +29,10 @@ use tracing::{
 
 use crate::{
     celestia,
-    executor,
+    executor::{
+        self,
+        BlockHashVerifier,
+    },
     metrics::Metrics,
     sequencer,
     utils::flatten,
@@ -91,11 +94,26 @@ impl Conductor {
 
     /// Create a new [`Conductor`] from a [`Config`].
     ///
+    /// Blocks returned by the execution layer are not checked against their hashes, see
+    /// [`Conductor::with_block_hash_verifier`].
+    ///
     /// # Errors
     /// Returns an error in the following cases if one of its constituent
     /// actors could not be spawned (executor, sequencer reader, or data availability reader).
     /// This usually happens if the actors failed to connect to their respective endpoints.
     pub fn new(cfg: Config) -> eyre::Result<Self> {
+        Self::with_block_hash_verifier(cfg, executor::NoopBlockHashVerifier)
+    }
+
+    /// Create a new [`Conductor`] from a [`Config`] that checks every block returned by the
+    /// execution layer with `block_hash_verifier`.
+    ///
+    /// # Errors
+    /// Returns an error for the same reasons as [`Conductor::new`].
+    pub fn with_block_hash_verifier<V: BlockHashVerifier>(
+        cfg: Config,
+        block_hash_verifier: V,
+    ) -> eyre::Result<Self> {
         static METRICS: OnceLock<Metrics> = OnceLock::new();
         let metrics = METRICS.get_or_init(Metrics::new);
 
@@ -111,6 +129,12 @@ impl Conductor {
             let (executor, handle) = executor::Builder {
                 mode: cfg.execution_commit_level,
                 rollup_address: cfg.execution_rpc_url,
+                expected_rollup_id: cfg.expected_rollup_id,
+                block_hash_verifier: Box::new(block_hash_verifier),
+                max_firm_block_skew: (cfg.execution_max_firm_block_skew_ms > 0)
+                    .then(|| Duration::from_millis(cfg.execution_max_firm_block_skew_ms)),
+                block_hash_length: (cfg.execution_block_hash_length > 0)
+                    .then_some(cfg.execution_block_hash_length),
                 shutdown: shutdown.clone(),
                 metrics,
             }
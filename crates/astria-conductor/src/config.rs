@@ -61,6 +61,9 @@ pub struct Config {
     /// Address of the RPC server for execution
     pub execution_rpc_url: String,
 
+    /// The hex-encoded ID of the rollup that the execution layer is expected to run.
+    pub expected_rollup_id: String,
+
     /// log directive to use for telemetry.
     pub log: String,
 
@@ -68,6 +71,15 @@ pub struct Config {
     /// the execution layer.
     pub execution_commit_level: CommitLevel,
 
+    /// The maximum time in milliseconds that the timestamp of a new firm block may lie ahead of
+    /// the current time. A value of 0 disables the check.
+    pub execution_max_firm_block_skew_ms: u64,
+
+    /// The length in bytes of the rollup's block hashes. Blocks returned by the execution layer
+    /// whose hash or parent hash is of a different length are rejected. A value of 0 disables the
+    /// check.
+    pub execution_block_hash_length: usize,
+
     /// Forces writing trace data to stdout no matter if connected to a tty or not.
     pub force_stdout: bool,
 
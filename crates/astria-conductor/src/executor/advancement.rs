@@ -0,0 +1,93 @@
+//! Tracks how fast the firm commitment advances relative to the soft commitment.
+//!
+//! Soft blocks are executed as soon as sequencer produces them, while firm blocks trail behind
+//! until their data is found on Celestia. If firm keeps up, both advance by the same number of
+//! blocks over time; if the data availability layer is a bottleneck, firm falls further behind.
+
+use std::collections::VecDeque;
+
+/// The ratio of firm to soft advancement over a sliding window of commitment states.
+#[derive(Debug)]
+pub(super) struct AdvancementRatio {
+    /// The firm and soft block numbers of the most recent commitment states, oldest first.
+    window: VecDeque<(u32, u32)>,
+    capacity: usize,
+}
+
+impl AdvancementRatio {
+    /// The number of commitment states the ratio is computed over.
+    pub(super) const DEFAULT_WINDOW: usize = 100;
+
+    /// Creates a tracker computing the ratio over the last `capacity` commitment states.
+    ///
+    /// `capacity` is clamped to at least 2, the fewest states over which advancement can be
+    /// observed.
+    pub(super) fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(2);
+        Self {
+            window: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Records a commitment state with the given `firm` and `soft` block numbers, returning the
+    /// ratio of firm to soft advancement over the window.
+    ///
+    /// A ratio of 1.0 means firm keeps up with soft, and a ratio below 1.0 means firm is lagging.
+    /// Returns `None` if soft did not advance over the window.
+    pub(super) fn record(&mut self, firm: u32, soft: u32) -> Option<f64> {
+        if self.window.len() == self.capacity {
+            self.window.pop_front();
+        }
+        self.window.push_back((firm, soft));
+        let (oldest_firm, oldest_soft) = *self.window.front()?;
+        let soft_advance = soft.saturating_sub(oldest_soft);
+        if soft_advance == 0 {
+            return None;
+        }
+        let firm_advance = firm.saturating_sub(oldest_firm);
+        Some(f64::from(firm_advance) / f64::from(soft_advance))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AdvancementRatio;
+
+    #[track_caller]
+    fn assert_ratio(expected: f64, actual: Option<f64>) {
+        let actual = actual.expect("soft advanced, so there must be a ratio");
+        assert!(
+            (expected - actual).abs() < f64::EPSILON,
+            "expected ratio {expected}, got {actual}"
+        );
+    }
+
+    #[test]
+    fn firm_keeping_up_with_soft_gives_ratio_of_one() {
+        let mut ratio = AdvancementRatio::new(4);
+        assert_eq!(None, ratio.record(1, 1));
+        for number in 2..10 {
+            assert_ratio(1.0, ratio.record(number, number));
+        }
+    }
+
+    #[test]
+    fn firm_lagging_behind_soft_gives_ratio_below_one() {
+        let mut ratio = AdvancementRatio::new(4);
+        // soft advances by two blocks per commitment state, firm only by one.
+        let mut last = None;
+        for (firm, soft) in (0..10).zip((0..20).step_by(2)) {
+            last = ratio.record(firm, soft);
+        }
+        assert_ratio(0.5, last);
+    }
+
+    #[test]
+    fn soft_not_advancing_gives_no_ratio() {
+        let mut ratio = AdvancementRatio::new(4);
+        assert_eq!(None, ratio.record(1, 5));
+        assert_eq!(None, ratio.record(2, 5));
+        assert_eq!(None, ratio.record(3, 5));
+    }
+}
@@ -0,0 +1,57 @@
+//! Verification of the hashes of blocks returned by the execution layer.
+//!
+//! Conductor consults a [`BlockHashVerifier`] on every block it receives from the execution
+//! layer, both for blocks it asked the rollup to execute and for blocks it fetched after the fact.
+//! A rollup whose block hash can be recomputed from the fields of an execution [`Block`] can
+//! implement the trait to detect a buggy or malicious execution layer, and run conductor with it
+//! through [`Conductor::with_block_hash_verifier`]. [`Conductor::new`] uses
+//! [`NoopBlockHashVerifier`], which accepts every block.
+//!
+//! [`Conductor::new`]: crate::Conductor::new
+//! [`Conductor::with_block_hash_verifier`]: crate::Conductor::with_block_hash_verifier
+
+use astria_core::execution::v1alpha2::Block;
+
+/// Verifies that the hash of a block returned by the execution layer matches its contents.
+pub trait BlockHashVerifier: Send + Sync + 'static {
+    /// Checks the hash of `block`.
+    ///
+    /// # Errors
+    /// Returns an error if the hash stored in `block` does not match the hash computed from its
+    /// contents.
+    fn verify(&self, block: &Block) -> Result<(), BlockHashMismatch>;
+}
+
+/// A [`BlockHashVerifier`] that accepts every block.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NoopBlockHashVerifier;
+
+impl BlockHashVerifier for NoopBlockHashVerifier {
+    fn verify(&self, _block: &Block) -> Result<(), BlockHashMismatch> {
+        Ok(())
+    }
+}
+
+/// The hash of a block did not match the hash computed from its contents.
+#[derive(Debug, thiserror::Error)]
+#[error(
+    "execution layer returned block number `{number}` with hash `{actual}`, but its contents hash \
+     to `{expected}`"
+)]
+pub struct BlockHashMismatch {
+    number: u32,
+    expected: String,
+    actual: String,
+}
+
+impl BlockHashMismatch {
+    /// Reports that `block` does not carry the `expected` hash computed from its contents.
+    #[must_use]
+    pub fn new(block: &Block, expected: &[u8]) -> Self {
+        Self {
+            number: block.number(),
+            expected: telemetry::display::base64(expected).to_string(),
+            actual: telemetry::display::base64(block.hash()).to_string(),
+        }
+    }
+}
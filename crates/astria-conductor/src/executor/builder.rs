@@ -1,5 +1,9 @@
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    time::Duration,
+};
 
+use astria_core::primitive::v1::RollupId;
 use astria_eyre::eyre::{
     self,
     WrapErr as _,
@@ -9,6 +13,8 @@ use tokio_util::sync::CancellationToken;
 
 use super::{
     state,
+    AdvancementRatio,
+    BlockHashVerifier,
     Executor,
     Handle,
     StateNotInit,
@@ -21,6 +27,11 @@ use crate::{
 pub(crate) struct Builder {
     pub(crate) mode: CommitLevel,
     pub(crate) rollup_address: String,
+    pub(crate) expected_rollup_id: String,
+    pub(crate) block_hash_verifier: Box<dyn BlockHashVerifier>,
+    pub(crate) max_firm_block_skew: Option<Duration>,
+    /// The expected length of the rollup's block hashes. Not checked if `None`.
+    pub(crate) block_hash_length: Option<usize>,
     pub(crate) shutdown: CancellationToken,
     pub(crate) metrics: &'static Metrics,
 }
@@ -30,16 +41,31 @@ impl Builder {
         let Self {
             mode,
             rollup_address,
+            expected_rollup_id,
+            block_hash_verifier,
+            max_firm_block_skew,
+            block_hash_length,
             shutdown,
             metrics,
         } = self;
 
-        let client = super::client::Client::connect_lazy(&rollup_address).wrap_err_with(|| {
-            format!(
-                "failed to construct execution client for provided rollup address \
-                 `{rollup_address}`"
-            )
-        })?;
+        let expected_rollup_id = hex::decode(&expected_rollup_id)
+            .wrap_err("provided expected rollup ID is not hex-encoded")
+            .and_then(|bytes| {
+                RollupId::try_from_vec(bytes)
+                    .wrap_err("provided expected rollup ID does not contain 32 bytes")
+            })
+            .wrap_err_with(|| {
+                format!("failed parsing expected rollup ID `{expected_rollup_id}`")
+            })?;
+
+        let client = super::client::Client::connect_lazy(&rollup_address, block_hash_length)
+            .wrap_err_with(|| {
+                format!(
+                    "failed to construct execution client for provided rollup address \
+                     `{rollup_address}`"
+                )
+            })?;
 
         let mut firm_block_tx = None;
         let mut firm_block_rx = None;
@@ -63,6 +89,9 @@ impl Builder {
             client,
 
             mode,
+            expected_rollup_id,
+            block_hash_verifier,
+            max_firm_block_skew,
 
             firm_blocks: firm_block_rx,
             soft_blocks: soft_block_rx,
@@ -72,6 +101,7 @@ impl Builder {
             blocks_pending_finalization: HashMap::new(),
 
             max_spread: None,
+            advancement_ratio: AdvancementRatio::new(AdvancementRatio::DEFAULT_WINDOW),
             metrics,
         };
         let handle = Handle {
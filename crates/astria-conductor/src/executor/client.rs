@@ -3,6 +3,7 @@ use std::time::Duration;
 use astria_core::{
     execution::v1alpha2::{
         Block,
+        BlockError,
         CommitmentState,
         GenesisInfo,
     },
@@ -44,10 +45,13 @@ use tryhard::{
 pub(crate) struct Client {
     uri: Uri,
     inner: ExecutionServiceClient<Channel>,
+    /// The expected length of the hashes of all blocks received from the execution layer. Not
+    /// checked if `None`.
+    block_hash_length: Option<usize>,
 }
 
 impl Client {
-    pub(crate) fn connect_lazy(uri: &str) -> eyre::Result<Self> {
+    pub(crate) fn connect_lazy(uri: &str, block_hash_length: Option<usize>) -> eyre::Result<Self> {
         let uri: Uri = uri
             .parse()
             .wrap_err("failed to parse provided string as uri")?;
@@ -56,9 +60,40 @@ impl Client {
         Ok(Self {
             uri,
             inner,
+            block_hash_length,
         })
     }
 
+    /// Converts a block received from the execution layer, checking the length of its hashes if
+    /// one is configured.
+    fn block_from_raw(&self, raw: raw::Block) -> Result<Block, BlockError> {
+        match self.block_hash_length {
+            Some(hash_length) => Block::try_from_raw_with_hash_length(raw, hash_length),
+            None => Block::try_from_raw(raw),
+        }
+    }
+
+    /// Converts a commitment state received from the execution layer, checking the length of the
+    /// hashes of its blocks if one is configured.
+    fn commitment_state_from_raw(
+        &self,
+        raw: raw::CommitmentState,
+    ) -> eyre::Result<CommitmentState> {
+        let commitment_state = CommitmentState::try_from_raw(raw)
+            .wrap_err("failed converting raw response to validated commitment state")?;
+        if let Some(hash_length) = self.block_hash_length {
+            commitment_state
+                .firm()
+                .validate_hash_length(hash_length)
+                .wrap_err("firm block of commitment state has hashes of unexpected length")?;
+            commitment_state
+                .soft()
+                .validate_hash_length(hash_length)
+                .wrap_err("soft block of commitment state has hashes of unexpected length")?;
+        }
+        Ok(commitment_state)
+    }
+
     /// Calls RPC astria.execution.v1alpha2.GetBlock
     #[instrument(skip_all, fields(block_number, uri = %self.uri), err)]
     pub(crate) async fn get_block_with_retry(&mut self, block_number: u32) -> eyre::Result<Block> {
@@ -82,7 +117,8 @@ impl Client {
             "requested block at number `{block_number}`, but received block contained `{}`",
             raw_block.number
         );
-        Block::try_from_raw(raw_block).wrap_err("failed validating received block")
+        self.block_from_raw(raw_block)
+            .wrap_err("failed validating received block")
     }
 
     /// Calls remote procedure `astria.execution.v1alpha2.GetGenesisInfo`
@@ -119,14 +155,14 @@ impl Client {
         prev_block_hash: Bytes,
         transactions: Vec<Vec<u8>>,
         timestamp: Timestamp,
-    ) -> eyre::Result<Block> {
+    ) -> Result<Block, ExecuteBlockError> {
         use prost::Message;
 
         let transactions = transactions
             .into_iter()
             .map(|tx| RollupData::decode(tx.as_slice()))
             .collect::<Result<_, _>>()
-            .wrap_err("failed to decode tx bytes as RollupData")?;
+            .map_err(ExecuteBlockError::undecodable_transactions)?;
 
         let request = raw::ExecuteBlockRequest {
             prev_block_hash,
@@ -141,13 +177,11 @@ impl Client {
         .with_config(retry_config())
         .in_current_span()
         .await
-        .wrap_err(
-            "failed to execute astria.execution.v1alpha2.ExecuteBlock RPC because of gRPC status \
-             code or because number of retries were exhausted",
-        )?
+        .map_err(ExecuteBlockError::from_status)?
         .into_inner();
-        let block = Block::try_from_raw(response)
-            .wrap_err("failed converting raw response to validated block")?;
+        let block = self
+            .block_from_raw(response)
+            .map_err(ExecuteBlockError::invalid_response)?;
         Ok(block)
     }
 
@@ -171,9 +205,7 @@ impl Client {
              status code or because number of retries were exhausted",
         )?
         .into_inner();
-        let commitment_state = CommitmentState::try_from_raw(response)
-            .wrap_err("failed converting raw response to validated commitment state")?;
-        Ok(commitment_state)
+        self.commitment_state_from_raw(response)
     }
 
     /// Calls remote procedure `astria.execution.v1alpha2.UpdateCommitmentState`
@@ -203,9 +235,72 @@ impl Client {
              gRPC status code or because number of retries were exhausted",
         )?
         .into_inner();
-        let commitment_state = CommitmentState::try_from_raw(response)
-            .wrap_err("failed converting raw response to validated commitment state")?;
-        Ok(commitment_state)
+        self.commitment_state_from_raw(response)
+    }
+}
+
+/// The ways in which executing a block on the execution layer can fail.
+///
+/// Failures reported by the execution layer are categorized by the gRPC status code following
+/// the execution API spec: the execution layer returns `INVALID_ARGUMENT` for transactions it
+/// cannot apply, `FAILED_PRECONDITION` if the block does not build on its soft block, and
+/// `PERMISSION_DENIED` to trigger a restart. All other status codes are treated as failures
+/// internal to the execution layer.
+///
+/// Transient failures are retried by [`Client::execute_block_with_retry`] following
+/// [`should_retry`], so none of these errors are resolved by executing the same block again.
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum ExecuteBlockError {
+    /// The block contains transactions that cannot be applied. Retrying the same block will not
+    /// succeed.
+    #[error("block contains transactions that cannot be executed")]
+    InvalidTransactions {
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
+    /// The block does not build on the execution layer's soft block, indicating that conductor
+    /// and the execution layer disagree about the head of the chain.
+    #[error("block does not build on the soft block of the execution layer")]
+    WrongParent { source: tonic::Status },
+    /// The execution layer refused to execute the block and requested that conductor restart.
+    #[error("execution layer requested a restart")]
+    RestartRequested { source: tonic::Status },
+    /// The execution layer failed for reasons unrelated to the block's contents.
+    #[error("execution layer failed executing the block")]
+    Internal { source: tonic::Status },
+    /// The execution layer returned a block that is not well-formed.
+    #[error("execution layer returned an invalid block")]
+    InvalidResponse { source: BlockError },
+}
+
+impl ExecuteBlockError {
+    fn undecodable_transactions(source: prost::DecodeError) -> Self {
+        Self::InvalidTransactions {
+            source: source.into(),
+        }
+    }
+
+    fn from_status(status: tonic::Status) -> Self {
+        use tonic::Code;
+        match status.code() {
+            Code::InvalidArgument => Self::InvalidTransactions {
+                source: status.into(),
+            },
+            Code::FailedPrecondition => Self::WrongParent {
+                source: status,
+            },
+            Code::PermissionDenied => Self::RestartRequested {
+                source: status,
+            },
+            _ => Self::Internal {
+                source: status,
+            },
+        }
+    }
+
+    fn invalid_response(source: BlockError) -> Self {
+        Self::InvalidResponse {
+            source,
+        }
     }
 }
 
@@ -332,17 +427,43 @@ fn should_retry(status: &tonic::Status) -> bool {
 mod tests {
     use std::time::Duration;
 
+    use bytes::Bytes;
     use tonic::{
         Code,
         Status,
     };
 
     use super::{
+        raw,
+        should_retry,
         BackoffStrategy as _,
+        Client,
+        ExecuteBlockError,
         ExecutionApiRetryStrategy,
         RetryPolicy,
     };
 
+    fn make_raw_block(number: u32, hash_length: usize) -> raw::Block {
+        raw::Block {
+            number,
+            hash: Bytes::from(vec![1; hash_length]),
+            parent_block_hash: Bytes::from(vec![0; hash_length]),
+            timestamp: Some(pbjson_types::Timestamp {
+                seconds: 1,
+                nanos: 0,
+            }),
+        }
+    }
+
+    fn make_raw_commitment_state(firm: raw::Block, soft: raw::Block) -> raw::CommitmentState {
+        raw::CommitmentState {
+            soft: Some(soft),
+            firm: Some(firm),
+            base_celestia_height: 1,
+            epoch: None,
+        }
+    }
+
     #[track_caller]
     fn assert_retry_policy<const SHOULD_RETRY: bool>(code: Code) {
         let mut strat = ExecutionApiRetryStrategy {
@@ -387,4 +508,89 @@ mod tests {
         assert_retry_policy::<SHOULD_BREAK>(Code::DataLoss);
         assert_retry_policy::<SHOULD_BREAK>(Code::Unauthenticated);
     }
+
+    #[test]
+    fn execute_block_statuses_map_to_expected_category() {
+        let error = ExecuteBlockError::from_status(Status::invalid_argument("bad transaction"));
+        assert!(matches!(
+            error,
+            ExecuteBlockError::InvalidTransactions { .. }
+        ));
+
+        let error = ExecuteBlockError::from_status(Status::failed_precondition(
+            "prev_block_hash does not match soft block",
+        ));
+        assert!(matches!(error, ExecuteBlockError::WrongParent { .. }));
+
+        let error = ExecuteBlockError::from_status(Status::permission_denied("restart"));
+        assert!(matches!(error, ExecuteBlockError::RestartRequested { .. }));
+
+        for code in [
+            Code::Internal,
+            Code::Unimplemented,
+            Code::DataLoss,
+            Code::Unauthenticated,
+        ] {
+            let error = ExecuteBlockError::from_status(Status::new(code, ""));
+            assert!(
+                matches!(error, ExecuteBlockError::Internal { .. }),
+                "gRPC code `{code}` should map to an internal error, but got {error:?}"
+            );
+            assert!(
+                !should_retry(&Status::new(code, "")),
+                "gRPC code `{code}` must not be retried"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn blocks_with_hashes_of_unexpected_length_are_rejected() {
+        let client = Client::connect_lazy("http://127.0.0.1:50051", Some(32)).unwrap();
+        client.block_from_raw(make_raw_block(1, 32)).unwrap();
+        let error = client
+            .block_from_raw(make_raw_block(1, 31))
+            .expect_err("a block with truncated hashes must be rejected");
+        assert!(
+            error.to_string().contains("expected to be `32` bytes long"),
+            "{error}"
+        );
+
+        client
+            .commitment_state_from_raw(make_raw_commitment_state(
+                make_raw_block(1, 32),
+                make_raw_block(2, 32),
+            ))
+            .unwrap();
+        let _ = client
+            .commitment_state_from_raw(make_raw_commitment_state(
+                make_raw_block(1, 32),
+                make_raw_block(2, 64),
+            ))
+            .expect_err("a commitment state with a soft block of longer hashes must be rejected");
+    }
+
+    #[tokio::test]
+    async fn hash_lengths_are_not_checked_without_expected_length() {
+        let client = Client::connect_lazy("http://127.0.0.1:50051", None).unwrap();
+        client.block_from_raw(make_raw_block(1, 31)).unwrap();
+        client
+            .commitment_state_from_raw(make_raw_commitment_state(
+                make_raw_block(1, 32),
+                make_raw_block(2, 64),
+            ))
+            .unwrap();
+    }
+
+    #[test]
+    fn undecodable_transactions_are_invalid_transactions() {
+        use prost::Message as _;
+        let decode_error =
+            astria_core::generated::sequencerblock::v1alpha1::RollupData::decode(&[0xff, 0xff][..])
+                .unwrap_err();
+        let error = ExecuteBlockError::undecodable_transactions(decode_error);
+        assert!(matches!(
+            error,
+            ExecuteBlockError::InvalidTransactions { .. }
+        ));
+    }
 }
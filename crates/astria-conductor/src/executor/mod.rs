@@ -1,4 +1,11 @@
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    ops::RangeInclusive,
+    time::{
+        Duration,
+        SystemTime,
+    },
+};
 
 use astria_core::{
     execution::v1alpha2::{
@@ -43,13 +50,22 @@ use crate::{
     metrics::Metrics,
 };
 
+mod advancement;
+mod block_hash;
 mod builder;
 pub(crate) mod channel;
 
+use advancement::AdvancementRatio;
+pub use block_hash::{
+    BlockHashMismatch,
+    BlockHashVerifier,
+    NoopBlockHashVerifier,
+};
 pub(crate) use builder::Builder;
 use channel::soft_block_channel;
 
 mod client;
+pub mod self_test;
 mod state;
 #[cfg(test)]
 mod tests;
@@ -207,13 +223,13 @@ impl Handle<StateIsInit> {
         self.state.rollup_id()
     }
 
-    pub(crate) fn celestia_base_block_height(&mut self) -> CelestiaHeight {
-        self.state.celestia_base_block_height()
-    }
-
     pub(crate) fn celestia_block_variance(&mut self) -> u64 {
         self.state.celestia_block_variance()
     }
+
+    pub(crate) fn celestia_search_range(&mut self) -> RangeInclusive<u32> {
+        self.state.celestia_search_range()
+    }
 }
 
 pub(crate) struct Executor {
@@ -223,6 +239,16 @@ pub(crate) struct Executor {
     /// The mode under which this executor (and hence conductor) runs.
     mode: CommitLevel,
 
+    /// The rollup ID the execution layer is expected to report in its genesis info.
+    expected_rollup_id: RollupId,
+
+    /// Verifies the hashes of all blocks returned by the execution layer.
+    block_hash_verifier: Box<dyn BlockHashVerifier>,
+
+    /// The maximum time the timestamp of a new firm block may lie ahead of the current time.
+    /// The check is disabled if unset.
+    max_firm_block_skew: Option<Duration>,
+
     /// The channel of which this executor receives blocks for executing
     /// firm commitments.
     /// Only set if `mode` is `FirmOnly` or `SoftAndFirm`.
@@ -248,6 +274,9 @@ pub(crate) struct Executor {
     /// The maximum permitted spread between firm and soft blocks.
     max_spread: Option<usize>,
 
+    /// Tracks how fast firm advances relative to soft over recent commitment state updates.
+    advancement_ratio: AdvancementRatio,
+
     metrics: &'static Metrics,
 }
 
@@ -455,7 +484,7 @@ impl Executor {
         // XXX: We set an absolute number value here to avoid any potential issues of the remote
         // rollup state and the local state falling out of lock-step.
         self.metrics
-            .absolute_set_executed_soft_block_number(block_number);
+            .absolute_set_executed_soft_block_number(self.state.rollup_id(), block_number);
 
         Ok(())
     }
@@ -508,7 +537,12 @@ impl Executor {
                  Trying to fetch the already-executed block from the rollup before giving up."
             );
             match self.client.get_block_with_retry(block_number).await {
-                Ok(block) => Update::OnlyFirm(block, celestia_height),
+                Ok(block) => {
+                    self.block_hash_verifier
+                        .verify(&block)
+                        .wrap_err("execution layer returned block with invalid hash")?;
+                    Update::OnlyFirm(block, celestia_height)
+                }
                 Err(error) => {
                     error!(
                         block_number,
@@ -530,7 +564,7 @@ impl Executor {
         // XXX: We set an absolute number value here to avoid any potential issues of the remote
         // rollup state and the local state falling out of lock-step.
         self.metrics
-            .absolute_set_executed_soft_block_number(block_number);
+            .absolute_set_executed_soft_block_number(self.state.rollup_id(), block_number);
 
         Ok(())
     }
@@ -563,9 +597,12 @@ impl Executor {
             .execute_block_with_retry(parent_hash, transactions, timestamp)
             .await
             .wrap_err("failed to run execute_block RPC")?;
+        self.block_hash_verifier
+            .verify(&executed_block)
+            .wrap_err("execution layer returned block with invalid hash")?;
 
         self.metrics
-            .record_transactions_per_executed_block(n_transactions);
+            .record_transactions_per_executed_block(self.state.rollup_id(), n_transactions);
 
         info!(
             executed_block.hash = %telemetry::display::base64(&executed_block.hash()),
@@ -597,14 +634,20 @@ impl Executor {
             }
         };
         let (genesis_info, commitment_state) = tokio::try_join!(genesis_info, commitment_state)?;
+        genesis_info
+            .assert_matches_rollup(&self.expected_rollup_id)
+            .wrap_err("execution layer does not run the rollup conductor was configured for")?;
+        ensure_supported_epoch(&commitment_state)
+            .wrap_err("execution layer returned a commitment state conductor cannot follow")?;
         self.state
             .try_init(genesis_info, commitment_state)
             .wrap_err("failed initializing state tracking")?;
 
+        let rollup_id = self.state.rollup_id();
         self.metrics
-            .absolute_set_executed_firm_block_number(self.state.firm_number());
+            .absolute_set_executed_firm_block_number(rollup_id, self.state.firm_number());
         self.metrics
-            .absolute_set_executed_soft_block_number(self.state.soft_number());
+            .absolute_set_executed_soft_block_number(rollup_id, self.state.soft_number());
         info!(
             initial_state = serde_json::to_string(&*self.state.get())
                 .expect("writing json to a string should not fail"),
@@ -614,12 +657,18 @@ impl Executor {
     }
 
     #[instrument(skip_all)]
-    async fn update_commitment_state(&mut self, update: Update) -> eyre::Result<()> {
+    async fn update_commitment_state(&mut self, update: Update) -> eyre::Result<UpdateOutcome> {
         use Update::{
             OnlyFirm,
             OnlySoft,
             ToSame,
         };
+        if let (OnlyFirm(firm, _) | ToSame(firm, _), Some(max_skew)) =
+            (&update, self.max_firm_block_skew)
+        {
+            ensure_firm_block_is_not_ahead(firm, SystemTime::now(), max_skew)
+                .wrap_err("refusing to update firm commitment to a block from the future")?;
+        }
         let (firm, soft, celestia_height) = match update {
             OnlyFirm(firm, celestia_height) => (firm, self.state.soft(), celestia_height),
             OnlySoft(soft) => (
@@ -633,24 +682,55 @@ impl Executor {
             .firm(firm)
             .soft(soft)
             .base_celestia_height(celestia_height)
-            .build()
-            .wrap_err("failed constructing commitment state")?;
+            .build_successor_of(&self.state.commitment_state())
+            .wrap_err(
+                "refusing to send a commitment state that is not a valid successor of the current \
+                 one",
+            )?;
+        if is_unchanged(&self.state.commitment_state(), &commitment_state) {
+            debug!("commitment state is unchanged; not updating it");
+            return Ok(UpdateOutcome::Unchanged);
+        }
         let new_state = self
             .client
             .update_commitment_state_with_retry(commitment_state)
             .await
             .wrap_err("failed updating remote commitment state")?;
+        ensure_supported_epoch(&new_state)
+            .wrap_err("rollup returned a commitment state conductor cannot follow")?;
+        // Firm never advances without celestia, so the ratio is only meaningful if firm
+        // commitments are tracked.
+        let firm_to_soft_ratio = if self.mode.is_with_firm() {
+            self.advancement_ratio
+                .record(new_state.firm().number(), new_state.soft().number())
+        } else {
+            None
+        };
+        if let Some(ratio) = firm_to_soft_ratio {
+            self.metrics
+                .set_firm_to_soft_advancement_ratio(self.state.rollup_id(), ratio);
+        }
         info!(
             soft.number = new_state.soft().number(),
             soft.hash = %telemetry::display::base64(&new_state.soft().hash()),
             firm.number = new_state.firm().number(),
             firm.hash = %telemetry::display::base64(&new_state.firm().hash()),
+            firm_to_soft_ratio,
             "updated commitment state",
         );
+        let rollup_id = self.state.rollup_id();
+        self.metrics.increment_commitment_state_updates(rollup_id);
+        self.metrics.set_firm_to_soft_block_gap(
+            rollup_id,
+            new_state
+                .soft()
+                .number()
+                .saturating_sub(new_state.firm().number()),
+        );
         self.state
             .try_update_commitment_state(new_state)
             .wrap_err("failed updating internal state tracking rollup state; invalid?")?;
-        Ok(())
+        Ok(UpdateOutcome::Updated)
     }
 
     fn does_block_response_fulfill_contract(
@@ -681,6 +761,34 @@ enum Update {
     ToSame(Block, CelestiaHeight),
 }
 
+/// The result of [`Executor::update_commitment_state`].
+#[derive(Debug, PartialEq, Eq)]
+enum UpdateOutcome {
+    /// The commitment state was sent to the rollup and is tracked as its new state.
+    Updated,
+    /// The commitment state equals the current one, so it was neither sent to the rollup nor
+    /// tracked again.
+    Unchanged,
+}
+
+/// Returns if `next` has the same blocks and base celestia height as `current`, in which case
+/// updating the commitment state to `next` would not change it.
+fn is_unchanged(current: &CommitmentState, next: &CommitmentState) -> bool {
+    current.same_blocks(next) && current.base_celestia_height() == next.base_celestia_height()
+}
+
+/// Checks that `commitment_state` is of [`CommitmentState::CURRENT_EPOCH`], the only epoch
+/// conductor knows how to follow.
+fn ensure_supported_epoch(commitment_state: &CommitmentState) -> eyre::Result<()> {
+    ensure!(
+        commitment_state.epoch() == CommitmentState::CURRENT_EPOCH,
+        "commitment state is of epoch `{}`, but conductor only supports epoch `{}`",
+        commitment_state.epoch(),
+        CommitmentState::CURRENT_EPOCH,
+    );
+    Ok(())
+}
+
 #[derive(Debug)]
 struct ExecutableBlock {
     hash: [u8; 32],
@@ -799,6 +907,25 @@ fn does_block_response_fulfill_contract(
     }
 }
 
+/// Checks that the timestamp of `firm` does not lie more than `max_skew` ahead of `now`.
+fn ensure_firm_block_is_not_ahead(
+    firm: &Block,
+    now: SystemTime,
+    max_skew: Duration,
+) -> eyre::Result<()> {
+    if let Err(ahead) = firm.age(now) {
+        ensure!(
+            ahead <= max_skew,
+            "timestamp of firm block number `{}` lies {}ms ahead of the current time, exceeding \
+             the maximum permitted skew of {}ms",
+            firm.number(),
+            ahead.as_millis(),
+            max_skew.as_millis(),
+        );
+    }
+    Ok(())
+}
+
 fn should_execute_firm_block(
     firm_sequencer_height: u64,
     soft_sequencer_height: u64,
@@ -0,0 +1,354 @@
+//! A self-test of the execution layer that conductor drives.
+//!
+//! [`run`] performs the same round trip conductor performs on startup: it fetches the genesis
+//! info and the commitment state, then the firm and soft blocks, and checks the results against
+//! the invariants conductor relies on. Unlike conductor, it does not retry failed requests and does
+//! not stop at the first inconsistency, but collects all of them into a [`Diagnosis`]. This is
+//! intended for operators debugging a misbehaving execution layer.
+//!
+//! The genesis info and commitment state are checked with the same validators conductor uses
+//! when it initializes its state, so a violated invariant is reported as the [`InvalidState`]
+//! conductor would fail with.
+
+use std::fmt::{
+    self,
+    Display,
+    Formatter,
+};
+
+use astria_core::{
+    execution::v1alpha2::{
+        Block,
+        BlockError,
+        CommitmentState,
+        CommitmentStateError,
+        GenesisInfo,
+        GenesisInfoError,
+    },
+    generated::execution::{
+        v1alpha2 as raw,
+        v1alpha2::execution_service_client::ExecutionServiceClient,
+    },
+    Protobuf as _,
+};
+use astria_eyre::eyre::{
+    self,
+    WrapErr as _,
+};
+use bytes::Bytes;
+use tonic::transport::{
+    Channel,
+    Endpoint,
+};
+
+pub use super::state::InvalidState;
+use super::state::{
+    can_map_firm_to_sequencer_height,
+    can_map_soft_to_sequencer_height,
+    celestia_base_height_is_consistent,
+};
+
+/// The maximum number of blocks requested from the execution layer in a single
+/// `BatchGetBlocks` call.
+const MAX_BLOCKS_PER_REQUEST: u32 = 128;
+
+/// An invariant of the execution layer that was found to be violated.
+#[derive(Debug, thiserror::Error)]
+pub enum Violation {
+    #[error("the execution layer returned genesis info that is not valid")]
+    InvalidGenesisInfo { source: GenesisInfoError },
+    #[error("the execution layer returned a commitment state that is not valid")]
+    InvalidCommitmentState { source: CommitmentStateError },
+    #[error("conductor cannot start from the genesis info and commitment state")]
+    InvalidState { source: InvalidState },
+    #[error(
+        "the execution layer failed to return the blocks at rollup numbers `{first}` to `{last}`"
+    )]
+    BlocksUnavailable {
+        first: u32,
+        last: u32,
+        source: tonic::Status,
+    },
+    #[error(
+        "the execution layer did not include the block at rollup number `{number}` in its response"
+    )]
+    MissingBlock { number: u32 },
+    #[error("the execution layer returned a block at rollup number `{number}` that is not valid")]
+    InvalidBlock { number: u32, source: BlockError },
+    #[error(
+        "requested the block at rollup number `{requested}`, but the execution layer returned the \
+         block at rollup number `{returned}`"
+    )]
+    WrongBlockNumber { requested: u32, returned: u32 },
+    #[error(
+        "the `{commitment_type}` block of the commitment state has hash `{expected}`, but the \
+         execution layer returned a block with hash `{actual}` at rollup number `{number}`",
+        expected = hex::encode(.expected_hash),
+        actual = hex::encode(.actual_hash),
+    )]
+    BlockMismatch {
+        commitment_type: &'static str,
+        number: u32,
+        expected_hash: Bytes,
+        actual_hash: Bytes,
+    },
+    #[error(
+        "the block at rollup number `{number}` has parent block hash `{actual}`, but the block \
+         before it has hash `{expected}`",
+        actual = hex::encode(.parent_block_hash),
+        expected = hex::encode(.expected_parent_block_hash),
+    )]
+    BrokenHashLinkage {
+        number: u32,
+        parent_block_hash: Bytes,
+        expected_parent_block_hash: Bytes,
+    },
+}
+
+/// The result of running the self-test against an execution layer.
+#[derive(Debug, Default)]
+pub struct Diagnosis {
+    violations: Vec<Violation>,
+}
+
+impl Diagnosis {
+    /// Returns if no invariant of the execution layer was found to be violated.
+    #[must_use]
+    pub fn is_healthy(&self) -> bool {
+        self.violations.is_empty()
+    }
+
+    /// Returns the violated invariants in the order they were found.
+    #[must_use]
+    pub fn violations(&self) -> &[Violation] {
+        &self.violations
+    }
+}
+
+impl Display for Diagnosis {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.is_healthy() {
+            return f.write_str("the execution layer upholds all checked invariants");
+        }
+        write!(
+            f,
+            "the execution layer violates {} invariant(s):",
+            self.violations.len()
+        )?;
+        for violation in &self.violations {
+            write!(f, "\n- {violation}")?;
+            let mut source = std::error::Error::source(violation);
+            while let Some(cause) = source {
+                write!(f, ": {cause}")?;
+                source = cause.source();
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Runs the self-test against the execution layer served at `execution_rpc_url`.
+///
+/// The firm and soft blocks, and all blocks between them, are fetched in batches of at most 128
+/// blocks.
+///
+/// # Errors
+/// Returns an error if no connection to the execution layer can be established, or if it fails to
+/// return its genesis info or commitment state. All other failures are reported as violations in
+/// the returned [`Diagnosis`].
+pub async fn run(execution_rpc_url: &str) -> eyre::Result<Diagnosis> {
+    let channel = Endpoint::from_shared(execution_rpc_url.to_string())
+        .wrap_err("failed to parse execution rpc url")?
+        .connect()
+        .await
+        .wrap_err("failed to connect to execution layer")?;
+    SelfTest {
+        client: ExecutionServiceClient::new(channel),
+        diagnosis: Diagnosis::default(),
+    }
+    .run()
+    .await
+}
+
+struct SelfTest {
+    client: ExecutionServiceClient<Channel>,
+    diagnosis: Diagnosis,
+}
+
+impl SelfTest {
+    async fn run(mut self) -> eyre::Result<Diagnosis> {
+        let raw_genesis_info = self
+            .client
+            .get_genesis_info(raw::GetGenesisInfoRequest {})
+            .await
+            .wrap_err("failed to get genesis info")?
+            .into_inner();
+        let raw_commitment_state = self
+            .client
+            .get_commitment_state(raw::GetCommitmentStateRequest {})
+            .await
+            .wrap_err("failed to get commitment state")?
+            .into_inner();
+
+        let genesis_info = GenesisInfo::try_from_raw(raw_genesis_info).map_err(|source| {
+            Violation::InvalidGenesisInfo {
+                source,
+            }
+        });
+        let commitment_state =
+            CommitmentState::try_from_raw(raw_commitment_state).map_err(|source| {
+                Violation::InvalidCommitmentState {
+                    source,
+                }
+            });
+        let (genesis_info, commitment_state) = match (genesis_info, commitment_state) {
+            (Ok(genesis_info), Ok(commitment_state)) => (genesis_info, commitment_state),
+            (genesis_info, commitment_state) => {
+                self.diagnosis
+                    .violations
+                    .extend(genesis_info.err().into_iter().chain(commitment_state.err()));
+                return Ok(self.diagnosis);
+            }
+        };
+
+        self.check_state(genesis_info, &commitment_state);
+        self.check_blocks(&commitment_state).await;
+        Ok(self.diagnosis)
+    }
+
+    /// Checks the genesis info and commitment state with the validators conductor runs when it
+    /// initializes its state.
+    fn check_state(&mut self, genesis_info: GenesisInfo, commitment_state: &CommitmentState) {
+        let results = [
+            can_map_firm_to_sequencer_height(genesis_info, commitment_state),
+            can_map_soft_to_sequencer_height(genesis_info, commitment_state),
+            celestia_base_height_is_consistent(commitment_state),
+        ];
+        self.diagnosis
+            .violations
+            .extend(results.into_iter().filter_map(Result::err).map(|source| {
+                Violation::InvalidState {
+                    source,
+                }
+            }));
+    }
+
+    /// Checks that the blocks served by the execution layer match the firm and soft blocks of
+    /// the commitment state, and that each block from firm to soft links to its predecessor.
+    async fn check_blocks(&mut self, commitment_state: &CommitmentState) {
+        let firm = commitment_state.firm();
+        let soft = commitment_state.soft();
+        let mut previous: Option<Block> = None;
+        let mut first = firm.number();
+        loop {
+            let last = first
+                .saturating_add(MAX_BLOCKS_PER_REQUEST - 1)
+                .min(soft.number());
+            let blocks = self.fetch_blocks(first, last).await;
+            for (number, current) in (first..=last).zip(blocks) {
+                if number == firm.number() {
+                    self.check_matches(current.as_ref(), "firm", firm);
+                } else if let (Some(previous), Some(current)) = (&previous, &current) {
+                    if current.parent_block_hash() != previous.hash() {
+                        self.diagnosis
+                            .violations
+                            .push(Violation::BrokenHashLinkage {
+                                number,
+                                parent_block_hash: current.parent_block_hash().clone(),
+                                expected_parent_block_hash: previous.hash().clone(),
+                            });
+                    }
+                }
+                if number == soft.number() {
+                    self.check_matches(current.as_ref(), "soft", soft);
+                }
+                previous = current;
+            }
+            if last >= soft.number() {
+                break;
+            }
+            first = last.saturating_add(1);
+        }
+    }
+
+    fn check_matches(
+        &mut self,
+        fetched: Option<&Block>,
+        commitment_type: &'static str,
+        block: &Block,
+    ) {
+        let Some(fetched) = fetched else {
+            return;
+        };
+        if fetched.hash() != block.hash() {
+            self.diagnosis.violations.push(Violation::BlockMismatch {
+                commitment_type,
+                number: block.number(),
+                expected_hash: block.hash().clone(),
+                actual_hash: fetched.hash().clone(),
+            });
+        }
+    }
+
+    /// Fetches the blocks at rollup numbers `first` to `last` in a single request, returning one
+    /// entry per requested number.
+    ///
+    /// Records a violation for every block that cannot be fetched or is not valid, and returns
+    /// `None` in its place.
+    async fn fetch_blocks(&mut self, first: u32, last: u32) -> Vec<Option<Block>> {
+        let request = raw::BatchGetBlocksRequest {
+            identifiers: (first..=last)
+                .map(|number| raw::BlockIdentifier {
+                    identifier: Some(raw::block_identifier::Identifier::BlockNumber(number)),
+                })
+                .collect(),
+        };
+        let raw_blocks = match self.client.batch_get_blocks(request).await {
+            Ok(response) => response.into_inner().blocks,
+            Err(source) => {
+                self.diagnosis
+                    .violations
+                    .push(Violation::BlocksUnavailable {
+                        first,
+                        last,
+                        source,
+                    });
+                return (first..=last).map(|_| None).collect();
+            }
+        };
+        let mut raw_blocks = raw_blocks.into_iter();
+        (first..=last)
+            .map(|number| {
+                let Some(raw_block) = raw_blocks.next() else {
+                    self.diagnosis.violations.push(Violation::MissingBlock {
+                        number,
+                    });
+                    return None;
+                };
+                self.convert_block(number, raw_block)
+            })
+            .collect()
+    }
+
+    /// Converts the block returned for rollup `number`, recording a violation if it is not valid
+    /// or is not the requested block.
+    fn convert_block(&mut self, number: u32, raw_block: raw::Block) -> Option<Block> {
+        match Block::try_from_raw(raw_block) {
+            Ok(block) if block.number() == number => Some(block),
+            Ok(block) => {
+                self.diagnosis.violations.push(Violation::WrongBlockNumber {
+                    requested: number,
+                    returned: block.number(),
+                });
+                None
+            }
+            Err(source) => {
+                self.diagnosis.violations.push(Violation::InvalidBlock {
+                    number,
+                    source,
+                });
+                None
+            }
+        }
+    }
+}
@@ -2,6 +2,8 @@
 //! the other methods can be used. Otherwise, they will panic.
 //!
 //! The inner state must not be unset after having been set.
+use std::ops::RangeInclusive;
+
 use astria_core::{
     execution::v1alpha2::{
         Block,
@@ -32,16 +34,27 @@ pub(super) fn channel() -> (StateSender, StateReceiver) {
     (sender, receiver)
 }
 
+/// The height of the first Celestia block. Celestia has no blobs below it.
+pub(super) const FIRST_CELESTIA_HEIGHT: u64 = 1;
+
 #[derive(Debug, thiserror::Error)]
-#[error(
-    "adding sequencer genesis height `{sequencer_genesis_height}` and `{commitment_type}` rollup \
-     number `{rollup_number}` overflowed unsigned u32::MAX, the maximum permissible cometbft \
-     height"
-)]
-pub(super) struct InvalidState {
-    commitment_type: &'static str,
-    sequencer_genesis_height: u64,
-    rollup_number: u64,
+pub enum InvalidState {
+    #[error(
+        "adding sequencer genesis height `{sequencer_genesis_height}` and `{commitment_type}` \
+         rollup number `{rollup_number}` overflowed unsigned u32::MAX, the maximum permissible \
+         cometbft height"
+    )]
+    UnmappableRollupNumber {
+        commitment_type: &'static str,
+        sequencer_genesis_height: u64,
+        rollup_number: u64,
+    },
+    #[error(
+        "the base celestia height `{base_celestia_height}` of the initial commitment state is \
+         below the first celestia height `1`; conductor would start searching for the rollup's \
+         blobs at a celestia height that does not exist"
+    )]
+    CelestiaBaseHeightBelowGenesis { base_celestia_height: u64 },
 }
 
 #[derive(Clone, Debug)]
@@ -94,14 +107,14 @@ pub(super) struct StateSender {
     inner: watch::Sender<Option<State>>,
 }
 
-fn can_map_firm_to_sequencer_height(
+pub(super) fn can_map_firm_to_sequencer_height(
     genesis_info: GenesisInfo,
     commitment_state: &CommitmentState,
 ) -> Result<(), InvalidState> {
     let sequencer_genesis_height = genesis_info.sequencer_genesis_block_height();
     let rollup_number = commitment_state.firm().number();
     if map_rollup_number_to_sequencer_height(sequencer_genesis_height, rollup_number).is_none() {
-        Err(InvalidState {
+        Err(InvalidState::UnmappableRollupNumber {
             commitment_type: "firm",
             sequencer_genesis_height: sequencer_genesis_height.value(),
             rollup_number: rollup_number.into(),
@@ -111,14 +124,14 @@ fn can_map_firm_to_sequencer_height(
     }
 }
 
-fn can_map_soft_to_sequencer_height(
+pub(super) fn can_map_soft_to_sequencer_height(
     genesis_info: GenesisInfo,
     commitment_state: &CommitmentState,
 ) -> Result<(), InvalidState> {
     let sequencer_genesis_height = genesis_info.sequencer_genesis_block_height();
     let rollup_number = commitment_state.soft().number();
     if map_rollup_number_to_sequencer_height(sequencer_genesis_height, rollup_number).is_none() {
-        Err(InvalidState {
+        Err(InvalidState::UnmappableRollupNumber {
             commitment_type: "soft",
             sequencer_genesis_height: sequencer_genesis_height.value(),
             rollup_number: rollup_number.into(),
@@ -128,6 +141,20 @@ fn can_map_soft_to_sequencer_height(
     }
 }
 
+/// Checks that the Celestia height conductor starts searching at exists.
+pub(super) fn celestia_base_height_is_consistent(
+    commitment_state: &CommitmentState,
+) -> Result<(), InvalidState> {
+    let base_celestia_height = commitment_state.base_celestia_height();
+    if base_celestia_height < FIRST_CELESTIA_HEIGHT {
+        Err(InvalidState::CelestiaBaseHeightBelowGenesis {
+            base_celestia_height,
+        })
+    } else {
+        Ok(())
+    }
+}
+
 impl StateSender {
     pub(super) fn try_init(
         &mut self,
@@ -136,6 +163,7 @@ impl StateSender {
     ) -> Result<(), InvalidState> {
         can_map_firm_to_sequencer_height(genesis_info, &commitment_state)?;
         can_map_soft_to_sequencer_height(genesis_info, &commitment_state)?;
+        celestia_base_height_is_consistent(&commitment_state)?;
         self.inner.send_modify(move |state| {
             let old_state = state.replace(State::new(genesis_info, commitment_state));
             assert!(
@@ -211,6 +239,7 @@ macro_rules! forward_impls {
 forward_impls!(
     StateSender:
     [genesis_info -> GenesisInfo],
+    [commitment_state -> CommitmentState],
     [firm -> Block],
     [soft -> Block],
     [firm_number -> u32],
@@ -225,7 +254,7 @@ forward_impls!(
 
 forward_impls!(
     StateReceiver:
-    [celestia_base_block_height -> u64],
+    [celestia_search_range -> RangeInclusive<u32>],
     [celestia_block_variance -> u64],
     [rollup_id -> RollupId],
 );
@@ -254,6 +283,10 @@ impl State {
         self.genesis_info
     }
 
+    fn commitment_state(&self) -> &CommitmentState {
+        &self.commitment_state
+    }
+
     fn firm(&self) -> &Block {
         self.commitment_state.firm()
     }
@@ -286,6 +319,11 @@ impl State {
         self.genesis_info.celestia_block_variance()
     }
 
+    fn celestia_search_range(&self) -> RangeInclusive<u32> {
+        self.commitment_state
+            .celestia_search_range(&self.genesis_info)
+    }
+
     fn sequencer_genesis_block_height(&self) -> SequencerHeight {
         self.genesis_info.sequencer_genesis_block_height()
     }
@@ -313,7 +351,7 @@ impl State {
 ///
 /// Returns `None` if `sequencer_genesis_height + rollup_number` overflows
 /// `u32::MAX`.
-fn map_rollup_number_to_sequencer_height(
+pub(super) fn map_rollup_number_to_sequencer_height(
     sequencer_genesis_height: SequencerHeight,
     rollup_number: u32,
 ) -> Option<SequencerHeight> {
@@ -349,6 +387,12 @@ mod tests {
     use super::*;
 
     fn make_commitment_state() -> CommitmentState {
+        make_commitment_state_with_base_celestia_height(1)
+    }
+
+    fn make_commitment_state_with_base_celestia_height(
+        base_celestia_height: u64,
+    ) -> CommitmentState {
         let firm = Block::try_from_raw(raw::Block {
             number: 1,
             hash: vec![42u8; 32].into(),
@@ -372,7 +416,7 @@ mod tests {
         CommitmentState::builder()
             .firm(firm)
             .soft(soft)
-            .base_celestia_height(1u64)
+            .base_celestia_height(base_celestia_height)
             .build()
             .unwrap()
     }
@@ -426,4 +470,37 @@ mod tests {
         assert_height_is_correct(0, 1, 1);
         assert_height_is_correct(1, 0, 1);
     }
+
+    #[test]
+    fn state_with_consistent_celestia_base_height_is_initialized() {
+        let (mut tx, _rx) = super::channel();
+        tx.try_init(
+            make_genesis_info(),
+            make_commitment_state_with_base_celestia_height(FIRST_CELESTIA_HEIGHT),
+        )
+        .unwrap();
+        assert_eq!(1..=1, tx.get().as_ref().unwrap().celestia_search_range(),);
+    }
+
+    #[test]
+    fn state_with_celestia_base_height_below_genesis_is_rejected() {
+        let (mut tx, _rx) = super::channel();
+        let error = tx
+            .try_init(
+                make_genesis_info(),
+                make_commitment_state_with_base_celestia_height(0),
+            )
+            .unwrap_err();
+        assert!(
+            matches!(
+                error,
+                InvalidState::CelestiaBaseHeightBelowGenesis {
+                    base_celestia_height: 0,
+                    ..
+                }
+            ),
+            "unexpected error: {error}",
+        );
+        assert!(tx.get().is_none());
+    }
 }
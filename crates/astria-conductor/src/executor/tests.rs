@@ -1,3 +1,8 @@
+use std::time::{
+    Duration,
+    SystemTime,
+};
+
 use astria_core::{
     self,
     execution::v1alpha2::{
@@ -9,13 +14,23 @@ use astria_core::{
     Protobuf as _,
 };
 use bytes::Bytes;
+use sha2::{
+    Digest as _,
+    Sha256,
+};
 
 use super::{
+    block_hash::BlockHashMismatch,
+    ensure_firm_block_is_not_ahead,
+    ensure_supported_epoch,
+    is_unchanged,
     should_execute_firm_block,
     state::{
         StateReceiver,
         StateSender,
     },
+    BlockHashVerifier,
+    NoopBlockHashVerifier,
     RollupId,
 };
 use crate::config::CommitLevel;
@@ -55,6 +70,7 @@ fn make_state(
         firm: Some(make_block(firm)),
         soft: Some(make_block(soft)),
         base_celestia_height: 1,
+        epoch: None,
     })
     .unwrap();
     let (mut tx, rx) = super::state::channel();
@@ -206,3 +222,146 @@ fn should_execute_firm() {
          don't match"
     );
 }
+
+/// A verifier for a rollup whose block hash is the sha256 hash over the block number and the
+/// parent block hash.
+struct NumberAndParentVerifier;
+
+impl NumberAndParentVerifier {
+    fn hash(number: u32, parent_block_hash: &[u8]) -> Bytes {
+        let mut hasher = Sha256::new();
+        hasher.update(number.to_be_bytes());
+        hasher.update(parent_block_hash);
+        Bytes::copy_from_slice(&hasher.finalize())
+    }
+}
+
+impl BlockHashVerifier for NumberAndParentVerifier {
+    fn verify(&self, block: &Block) -> Result<(), BlockHashMismatch> {
+        let expected = Self::hash(block.number(), block.parent_block_hash());
+        if expected == block.hash() {
+            Ok(())
+        } else {
+            Err(BlockHashMismatch::new(block, &expected))
+        }
+    }
+}
+
+fn make_hashed_block(number: u32) -> raw::Block {
+    let mut block = make_block(number);
+    block.hash = NumberAndParentVerifier::hash(number, &block.parent_block_hash);
+    block
+}
+
+#[test]
+fn verifier_accepts_block_with_matching_hash() {
+    let block = Block::try_from_raw(make_hashed_block(2)).unwrap();
+    NumberAndParentVerifier.verify(&block).unwrap();
+}
+
+#[test]
+fn verifier_rejects_tampered_block() {
+    let mut tampered = make_hashed_block(2);
+    tampered.parent_block_hash = Bytes::from_static(&[1u8; 32]);
+    let tampered = Block::try_from_raw(tampered).unwrap();
+    let error = NumberAndParentVerifier.verify(&tampered).unwrap_err();
+    assert!(error.to_string().contains("block number `2`"));
+}
+
+#[test]
+fn noop_verifier_accepts_any_block() {
+    let mut tampered = make_hashed_block(2);
+    tampered.hash = Bytes::from_static(&[1u8; 32]);
+    NoopBlockHashVerifier
+        .verify(&Block::try_from_raw(tampered).unwrap())
+        .unwrap();
+}
+
+fn make_block_at(seconds: i64) -> Block {
+    let mut block = make_block(2);
+    block.timestamp = Some(pbjson_types::Timestamp {
+        seconds,
+        nanos: 0,
+    });
+    Block::try_from_raw(block).unwrap()
+}
+
+fn seconds_after_epoch(seconds: u64) -> SystemTime {
+    SystemTime::UNIX_EPOCH
+        .checked_add(Duration::from_secs(seconds))
+        .unwrap()
+}
+
+#[test]
+fn firm_block_within_skew_is_accepted() {
+    const MAX_SKEW: Duration = Duration::from_secs(10);
+    // a block 5 seconds in the future
+    ensure_firm_block_is_not_ahead(&make_block_at(100), seconds_after_epoch(95), MAX_SKEW).unwrap();
+    // a block from the past
+    ensure_firm_block_is_not_ahead(&make_block_at(100), seconds_after_epoch(200), MAX_SKEW)
+        .unwrap();
+}
+
+#[test]
+fn firm_block_beyond_skew_is_rejected() {
+    const MAX_SKEW: Duration = Duration::from_secs(10);
+    let error =
+        ensure_firm_block_is_not_ahead(&make_block_at(100), seconds_after_epoch(50), MAX_SKEW)
+            .unwrap_err();
+    assert!(error.to_string().contains("50000ms ahead"), "{error}");
+}
+
+fn make_commitment_state(firm: u32, soft: u32, base_celestia_height: u64) -> CommitmentState {
+    CommitmentState::try_from_raw(raw::CommitmentState {
+        firm: Some(make_block(firm)),
+        soft: Some(make_block(soft)),
+        base_celestia_height,
+        epoch: None,
+    })
+    .unwrap()
+}
+
+#[test]
+fn identical_commitment_state_is_unchanged() {
+    let current = make_commitment_state(2, 3, 10);
+    assert!(is_unchanged(&current, &make_commitment_state(2, 3, 10)));
+}
+
+#[test]
+fn commitment_state_with_other_blocks_or_celestia_height_is_changed() {
+    let current = make_commitment_state(2, 3, 10);
+    assert!(!is_unchanged(&current, &make_commitment_state(2, 4, 10)));
+    assert!(!is_unchanged(&current, &make_commitment_state(3, 3, 10)));
+    assert!(!is_unchanged(&current, &make_commitment_state(2, 3, 11)));
+}
+
+fn make_commitment_state_of_epoch(epoch: Option<u32>) -> CommitmentState {
+    CommitmentState::try_from_raw(raw::CommitmentState {
+        firm: Some(make_block(2)),
+        soft: Some(make_block(3)),
+        base_celestia_height: 10,
+        epoch,
+    })
+    .unwrap()
+}
+
+#[test]
+fn commitment_state_of_current_epoch_is_supported() {
+    ensure_supported_epoch(&make_commitment_state_of_epoch(None)).unwrap();
+    ensure_supported_epoch(&make_commitment_state_of_epoch(Some(
+        CommitmentState::CURRENT_EPOCH,
+    )))
+    .unwrap();
+}
+
+#[test]
+fn commitment_state_of_other_epoch_is_rejected() {
+    for epoch in [0, CommitmentState::CURRENT_EPOCH.saturating_add(1)] {
+        let error =
+            ensure_supported_epoch(&make_commitment_state_of_epoch(Some(epoch))).unwrap_err();
+        assert!(
+            error.to_string().contains(&format!("epoch `{epoch}`")),
+            "{error}"
+        );
+    }
+}
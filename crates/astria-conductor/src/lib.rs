@@ -21,3 +21,9 @@ mod utils;
 pub use build_info::BUILD_INFO;
 pub use conductor::Conductor;
 pub use config::Config;
+pub use executor::{
+    self_test,
+    BlockHashMismatch,
+    BlockHashVerifier,
+    NoopBlockHashVerifier,
+};
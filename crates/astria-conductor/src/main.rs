@@ -1,6 +1,7 @@
 use std::process::ExitCode;
 
 use astria_conductor::{
+    self_test,
     Conductor,
     Config,
     BUILD_INFO,
@@ -44,6 +45,10 @@ async fn main() -> ExitCode {
         Ok(cfg) => cfg,
     };
 
+    if std::env::args().nth(1).as_deref() == Some("--self-test") {
+        return run_self_test(&cfg.execution_rpc_url).await;
+    }
+
     let mut telemetry_conf = telemetry::configure()
         .set_no_otel(cfg.no_otel)
         .set_force_stdout(cfg.force_stdout)
@@ -103,3 +108,21 @@ async fn main() -> ExitCode {
         }
     )
 }
+
+/// Runs the self-test against the execution layer, exiting with success only if it is healthy.
+async fn run_self_test(execution_rpc_url: &str) -> ExitCode {
+    match self_test::run(execution_rpc_url).await {
+        Err(error) => {
+            eprintln!("failed running self-test of the execution layer:\n{error:?}");
+            ExitCode::FAILURE
+        }
+        Ok(diagnosis) => {
+            println!("{diagnosis}");
+            if diagnosis.is_healthy() {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            }
+        }
+    }
+}
@@ -1,9 +1,13 @@
+use astria_core::primitive::v1::RollupId;
 use metrics::{
     counter,
     describe_counter,
+    describe_gauge,
     describe_histogram,
+    gauge,
     histogram,
     Counter,
+    Gauge,
     Histogram,
     Unit,
 };
@@ -13,6 +17,8 @@ const NAMESPACE_TYPE_LABEL: &str = "namespace_type";
 const NAMESPACE_TYPE_METADATA: &str = "metadata";
 const NAMESPACE_TYPE_ROLLUP_DATA: &str = "rollup_data";
 
+const ROLLUP_ID_LABEL: &str = "rollup_id";
+
 pub(crate) struct Metrics {
     metadata_blobs_per_celestia_fetch: Histogram,
     rollup_data_blobs_per_celestia_fetch: Histogram,
@@ -21,9 +27,6 @@ pub(crate) struct Metrics {
     decoded_rollup_data_items_per_celestia_fetch: Histogram,
     sequencer_blocks_metadata_verified_per_celestia_fetch: Histogram,
     sequencer_block_information_reconstructed_per_celestia_fetch: Histogram,
-    executed_firm_block_number: Counter,
-    executed_soft_block_number: Counter,
-    transactions_per_executed_block: Histogram,
 }
 
 impl Metrics {
@@ -87,14 +90,12 @@ impl Metrics {
             Unit::Count,
             "The number/rollup height of the last executed or confirmed firm block"
         );
-        let executed_firm_block_number = counter!(EXECUTED_FIRM_BLOCK_NUMBER);
 
         describe_counter!(
             EXECUTED_SOFT_BLOCK_NUMBER,
             Unit::Count,
             "The number/rollup height of the last executed soft block"
         );
-        let executed_soft_block_number = counter!(EXECUTED_SOFT_BLOCK_NUMBER);
 
         describe_histogram!(
             TRANSACTIONS_PER_EXECUTED_BLOCK,
@@ -102,7 +103,27 @@ impl Metrics {
             "The number of transactions that were included in the latest block executed against \
              the rollup"
         );
-        let transactions_per_executed_block = histogram!(TRANSACTIONS_PER_EXECUTED_BLOCK);
+
+        describe_counter!(
+            COMMITMENT_STATE_UPDATES,
+            Unit::Count,
+            "The number of commitment state updates accepted by the rollup"
+        );
+
+        describe_gauge!(
+            FIRM_TO_SOFT_BLOCK_GAP,
+            Unit::Count,
+            "The number of blocks the soft commitment is ahead of the firm commitment after the \
+             latest commitment state update"
+        );
+
+        describe_gauge!(
+            FIRM_TO_SOFT_ADVANCEMENT_RATIO,
+            Unit::Count,
+            "The number of blocks the firm commitment advanced per block the soft commitment \
+             advanced over recent commitment state updates; 1.0 means firm keeps up with soft, \
+             below 1.0 means firm is lagging"
+        );
 
         Self {
             metadata_blobs_per_celestia_fetch,
@@ -112,9 +133,6 @@ impl Metrics {
             decoded_rollup_data_items_per_celestia_fetch,
             sequencer_blocks_metadata_verified_per_celestia_fetch,
             sequencer_block_information_reconstructed_per_celestia_fetch,
-            executed_firm_block_number,
-            executed_soft_block_number,
-            transactions_per_executed_block,
         }
     }
 
@@ -170,23 +188,78 @@ impl Metrics {
             .record(block_count as f64);
     }
 
-    pub(crate) fn absolute_set_executed_firm_block_number(&self, block_number: u32) {
-        self.executed_firm_block_number
-            .absolute(u64::from(block_number));
+    pub(crate) fn absolute_set_executed_firm_block_number(
+        &self,
+        rollup_id: RollupId,
+        block_number: u32,
+    ) {
+        executed_firm_block_number(rollup_id).absolute(u64::from(block_number));
     }
 
-    pub(crate) fn absolute_set_executed_soft_block_number(&self, block_number: u32) {
-        self.executed_soft_block_number
-            .absolute(u64::from(block_number));
+    pub(crate) fn absolute_set_executed_soft_block_number(
+        &self,
+        rollup_id: RollupId,
+        block_number: u32,
+    ) {
+        executed_soft_block_number(rollup_id).absolute(u64::from(block_number));
+    }
+
+    pub(crate) fn increment_commitment_state_updates(&self, rollup_id: RollupId) {
+        commitment_state_updates(rollup_id).increment(1);
+    }
+
+    pub(crate) fn set_firm_to_soft_block_gap(&self, rollup_id: RollupId, gap: u32) {
+        firm_to_soft_block_gap(rollup_id).set(f64::from(gap));
     }
 
-    pub(crate) fn record_transactions_per_executed_block(&self, tx_count: usize) {
+    pub(crate) fn set_firm_to_soft_advancement_ratio(&self, rollup_id: RollupId, ratio: f64) {
+        firm_to_soft_advancement_ratio(rollup_id).set(ratio);
+    }
+
+    pub(crate) fn record_transactions_per_executed_block(
+        &self,
+        rollup_id: RollupId,
+        tx_count: usize,
+    ) {
         // allow: precision loss is unlikely (values too small) but also unimportant in histograms.
         #[allow(clippy::cast_precision_loss)]
-        self.transactions_per_executed_block.record(tx_count as f64);
+        transactions_per_executed_block(rollup_id).record(tx_count as f64);
     }
 }
 
+/// Returns the labels attached to all metrics scoped to the rollup identified by `rollup_id`.
+///
+/// The rollup ID is rendered as lower-case hex.
+fn rollup_labels(rollup_id: RollupId) -> [(&'static str, String); 1] {
+    [(ROLLUP_ID_LABEL, hex::encode(rollup_id))]
+}
+
+// XXX: The execution metrics are only known after the executor received the genesis info from
+// the rollup, so their handles are looked up in the global registry on every recording.
+fn executed_firm_block_number(rollup_id: RollupId) -> Counter {
+    counter!(EXECUTED_FIRM_BLOCK_NUMBER, &rollup_labels(rollup_id))
+}
+
+fn executed_soft_block_number(rollup_id: RollupId) -> Counter {
+    counter!(EXECUTED_SOFT_BLOCK_NUMBER, &rollup_labels(rollup_id))
+}
+
+fn transactions_per_executed_block(rollup_id: RollupId) -> Histogram {
+    histogram!(TRANSACTIONS_PER_EXECUTED_BLOCK, &rollup_labels(rollup_id))
+}
+
+fn commitment_state_updates(rollup_id: RollupId) -> Counter {
+    counter!(COMMITMENT_STATE_UPDATES, &rollup_labels(rollup_id))
+}
+
+fn firm_to_soft_block_gap(rollup_id: RollupId) -> Gauge {
+    gauge!(FIRM_TO_SOFT_BLOCK_GAP, &rollup_labels(rollup_id))
+}
+
+fn firm_to_soft_advancement_ratio(rollup_id: RollupId) -> Gauge {
+    gauge!(FIRM_TO_SOFT_ADVANCEMENT_RATIO, &rollup_labels(rollup_id))
+}
+
 metric_names!(pub const METRICS_NAMES:
     BLOBS_PER_CELESTIA_FETCH,
     CELESTIA_BLOB_FETCH_ERROR_COUNT,
@@ -196,18 +269,33 @@ metric_names!(pub const METRICS_NAMES:
 
     EXECUTED_FIRM_BLOCK_NUMBER,
     EXECUTED_SOFT_BLOCK_NUMBER,
-    TRANSACTIONS_PER_EXECUTED_BLOCK
+    TRANSACTIONS_PER_EXECUTED_BLOCK,
+    COMMITMENT_STATE_UPDATES,
+    FIRM_TO_SOFT_BLOCK_GAP,
+    FIRM_TO_SOFT_ADVANCEMENT_RATIO
 );
 
 #[cfg(test)]
 mod tests {
-    use super::TRANSACTIONS_PER_EXECUTED_BLOCK;
+    use std::collections::HashSet;
+
+    use astria_core::primitive::v1::RollupId;
+    use metrics_util::debugging::DebuggingRecorder;
+
+    use super::{
+        Metrics,
+        ROLLUP_ID_LABEL,
+        TRANSACTIONS_PER_EXECUTED_BLOCK,
+    };
     use crate::metrics::{
         BLOBS_PER_CELESTIA_FETCH,
         CELESTIA_BLOB_FETCH_ERROR_COUNT,
+        COMMITMENT_STATE_UPDATES,
         DECODED_ITEMS_PER_CELESTIA_FETCH,
         EXECUTED_FIRM_BLOCK_NUMBER,
         EXECUTED_SOFT_BLOCK_NUMBER,
+        FIRM_TO_SOFT_ADVANCEMENT_RATIO,
+        FIRM_TO_SOFT_BLOCK_GAP,
         SEQUENCER_BLOCKS_METADATA_VERIFIED_PER_CELESTIA_FETCH,
         SEQUENCER_BLOCK_INFORMATION_RECONSTRUCTED_PER_CELESTIA_FETCH,
     };
@@ -247,5 +335,53 @@ mod tests {
             TRANSACTIONS_PER_EXECUTED_BLOCK,
             "transactions_per_executed_block",
         );
+        assert_const(COMMITMENT_STATE_UPDATES, "commitment_state_updates");
+        assert_const(FIRM_TO_SOFT_BLOCK_GAP, "firm_to_soft_block_gap");
+        assert_const(
+            FIRM_TO_SOFT_ADVANCEMENT_RATIO,
+            "firm_to_soft_advancement_ratio",
+        );
+    }
+
+    #[test]
+    fn execution_metrics_are_recorded_with_rollup_id_label_as_hex() {
+        let recorder = DebuggingRecorder::new();
+        let snapshotter = recorder.snapshotter();
+        let rollup_id = RollupId::new([0xab; 32]);
+        metrics::with_local_recorder(&recorder, || {
+            let metrics = Metrics::new();
+            metrics.absolute_set_executed_firm_block_number(rollup_id, 1);
+            metrics.absolute_set_executed_soft_block_number(rollup_id, 2);
+            metrics.record_transactions_per_executed_block(rollup_id, 3);
+            metrics.increment_commitment_state_updates(rollup_id);
+            metrics.set_firm_to_soft_block_gap(rollup_id, 1);
+            metrics.set_firm_to_soft_advancement_ratio(rollup_id, 0.5);
+        });
+
+        let rollup_id_hex = "ab".repeat(32);
+        let labeled: HashSet<_> = snapshotter
+            .snapshot()
+            .into_vec()
+            .into_iter()
+            .filter_map(|(key, ..)| {
+                let key = key.key();
+                key.labels()
+                    .any(|label| label.key() == ROLLUP_ID_LABEL && label.value() == rollup_id_hex)
+                    .then(|| key.name().to_string())
+            })
+            .collect();
+        for name in [
+            EXECUTED_FIRM_BLOCK_NUMBER,
+            EXECUTED_SOFT_BLOCK_NUMBER,
+            TRANSACTIONS_PER_EXECUTED_BLOCK,
+            COMMITMENT_STATE_UPDATES,
+            FIRM_TO_SOFT_BLOCK_GAP,
+            FIRM_TO_SOFT_ADVANCEMENT_RATIO,
+        ] {
+            assert!(
+                labeled.contains(name),
+                "`{name}` was not recorded with the rollup ID label"
+            );
+        }
     }
 }
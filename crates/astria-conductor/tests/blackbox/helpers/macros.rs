@@ -68,6 +68,7 @@ macro_rules! commitment_state {
                 parent: $soft_parent,
             )),
            base_celestia_height: $base_celestia_height,
+           epoch: None,
         }
     };
 }
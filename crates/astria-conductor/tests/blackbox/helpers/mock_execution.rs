@@ -0,0 +1,324 @@
+//! An in-memory implementation of the `astria.execution.v1alpha2.ExecutionService`.
+//!
+//! Unlike the execution service served by [`super::MockGrpc`], which only replays the responses
+//! mounted by a test, [`MockExecution`] behaves like a rollup node: it executes blocks on top of
+//! the blocks it knows about, stores them, and tracks the commitment state set by conductor.
+//! Tests only provide the genesis info and initial commitment state, and can script failures of
+//! individual RPCs with [`MockExecution::fail_next`].
+
+use std::{
+    collections::{
+        BTreeMap,
+        HashMap,
+        VecDeque,
+    },
+    net::SocketAddr,
+    sync::{
+        Arc,
+        Mutex,
+    },
+    time::Duration,
+};
+
+use astria_core::generated::execution::v1alpha2::{
+    block_identifier::Identifier,
+    execution_service_server::{
+        ExecutionService,
+        ExecutionServiceServer,
+    },
+    BatchGetBlocksRequest,
+    BatchGetBlocksResponse,
+    Block,
+    BlockIdentifier,
+    CommitmentState,
+    ExecuteBlockRequest,
+    GenesisInfo,
+    GetBlockRequest,
+    GetCommitmentStateRequest,
+    GetGenesisInfoRequest,
+    UpdateCommitmentStateRequest,
+};
+use astria_eyre::eyre::{
+    self,
+    WrapErr as _,
+};
+use bytes::Bytes;
+use prost::Message as _;
+use sha2::{
+    Digest as _,
+    Sha256,
+};
+use tokio::{
+    sync::watch,
+    task::JoinHandle,
+};
+use tonic::{
+    transport::Server,
+    Request,
+    Response,
+    Status,
+};
+
+pub struct MockExecution {
+    _server: JoinHandle<eyre::Result<()>>,
+    pub local_addr: SocketAddr,
+    store: Arc<Mutex<Store>>,
+    commitment_state: watch::Receiver<CommitmentState>,
+}
+
+impl MockExecution {
+    /// Spawns an execution service whose chain starts at the firm and soft blocks of
+    /// `commitment_state`.
+    pub async fn spawn(genesis_info: GenesisInfo, commitment_state: CommitmentState) -> Self {
+        use tokio_stream::wrappers::TcpListenerStream;
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let local_addr = listener.local_addr().unwrap();
+
+        let (commitment_state_tx, commitment_state_rx) = watch::channel(commitment_state.clone());
+        let store = Arc::new(Mutex::new(Store::new(
+            genesis_info,
+            commitment_state,
+            commitment_state_tx,
+        )));
+
+        let server = {
+            let service = InMemoryExecutionService {
+                store: store.clone(),
+            };
+            tokio::spawn(async move {
+                Server::builder()
+                    .add_service(ExecutionServiceServer::new(service))
+                    .serve_with_incoming(TcpListenerStream::new(listener))
+                    .await
+                    .wrap_err("gRPC server failed")
+            })
+        };
+        Self {
+            _server: server,
+            local_addr,
+            store,
+            commitment_state: commitment_state_rx,
+        }
+    }
+
+    /// Makes the next call to `rpc` fail with `status` instead of being served.
+    ///
+    /// Failures scripted for the same RPC are returned in the order they were added.
+    pub fn fail_next(&self, rpc: &'static str, status: Status) {
+        self.store
+            .lock()
+            .unwrap()
+            .failures
+            .entry(rpc)
+            .or_default()
+            .push_back(status);
+    }
+
+    /// Returns the block at rollup height `number`, if it was executed or is part of the initial
+    /// commitment state.
+    pub fn block(&self, number: u32) -> Option<Block> {
+        self.store.lock().unwrap().blocks.get(&number).cloned()
+    }
+
+    /// Returns the commitment state last set by conductor.
+    pub fn commitment_state(&self) -> CommitmentState {
+        self.commitment_state.borrow().clone()
+    }
+
+    /// Waits until the commitment state satisfies `predicate`, returning it.
+    ///
+    /// # Panics
+    /// Panics if the commitment state does not satisfy `predicate` within `timeout`.
+    pub async fn wait_for_commitment_state<F>(
+        &self,
+        timeout: Duration,
+        mut predicate: F,
+    ) -> CommitmentState
+    where
+        F: FnMut(&CommitmentState) -> bool,
+    {
+        let mut commitment_state = self.commitment_state.clone();
+        tokio::time::timeout(timeout, commitment_state.wait_for(|state| predicate(state)))
+            .await
+            .unwrap_or_else(|_| {
+                panic!(
+                    "commitment state did not reach the expected state within {timeout:?}; last \
+                     seen: {:?}",
+                    self.commitment_state(),
+                )
+            })
+            .expect("the sender is held by the store and is never dropped")
+            .clone()
+    }
+}
+
+struct Store {
+    genesis_info: GenesisInfo,
+    blocks: BTreeMap<u32, Block>,
+    commitment_state: watch::Sender<CommitmentState>,
+    failures: HashMap<&'static str, VecDeque<Status>>,
+}
+
+impl Store {
+    fn new(
+        genesis_info: GenesisInfo,
+        commitment_state: CommitmentState,
+        commitment_state_tx: watch::Sender<CommitmentState>,
+    ) -> Self {
+        let blocks = [commitment_state.firm, commitment_state.soft]
+            .into_iter()
+            .flatten()
+            .map(|block| (block.number, block))
+            .collect();
+        Self {
+            genesis_info,
+            blocks,
+            commitment_state: commitment_state_tx,
+            failures: HashMap::new(),
+        }
+    }
+
+    /// Returns the failure scripted for the next call to `rpc`, if any.
+    fn take_failure(&mut self, rpc: &'static str) -> Result<(), Status> {
+        match self.failures.get_mut(rpc).and_then(VecDeque::pop_front) {
+            Some(status) => Err(status),
+            None => Ok(()),
+        }
+    }
+
+    fn find_block(&self, identifier: Option<&BlockIdentifier>) -> Result<Block, Status> {
+        let identifier = identifier
+            .and_then(|identifier| identifier.identifier.as_ref())
+            .ok_or_else(|| Status::invalid_argument("block identifier not set"))?;
+        let block = match identifier {
+            Identifier::BlockNumber(number) => self.blocks.get(number),
+            Identifier::BlockHash(hash) => self.blocks.values().find(|block| block.hash == *hash),
+        };
+        block
+            .cloned()
+            .ok_or_else(|| Status::not_found(format!("no block for identifier `{identifier:?}`")))
+    }
+
+    /// Returns if `block` was stored, matching blocks by their number and hash.
+    fn contains(&self, block: Option<&Block>) -> bool {
+        block.is_some_and(|block| {
+            self.blocks
+                .get(&block.number)
+                .is_some_and(|stored| stored.hash == block.hash)
+        })
+    }
+}
+
+/// Returns the hash of the block at `number` that executes `request`.
+fn block_hash(number: u32, request: &ExecuteBlockRequest) -> Bytes {
+    let mut hasher = Sha256::new();
+    hasher.update(&request.prev_block_hash);
+    hasher.update(number.to_be_bytes());
+    for transaction in &request.transactions {
+        hasher.update(transaction.encode_length_delimited_to_vec());
+    }
+    Bytes::copy_from_slice(&hasher.finalize())
+}
+
+struct InMemoryExecutionService {
+    store: Arc<Mutex<Store>>,
+}
+
+#[tonic::async_trait]
+impl ExecutionService for InMemoryExecutionService {
+    async fn get_block(
+        self: Arc<Self>,
+        request: Request<GetBlockRequest>,
+    ) -> tonic::Result<Response<Block>> {
+        let mut store = self.store.lock().unwrap();
+        store.take_failure("get_block")?;
+        let block = store.find_block(request.get_ref().identifier.as_ref())?;
+        Ok(Response::new(block))
+    }
+
+    async fn get_genesis_info(
+        self: Arc<Self>,
+        _request: Request<GetGenesisInfoRequest>,
+    ) -> tonic::Result<Response<GenesisInfo>> {
+        let mut store = self.store.lock().unwrap();
+        store.take_failure("get_genesis_info")?;
+        Ok(Response::new(store.genesis_info.clone()))
+    }
+
+    async fn batch_get_blocks(
+        self: Arc<Self>,
+        request: Request<BatchGetBlocksRequest>,
+    ) -> tonic::Result<Response<BatchGetBlocksResponse>> {
+        let mut store = self.store.lock().unwrap();
+        store.take_failure("batch_get_blocks")?;
+        let blocks = request
+            .get_ref()
+            .identifiers
+            .iter()
+            .map(|identifier| store.find_block(Some(identifier)))
+            .collect::<Result<_, _>>()?;
+        Ok(Response::new(BatchGetBlocksResponse {
+            blocks,
+        }))
+    }
+
+    async fn execute_block(
+        self: Arc<Self>,
+        request: Request<ExecuteBlockRequest>,
+    ) -> tonic::Result<Response<Block>> {
+        let mut store = self.store.lock().unwrap();
+        store.take_failure("execute_block")?;
+        let request = request.into_inner();
+        let parent = store
+            .blocks
+            .values()
+            .find(|block| block.hash == request.prev_block_hash)
+            .ok_or_else(|| Status::failed_precondition("parent block not found"))?;
+        let number = parent
+            .number
+            .checked_add(1)
+            .ok_or_else(|| Status::out_of_range("rollup block number overflowed"))?;
+        let block = Block {
+            number,
+            hash: block_hash(number, &request),
+            parent_block_hash: request.prev_block_hash.clone(),
+            timestamp: request.timestamp.clone(),
+        };
+        store.blocks.insert(number, block.clone());
+        Ok(Response::new(block))
+    }
+
+    async fn get_commitment_state(
+        self: Arc<Self>,
+        _request: Request<GetCommitmentStateRequest>,
+    ) -> tonic::Result<Response<CommitmentState>> {
+        let mut store = self.store.lock().unwrap();
+        store.take_failure("get_commitment_state")?;
+        let commitment_state = store.commitment_state.borrow().clone();
+        Ok(Response::new(commitment_state))
+    }
+
+    async fn update_commitment_state(
+        self: Arc<Self>,
+        request: Request<UpdateCommitmentStateRequest>,
+    ) -> tonic::Result<Response<CommitmentState>> {
+        let mut store = self.store.lock().unwrap();
+        store.take_failure("update_commitment_state")?;
+        let commitment_state = request
+            .into_inner()
+            .commitment_state
+            .ok_or_else(|| Status::invalid_argument("commitment state not set"))?;
+        if !store.contains(commitment_state.firm.as_ref())
+            || !store.contains(commitment_state.soft.as_ref())
+        {
+            return Err(Status::failed_precondition(
+                "firm and soft blocks must have been executed",
+            ));
+        }
+        store
+            .commitment_state
+            .send_replace(commitment_state.clone());
+        Ok(Response::new(commitment_state))
+    }
+}
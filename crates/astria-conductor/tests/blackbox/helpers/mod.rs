@@ -33,8 +33,10 @@ use sequencer_client::{
 
 #[macro_use]
 mod macros;
+mod mock_execution;
 mod mock_grpc;
 use astria_eyre;
+pub use mock_execution::MockExecution;
 pub use mock_grpc::MockGrpc;
 use serde_json::json;
 
@@ -71,6 +73,26 @@ static TELEMETRY: Lazy<()> = Lazy::new(|| {
 });
 
 pub async fn spawn_conductor(execution_commit_level: CommitLevel) -> TestConductor {
+    spawn_conductor_with_execution_url(execution_commit_level, None).await
+}
+
+/// Spawns a conductor executing blocks against `mock_execution` instead of the execution service
+/// served by [`MockGrpc`].
+pub async fn spawn_conductor_with_mock_execution(
+    execution_commit_level: CommitLevel,
+    mock_execution: &MockExecution,
+) -> TestConductor {
+    spawn_conductor_with_execution_url(
+        execution_commit_level,
+        Some(format!("http://{}", mock_execution.local_addr)),
+    )
+    .await
+}
+
+async fn spawn_conductor_with_execution_url(
+    execution_commit_level: CommitLevel,
+    execution_rpc_url: Option<String>,
+) -> TestConductor {
     assert_ne!(
         tokio::runtime::Handle::current().runtime_flavor(),
         tokio::runtime::RuntimeFlavor::CurrentThread,
@@ -85,7 +107,8 @@ pub async fn spawn_conductor(execution_commit_level: CommitLevel) -> TestConduct
 
     let config = Config {
         celestia_node_http_url: mock_http.uri(),
-        execution_rpc_url: format!("http://{}", mock_grpc.local_addr),
+        execution_rpc_url: execution_rpc_url
+            .unwrap_or_else(|| format!("http://{}", mock_grpc.local_addr)),
         sequencer_cometbft_url: mock_http.uri(),
         sequencer_grpc_url: format!("http://{}", mock_grpc.local_addr),
         execution_commit_level,
@@ -461,8 +484,11 @@ fn make_config() -> Config {
         sequencer_requests_per_second: 500,
         sequencer_block_time_ms: 2000,
         execution_rpc_url: "http://127.0.0.1:50051".into(),
+        expected_rollup_id: hex::encode(ROLLUP_ID.get()),
         log: "info".into(),
         execution_commit_level: astria_conductor::config::CommitLevel::SoftAndFirm,
+        execution_max_firm_block_skew_ms: 0,
+        execution_block_hash_length: 64,
         force_stdout: false,
         no_otel: false,
         no_metrics: true,
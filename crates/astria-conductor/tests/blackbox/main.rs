@@ -2,6 +2,7 @@
 pub mod firm_only;
 #[allow(clippy::missing_panics_doc)]
 pub mod helpers;
+pub mod self_test;
 pub mod shutdown;
 pub mod soft_and_firm;
 pub mod soft_only;
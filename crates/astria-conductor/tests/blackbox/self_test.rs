@@ -0,0 +1,127 @@
+use astria_conductor::self_test::{
+    self,
+    InvalidState,
+    Violation,
+};
+
+use crate::{
+    commitment_state,
+    genesis_info,
+    helpers::MockExecution,
+};
+
+/// Tests that the self-test finds no violations for an execution layer whose soft block builds on
+/// its firm block.
+#[tokio::test]
+async fn healthy_execution_layer_has_no_violations() {
+    let mock_execution = MockExecution::spawn(
+        genesis_info!(
+            sequencer_genesis_block_height: 1,
+            celestia_block_variance: 10,
+        ),
+        commitment_state!(
+            firm: (
+                number: 1,
+                hash: [1; 64],
+                parent: [0; 64],
+            ),
+            soft: (
+                number: 2,
+                hash: [2; 64],
+                parent: [1; 64],
+            ),
+            base_celestia_height: 1,
+        ),
+    )
+    .await;
+
+    let diagnosis = self_test::run(&format!("http://{}", mock_execution.local_addr))
+        .await
+        .unwrap();
+    assert!(diagnosis.is_healthy(), "{diagnosis}");
+}
+
+/// Tests that the self-test reports all violations of an execution layer whose soft block does
+/// not build on its firm block, and whose base celestia height is below celestia's first block.
+#[tokio::test]
+async fn inconsistent_execution_layer_has_violations() {
+    let mock_execution = MockExecution::spawn(
+        genesis_info!(
+            sequencer_genesis_block_height: 1,
+            celestia_block_variance: 10,
+        ),
+        commitment_state!(
+            firm: (
+                number: 1,
+                hash: [1; 64],
+                parent: [0; 64],
+            ),
+            soft: (
+                number: 2,
+                hash: [2; 64],
+                parent: [9; 64],
+            ),
+            base_celestia_height: 0,
+        ),
+    )
+    .await;
+
+    let diagnosis = self_test::run(&format!("http://{}", mock_execution.local_addr))
+        .await
+        .unwrap();
+    assert!(!diagnosis.is_healthy());
+    let [Violation::InvalidState {
+        source:
+            InvalidState::CelestiaBaseHeightBelowGenesis {
+                base_celestia_height: 0,
+            },
+    }, Violation::BrokenHashLinkage {
+        number: 2,
+        parent_block_hash,
+        expected_parent_block_hash,
+    }] = diagnosis.violations()
+    else {
+        panic!("expected two violations, got: {diagnosis}");
+    };
+    assert_eq!(&[9; 64][..], &parent_block_hash[..]);
+    assert_eq!(&[1; 64][..], &expected_parent_block_hash[..]);
+}
+
+/// Tests that the self-test reports a batch of blocks from firm to soft that the execution layer
+/// cannot return.
+#[tokio::test]
+async fn missing_block_between_firm_and_soft_is_a_violation() {
+    let mock_execution = MockExecution::spawn(
+        genesis_info!(
+            sequencer_genesis_block_height: 1,
+            celestia_block_variance: 10,
+        ),
+        commitment_state!(
+            firm: (
+                number: 1,
+                hash: [1; 64],
+                parent: [0; 64],
+            ),
+            soft: (
+                number: 3,
+                hash: [3; 64],
+                parent: [2; 64],
+            ),
+            base_celestia_height: 1,
+        ),
+    )
+    .await;
+
+    let diagnosis = self_test::run(&format!("http://{}", mock_execution.local_addr))
+        .await
+        .unwrap();
+    let [Violation::BlocksUnavailable {
+        first: 1,
+        last: 3,
+        source,
+    }] = diagnosis.violations()
+    else {
+        panic!("expected exactly one violation, got: {diagnosis}");
+    };
+    assert_eq!(tonic::Code::NotFound, source.code());
+}
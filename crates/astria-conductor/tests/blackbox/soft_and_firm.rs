@@ -8,7 +8,13 @@ use futures::future::{
 use tokio::time::timeout;
 
 use crate::{
-    helpers::spawn_conductor,
+    commitment_state,
+    genesis_info,
+    helpers::{
+        spawn_conductor,
+        spawn_conductor_with_mock_execution,
+        MockExecution,
+    },
     mount_abci_info,
     mount_celestia_blobs,
     mount_celestia_header_network_head,
@@ -263,3 +269,83 @@ async fn missing_block_is_fetched_for_updating_firm_commitment() {
          within 1000ms",
     );
 }
+
+/// Tests that conductor advances the soft and firm commitments of a realistic execution service.
+///
+/// Mirrors [`simple`], but instead of mounting the expected execution requests, conductor runs
+/// against the in-memory [`MockExecution`]. The first attempt to execute the block fails with a
+/// retryable status.
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn simple_against_in_memory_execution() {
+    let mock_execution = MockExecution::spawn(
+        genesis_info!(
+            sequencer_genesis_block_height: 1,
+            celestia_block_variance: 10,
+        ),
+        commitment_state!(
+            firm: (
+                number: 1,
+                hash: [1; 64],
+                parent: [0; 64],
+            ),
+            soft: (
+                number: 1,
+                hash: [1; 64],
+                parent: [0; 64],
+            ),
+            base_celestia_height: 1,
+        ),
+    )
+    .await;
+    mock_execution.fail_next(
+        "execute_block",
+        tonic::Status::unavailable("rollup node is still starting"),
+    );
+
+    let test_conductor =
+        spawn_conductor_with_mock_execution(CommitLevel::SoftAndFirm, &mock_execution).await;
+
+    mount_abci_info!(
+        test_conductor,
+        latest_sequencer_height: 3,
+    );
+
+    mount_sequencer_genesis!(test_conductor);
+
+    mount_celestia_header_network_head!(
+        test_conductor,
+        height: 1u32,
+    );
+
+    mount_celestia_blobs!(
+        test_conductor,
+        celestia_height: 1,
+        sequencer_heights: [3],
+    );
+
+    mount_sequencer_commit!(
+        test_conductor,
+        height: 3u32,
+    );
+
+    mount_sequencer_validator_set!(test_conductor, height: 2u32);
+
+    mount_get_filtered_sequencer_block!(
+        test_conductor,
+        sequencer_height: 3,
+    );
+
+    let commitment_state = mock_execution
+        .wait_for_commitment_state(Duration::from_millis(2000), |state| {
+            state.firm.as_ref().map(|block| block.number) == Some(2)
+                && state.soft.as_ref().map(|block| block.number) == Some(2)
+        })
+        .await;
+
+    let executed = mock_execution
+        .block(2)
+        .expect("rollup block 2 should have been executed");
+    assert_eq!(executed.hash, commitment_state.firm.unwrap().hash);
+    assert_eq!(executed.hash, commitment_state.soft.unwrap().hash);
+    assert_eq!(&[1; 64][..], &executed.parent_block_hash[..]);
+}
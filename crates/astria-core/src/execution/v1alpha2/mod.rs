@@ -1,11 +1,22 @@
+use std::{
+    ops::RangeInclusive,
+    time::{
+        Duration,
+        SystemTime,
+    },
+};
+
 use bytes::Bytes;
 use pbjson_types::Timestamp;
 
 use crate::{
     generated::execution::v1alpha2 as raw,
-    primitive::v1::{
-        IncorrectRollupIdLength,
-        RollupId,
+    primitive::{
+        try_into_fixed,
+        v1::{
+            IncorrectRollupIdLength,
+            RollupId,
+        },
     },
     Protobuf,
 };
@@ -33,12 +44,12 @@ enum GenesisInfoErrorKind {
 ///
 /// Usually constructed its [`Protobuf`] implementation from a
 /// [`raw::GenesisInfo`].
+///
+/// Serializes to the protobuf JSON mapping of [`raw::GenesisInfo`], extended by a `rollup_id_hex`
+/// field holding the rollup ID as a hex string for human readers.
 #[derive(Clone, Copy, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize))]
-#[cfg_attr(
-    feature = "serde",
-    serde(into = "crate::generated::execution::v1alpha2::GenesisInfo")
-)]
+#[cfg_attr(feature = "serde", serde(into = "GenesisInfoJson"))]
 pub struct GenesisInfo {
     /// The rollup id which is used to identify the rollup txs.
     rollup_id: RollupId,
@@ -63,6 +74,143 @@ impl GenesisInfo {
     pub fn celestia_block_variance(&self) -> u64 {
         self.celestia_block_variance
     }
+
+    /// Returns the canonical JSON encoding of this genesis info.
+    ///
+    /// Keys are sorted and no whitespace is emitted, so the output is stable and can be hashed or
+    /// signed.
+    #[cfg(feature = "serde")]
+    #[must_use]
+    pub fn to_canonical_json(&self) -> String {
+        crate::serde::to_canonical_json(self)
+    }
+
+    /// Checks that the rollup ID of this genesis info matches the `reported` rollup ID.
+    ///
+    /// # Errors
+    /// Returns an error if the two rollup IDs differ.
+    pub fn assert_matches_rollup(&self, reported: &RollupId) -> Result<(), RollupMismatch> {
+        if self.rollup_id == *reported {
+            Ok(())
+        } else {
+            Err(RollupMismatch {
+                genesis: self.rollup_id,
+                reported: *reported,
+            })
+        }
+    }
+
+    /// Advises whether the configured `celestia_block_variance` suits the Celestia inclusion
+    /// latency seen in `observations`.
+    ///
+    /// Each observation pairs a sequencer height with the Celestia height its block was found at.
+    /// The observed variance is the largest number of Celestia blocks between the Celestia
+    /// heights of two successive sequencer heights in `observations`. This is how far conductor
+    /// has to search past the Celestia height of the last firm block to find the next one.
+    #[must_use]
+    pub fn advise_celestia_block_variance<I>(
+        &self,
+        observations: I,
+    ) -> CelestiaBlockVarianceAdvisory
+    where
+        I: IntoIterator<Item = (u64, u64)>,
+    {
+        let mut observations: Vec<_> = observations.into_iter().collect();
+        observations.sort_unstable_by_key(|(sequencer_height, _)| *sequencer_height);
+        let Some(observed) = observations
+            .iter()
+            .zip(observations.iter().skip(1))
+            .map(|((_, previous), (_, next))| next.saturating_sub(*previous))
+            .max()
+        else {
+            return CelestiaBlockVarianceAdvisory::InsufficientData;
+        };
+        let configured = self.celestia_block_variance;
+        if configured < observed {
+            CelestiaBlockVarianceAdvisory::TooSmall {
+                configured,
+                observed,
+            }
+        } else if configured
+            > observed
+                .max(1)
+                .saturating_mul(CELESTIA_BLOCK_VARIANCE_HEADROOM)
+        {
+            CelestiaBlockVarianceAdvisory::TooLarge {
+                configured,
+                observed,
+            }
+        } else {
+            CelestiaBlockVarianceAdvisory::Adequate {
+                configured,
+                observed,
+            }
+        }
+    }
+}
+
+/// The factor by which a configured Celestia block variance may exceed the observed variance
+/// before it is considered too large.
+const CELESTIA_BLOCK_VARIANCE_HEADROOM: u64 = 2;
+
+/// Advice on a configured Celestia block variance, returned by
+/// [`GenesisInfo::advise_celestia_block_variance`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CelestiaBlockVarianceAdvisory {
+    /// Fewer than two observations were given, so no variance could be observed.
+    InsufficientData,
+    /// The configured variance is smaller than the observed one. Conductor will miss sequencer
+    /// blocks that land outside of the Celestia heights it searches.
+    TooSmall { configured: u64, observed: u64 },
+    /// The configured variance is more than twice the observed one. Conductor searches Celestia
+    /// heights that are not expected to contain sequencer blocks.
+    TooLarge { configured: u64, observed: u64 },
+    /// The configured variance covers the observed one without excess.
+    Adequate { configured: u64, observed: u64 },
+}
+
+/// The rollup ID in a [`GenesisInfo`] did not match the rollup ID it was checked against.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, thiserror::Error)]
+#[error(
+    "genesis info contained rollup ID `{genesis}`, which does not match rollup ID `{reported}`"
+)]
+pub struct RollupMismatch {
+    genesis: RollupId,
+    reported: RollupId,
+}
+
+impl RollupMismatch {
+    /// The rollup ID contained in the genesis info.
+    #[must_use]
+    pub fn genesis(&self) -> RollupId {
+        self.genesis
+    }
+
+    /// The rollup ID the genesis info was checked against.
+    #[must_use]
+    pub fn reported(&self) -> RollupId {
+        self.reported
+    }
+}
+
+/// The JSON representation of [`GenesisInfo`].
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize)]
+struct GenesisInfoJson {
+    #[serde(flatten)]
+    raw: raw::GenesisInfo,
+    #[serde(serialize_with = "crate::primitive::v1::rollup_id_hex::serialize")]
+    rollup_id_hex: RollupId,
+}
+
+#[cfg(feature = "serde")]
+impl From<GenesisInfo> for GenesisInfoJson {
+    fn from(genesis_info: GenesisInfo) -> Self {
+        Self {
+            raw: genesis_info.to_raw(),
+            rollup_id_hex: genesis_info.rollup_id,
+        }
+    }
 }
 
 impl From<GenesisInfo> for raw::GenesisInfo {
@@ -120,12 +268,89 @@ impl BlockError {
     fn field_not_set(field: &'static str) -> Self {
         Self(BlockErrorKind::FieldNotSet(field))
     }
+
+    fn timestamp_out_of_range(timestamp: Timestamp) -> Self {
+        Self(BlockErrorKind::TimestampOutOfRange {
+            seconds: timestamp.seconds,
+            nanos: timestamp.nanos,
+        })
+    }
+
+    fn empty_hash(field: &'static str) -> Self {
+        Self(BlockErrorKind::EmptyHash(field))
+    }
+
+    fn invalid_timestamp(timestamp: Timestamp) -> Self {
+        Self(BlockErrorKind::InvalidTimestamp {
+            seconds: timestamp.seconds,
+            nanos: timestamp.nanos,
+        })
+    }
+
+    fn invalid_hash_length(field: &'static str, expected: usize, actual: usize) -> Self {
+        Self(BlockErrorKind::InvalidHashLength {
+            field,
+            expected,
+            actual,
+        })
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
 enum BlockErrorKind {
     #[error("{0} field not set")]
     FieldNotSet(&'static str),
+    #[error(
+        "timestamp with seconds `{seconds}` and nanos `{nanos}` cannot be normalized without \
+         overflowing"
+    )]
+    TimestampOutOfRange { seconds: i64, nanos: i32 },
+    #[error("{0} field was empty")]
+    EmptyHash(&'static str),
+    #[error(
+        "timestamp with seconds `{seconds}` and nanos `{nanos}` is not a valid protobuf timestamp"
+    )]
+    InvalidTimestamp { seconds: i64, nanos: i32 },
+    #[error("{field} field was expected to be `{expected}` bytes long, but was `{actual}` bytes")]
+    InvalidHashLength {
+        field: &'static str,
+        expected: usize,
+        actual: usize,
+    },
+}
+
+const NANOS_PER_SECOND: i32 = 1_000_000_000;
+/// The seconds of the earliest valid protobuf timestamp, `0001-01-01T00:00:00Z`.
+const MIN_TIMESTAMP_SECONDS: i64 = -62_135_596_800;
+/// The seconds of the latest valid protobuf timestamp, `9999-12-31T23:59:59Z`.
+const MAX_TIMESTAMP_SECONDS: i64 = 253_402_300_799;
+
+/// Normalizes `timestamp` so that its nanos are in `[0, 1e9)`, carrying whole seconds in the
+/// nanos into the seconds.
+///
+/// Returns `None` if the seconds overflow.
+fn normalize_timestamp(timestamp: &Timestamp) -> Option<Timestamp> {
+    let carry = timestamp.nanos.div_euclid(NANOS_PER_SECOND);
+    let nanos = timestamp.nanos.rem_euclid(NANOS_PER_SECOND);
+    let seconds = timestamp.seconds.checked_add(i64::from(carry))?;
+    Some(Timestamp {
+        seconds,
+        nanos,
+    })
+}
+
+/// Converts a normalized `timestamp` to a [`SystemTime`].
+///
+/// Returns `None` if the timestamp cannot be represented on this platform.
+fn timestamp_to_system_time(timestamp: &Timestamp) -> Option<SystemTime> {
+    let seconds = Duration::from_secs(timestamp.seconds.unsigned_abs());
+    let nanos = Duration::from_nanos(u64::try_from(timestamp.nanos).ok()?);
+    if timestamp.seconds.is_negative() {
+        SystemTime::UNIX_EPOCH.checked_sub(seconds)
+    } else {
+        SystemTime::UNIX_EPOCH.checked_add(seconds)
+    }?
+    .checked_add(nanos)
 }
 
 /// An Astria execution block on a rollup.
@@ -149,6 +374,8 @@ pub struct Block {
     /// The hash of the parent block
     parent_block_hash: Bytes,
     /// Timestamp on the block, standardized to google protobuf standard.
+    ///
+    /// Always normalized, i.e. its nanos are in `[0, 1e9)`.
     timestamp: Timestamp,
 }
 
@@ -168,12 +395,208 @@ impl Block {
         &self.parent_block_hash
     }
 
+    /// Returns the block's timestamp. The timestamp is always normalized.
     #[must_use]
     pub fn timestamp(&self) -> Timestamp {
         // prost_types::Timestamp is a (i64, i32) tuple, so this is
         // effectively just a copy
         self.timestamp.clone()
     }
+
+    /// Returns whether `self` and `other` contain the same block.
+    ///
+    /// All fields are compared by value: two blocks whose hashes are backed by different
+    /// buffers, for example of different capacities or slices into larger allocations, are equal
+    /// if the hash bytes are equal. This is the same as `==` and is provided to make the
+    /// guarantee explicit for callers deduplicating blocks.
+    #[must_use]
+    pub fn content_eq(&self, other: &Self) -> bool {
+        let Self {
+            number,
+            hash,
+            parent_block_hash,
+            timestamp,
+        } = self;
+        *number == other.number
+            && hash[..] == other.hash[..]
+            && parent_block_hash[..] == other.parent_block_hash[..]
+            && *timestamp == other.timestamp
+    }
+
+    /// Returns a commitment to the block's contents, independent of the hash reported for it.
+    ///
+    /// The commitment is the sha256 hash of the concatenation of:
+    ///
+    /// 1. `number` as 4 big-endian bytes;
+    /// 2. `timestamp.seconds` as 8 big-endian bytes;
+    /// 3. `timestamp.nanos` as 4 big-endian bytes;
+    /// 4. the bytes of `parent_block_hash`.
+    ///
+    /// All fields but the parent block hash are of fixed width, so placing it last makes the
+    /// preimage unambiguous without a length prefix. The block's own `hash` is excluded so that
+    /// the commitment can be cross-checked against the hash the execution layer reported: two
+    /// blocks with the same hash but different commitments mean the execution layer reported
+    /// inconsistent blocks.
+    #[must_use]
+    pub fn commitment(&self) -> [u8; 32] {
+        use sha2::{
+            Digest as _,
+            Sha256,
+        };
+
+        let Self {
+            number,
+            hash: _,
+            parent_block_hash,
+            timestamp,
+        } = self;
+        Sha256::new()
+            .chain_update(number.to_be_bytes())
+            .chain_update(timestamp.seconds.to_be_bytes())
+            .chain_update(timestamp.nanos.to_be_bytes())
+            .chain_update(parent_block_hash)
+            .finalize()
+            .into()
+    }
+
+    /// Returns the age of the block, i.e. the time elapsed between its timestamp and `now`.
+    ///
+    /// # Errors
+    /// Mirroring [`SystemTime::duration_since`], returns an error containing how far the block's
+    /// timestamp lies ahead of `now` if the block is from the future.
+    pub fn age(&self, now: SystemTime) -> Result<Duration, Duration> {
+        let Some(timestamp) = timestamp_to_system_time(&self.timestamp) else {
+            // The timestamp lies beyond what this platform can represent in either direction.
+            return if self.timestamp.seconds.is_negative() {
+                Ok(Duration::MAX)
+            } else {
+                Err(Duration::MAX)
+            };
+        };
+        now.duration_since(timestamp).map_err(|err| err.duration())
+    }
+
+    /// Checks that the block is well-formed.
+    ///
+    /// A block is well-formed if its hash and parent block hash are not empty and its timestamp
+    /// is a normalized timestamp within the range permitted by protobuf, i.e. between
+    /// `0001-01-01T00:00:00Z` and `9999-12-31T23:59:59.999999999Z`.
+    ///
+    /// # Errors
+    /// Returns an error if the block is not well-formed.
+    pub fn validate(&self) -> Result<(), BlockError> {
+        if self.hash.is_empty() {
+            return Err(BlockError::empty_hash(".hash"));
+        }
+        if self.parent_block_hash.is_empty() {
+            return Err(BlockError::empty_hash(".parent_block_hash"));
+        }
+        let Timestamp {
+            seconds,
+            nanos,
+        } = self.timestamp;
+        if !(MIN_TIMESTAMP_SECONDS..=MAX_TIMESTAMP_SECONDS).contains(&seconds)
+            || !(0..NANOS_PER_SECOND).contains(&nanos)
+        {
+            return Err(BlockError::invalid_timestamp(self.timestamp.clone()));
+        }
+        Ok(())
+    }
+
+    /// Checks that the block hash and parent block hash are both `expected` bytes long.
+    ///
+    /// The length of block hashes is a property of the rollup, so it is not checked when
+    /// converting from a [`raw::Block`]. Use [`Block::try_from_raw_with_hash_length`] to convert
+    /// and check in one step.
+    ///
+    /// # Errors
+    /// Returns an error if either hash is not `expected` bytes long.
+    pub fn validate_hash_length(&self, expected: usize) -> Result<(), BlockError> {
+        if self.hash.len() != expected {
+            return Err(BlockError::invalid_hash_length(
+                ".hash",
+                expected,
+                self.hash.len(),
+            ));
+        }
+        if self.parent_block_hash.len() != expected {
+            return Err(BlockError::invalid_hash_length(
+                ".parent_block_hash",
+                expected,
+                self.parent_block_hash.len(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Converts `raw` to a block, checking that its hashes are `hash_length` bytes long.
+    ///
+    /// # Errors
+    /// Returns an error if `raw` cannot be converted to a block, or if either of its hashes is
+    /// not `hash_length` bytes long.
+    pub fn try_from_raw_with_hash_length(
+        raw: raw::Block,
+        hash_length: usize,
+    ) -> Result<Self, BlockError> {
+        let block = Self::try_from_raw(raw)?;
+        block.validate_hash_length(hash_length)?;
+        Ok(block)
+    }
+
+    /// Returns a lightweight [`BlockRef`] to this block.
+    ///
+    /// Returns `None` if the block hash is not exactly `N` bytes long.
+    #[must_use]
+    pub fn as_ref_lite<const N: usize>(&self) -> Option<BlockRef<N>> {
+        let hash = try_into_fixed::<N>(&self.hash).ok()?;
+        Some(BlockRef {
+            number: self.number,
+            hash,
+        })
+    }
+}
+
+/// A lightweight, copyable reference to a [`Block`], identifying it by its number and its
+/// fixed-size hash.
+///
+/// Intended for caches and chain tracking where the full block is not needed. Obtained through
+/// [`Block::as_ref_lite`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BlockRef<const N: usize = 32> {
+    number: u32,
+    hash: [u8; N],
+}
+
+impl<const N: usize> BlockRef<N> {
+    #[must_use]
+    pub fn number(&self) -> u32 {
+        self.number
+    }
+
+    #[must_use]
+    pub fn hash(&self) -> &[u8; N] {
+        &self.hash
+    }
+}
+
+/// Compares a native block with its protobuf form by converting `self` with [`Block::to_raw`].
+///
+/// A raw block without a timestamp is never equal to a native block, which always carries one.
+/// The timestamp of the raw block is compared as is, so a raw block with an unnormalized timestamp
+/// is not equal to the native block obtained from it.
+impl PartialEq<raw::Block> for Block {
+    fn eq(&self, other: &raw::Block) -> bool {
+        if other.timestamp.is_none() {
+            return false;
+        }
+        self.to_raw() == *other
+    }
+}
+
+impl PartialEq<Block> for raw::Block {
+    fn eq(&self, other: &Block) -> bool {
+        other == self
+    }
 }
 
 impl From<Block> for raw::Block {
@@ -182,6 +605,12 @@ impl From<Block> for raw::Block {
     }
 }
 
+impl From<&Block> for raw::Block {
+    fn from(value: &Block) -> Self {
+        value.to_raw()
+    }
+}
+
 impl Protobuf for Block {
     type Error = BlockError;
     type Raw = raw::Block;
@@ -193,10 +622,11 @@ impl Protobuf for Block {
             parent_block_hash,
             timestamp,
         } = raw;
-        // Cloning timestamp is effectively a copy because timestamp is just a (i32, i64) tuple
         let timestamp = timestamp
-            .clone()
+            .as_ref()
             .ok_or(Self::Error::field_not_set(".timestamp"))?;
+        let timestamp = normalize_timestamp(timestamp)
+            .ok_or_else(|| Self::Error::timestamp_out_of_range(timestamp.clone()))?;
 
         Ok(Self {
             number: *number,
@@ -244,6 +674,37 @@ impl CommitmentStateError {
     fn firm_exceeds_soft(source: FirmExceedsSoft) -> Self {
         Self(CommitmentStateErrorKind::FirmExceedsSoft(source))
     }
+
+    fn block_number_decreased(field: &'static str, previous: u32, new: u32) -> Self {
+        Self(CommitmentStateErrorKind::BlockNumberDecreased {
+            field,
+            previous,
+            new,
+        })
+    }
+
+    fn hash_changed(field: &'static str, number: u32, previous: Bytes, new: Bytes) -> Self {
+        Self(CommitmentStateErrorKind::HashChanged {
+            field,
+            number,
+            previous,
+            new,
+        })
+    }
+
+    fn base_celestia_height_decreased(previous: u64, new: u64) -> Self {
+        Self(CommitmentStateErrorKind::BaseCelestiaHeightDecreased {
+            previous,
+            new,
+        })
+    }
+
+    fn next_sequencer_height_mismatch(expected: Option<u64>, actual: u64) -> Self {
+        Self(CommitmentStateErrorKind::NextSequencerHeightMismatch {
+            expected,
+            actual,
+        })
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -256,6 +717,26 @@ enum CommitmentStateErrorKind {
     Soft(#[source] BlockError),
     #[error(transparent)]
     FirmExceedsSoft(FirmExceedsSoft),
+    #[error("{field} block number decreased from `{previous}` to `{new}`")]
+    BlockNumberDecreased {
+        field: &'static str,
+        previous: u32,
+        new: u32,
+    },
+    #[error("{field} block at number `{number}` changed hash from `{previous:?}` to `{new:?}`")]
+    HashChanged {
+        field: &'static str,
+        number: u32,
+        previous: Bytes,
+        new: Bytes,
+    },
+    #[error("base celestia height decreased from `{previous}` to `{new}`")]
+    BaseCelestiaHeightDecreased { previous: u64, new: u64 },
+    #[error(
+        "next sequencer height `{actual}` does not follow the sequencer height of the soft block; \
+         expected `{expected:?}`"
+    )]
+    NextSequencerHeightMismatch { expected: Option<u64>, actual: u64 },
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -265,6 +746,48 @@ pub struct FirmExceedsSoft {
     soft: u32,
 }
 
+/// The error returned by [`CommitmentState::rewind_soft`].
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct RewindError(RewindErrorKind);
+
+impl RewindError {
+    fn not_a_rewind(soft: u32, to: u32) -> Self {
+        Self(RewindErrorKind::NotARewind {
+            soft,
+            to,
+        })
+    }
+
+    fn below_firm(firm: u32, to: u32) -> Self {
+        Self(RewindErrorKind::BelowFirm {
+            firm,
+            to,
+        })
+    }
+
+    fn conflicts_with_firm(number: u32, firm: Bytes, to: Bytes) -> Self {
+        Self(RewindErrorKind::ConflictsWithFirm {
+            number,
+            firm,
+            to,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum RewindErrorKind {
+    #[error("cannot rewind soft commitment at `{soft}` to block `{to}` which is not below it")]
+    NotARewind { soft: u32, to: u32 },
+    #[error("cannot rewind soft commitment to block `{to}` below firm commitment at `{firm}`")]
+    BelowFirm { firm: u32, to: u32 },
+    #[error(
+        "cannot rewind soft commitment to block `{number}` with hash `{to:?}` because the firm \
+         block at that number has hash `{firm:?}`"
+    )]
+    ConflictsWithFirm { number: u32, firm: Bytes, to: Bytes },
+}
+
 pub struct NoFirm;
 pub struct NoSoft;
 pub struct NoBaseCelestiaHeight;
@@ -280,6 +803,7 @@ pub struct CommitmentStateBuilder<
     firm: TFirm,
     soft: TSoft,
     base_celestia_height: TBaseCelestiaHeight,
+    epoch: u32,
 }
 
 impl CommitmentStateBuilder<NoFirm, NoSoft, NoBaseCelestiaHeight> {
@@ -288,6 +812,7 @@ impl CommitmentStateBuilder<NoFirm, NoSoft, NoBaseCelestiaHeight> {
             firm: NoFirm,
             soft: NoSoft,
             base_celestia_height: NoBaseCelestiaHeight,
+            epoch: CommitmentState::CURRENT_EPOCH,
         }
     }
 }
@@ -297,12 +822,14 @@ impl<TFirm, TSoft, TCelestiaBaseHeight> CommitmentStateBuilder<TFirm, TSoft, TCe
         let Self {
             soft,
             base_celestia_height,
+            epoch,
             ..
         } = self;
         CommitmentStateBuilder {
             firm: WithFirm(firm),
             soft,
             base_celestia_height,
+            epoch,
         }
     }
 
@@ -310,15 +837,43 @@ impl<TFirm, TSoft, TCelestiaBaseHeight> CommitmentStateBuilder<TFirm, TSoft, TCe
         let Self {
             firm,
             base_celestia_height,
+            epoch,
             ..
         } = self;
         CommitmentStateBuilder {
             firm,
             soft: WithSoft(soft),
             base_celestia_height,
+            epoch,
         }
     }
 
+    /// Sets the firm block from its protobuf representation.
+    ///
+    /// # Errors
+    /// Returns an error if `firm` is not a valid block.
+    pub fn firm_raw(
+        self,
+        firm: &raw::Block,
+    ) -> Result<CommitmentStateBuilder<WithFirm, TSoft, TCelestiaBaseHeight>, CommitmentStateError>
+    {
+        let firm = Block::try_from_raw_ref(firm).map_err(CommitmentStateError::firm)?;
+        Ok(self.firm(firm))
+    }
+
+    /// Sets the soft block from its protobuf representation.
+    ///
+    /// # Errors
+    /// Returns an error if `soft` is not a valid block.
+    pub fn soft_raw(
+        self,
+        soft: &raw::Block,
+    ) -> Result<CommitmentStateBuilder<TFirm, WithSoft, TCelestiaBaseHeight>, CommitmentStateError>
+    {
+        let soft = Block::try_from_raw_ref(soft).map_err(CommitmentStateError::soft)?;
+        Ok(self.soft(soft))
+    }
+
     pub fn base_celestia_height(
         self,
         base_celestia_height: u64,
@@ -326,12 +881,25 @@ impl<TFirm, TSoft, TCelestiaBaseHeight> CommitmentStateBuilder<TFirm, TSoft, TCe
         let Self {
             firm,
             soft,
+            epoch,
             ..
         } = self;
         CommitmentStateBuilder {
             firm,
             soft,
             base_celestia_height: WithCelestiaBaseHeight(base_celestia_height),
+            epoch,
+        }
+    }
+
+    /// Sets the version of the commitment state semantics.
+    ///
+    /// Defaults to [`CommitmentState::CURRENT_EPOCH`] if not set.
+    #[must_use]
+    pub fn epoch(self, epoch: u32) -> Self {
+        Self {
+            epoch,
+            ..self
         }
     }
 }
@@ -346,6 +914,7 @@ impl CommitmentStateBuilder<WithFirm, WithSoft, WithCelestiaBaseHeight> {
             firm: WithFirm(firm),
             soft: WithSoft(soft),
             base_celestia_height: WithCelestiaBaseHeight(base_celestia_height),
+            epoch,
         } = self;
         if firm.number() > soft.number() {
             return Err(FirmExceedsSoft {
@@ -357,8 +926,44 @@ impl CommitmentStateBuilder<WithFirm, WithSoft, WithCelestiaBaseHeight> {
             soft,
             firm,
             base_celestia_height,
+            epoch,
         })
     }
+
+    /// Finalize the commitment state after checking that the firm and soft blocks are
+    /// well-formed.
+    ///
+    /// Use this instead of [`CommitmentStateBuilder::build`] when the blocks come from an
+    /// untrusted source. See [`Block::validate`] for the checks performed on each block.
+    ///
+    /// # Errors
+    /// Returns an error if the firm or soft block is not well-formed, or if the firm block
+    /// exceeds the soft one.
+    pub fn build_validated(self) -> Result<CommitmentState, CommitmentStateError> {
+        self.firm.0.validate().map_err(CommitmentStateError::firm)?;
+        self.soft.0.validate().map_err(CommitmentStateError::soft)?;
+        self.build()
+            .map_err(CommitmentStateError::firm_exceeds_soft)
+    }
+
+    /// Finalize the commitment state as the successor of `previous`.
+    ///
+    /// Use this instead of [`CommitmentStateBuilder::build`] to check a commitment state before
+    /// sending it to the execution layer.
+    ///
+    /// # Errors
+    /// Returns an error if the firm block exceeds the soft one, or if the commitment state is not
+    /// a valid successor of `previous`, see [`commitment_state_transition_is_valid`].
+    pub fn build_successor_of(
+        self,
+        previous: &CommitmentState,
+    ) -> Result<CommitmentState, CommitmentStateError> {
+        let next = self
+            .build()
+            .map_err(CommitmentStateError::firm_exceeds_soft)?;
+        commitment_state_transition_is_valid(previous, &next)?;
+        Ok(next)
+    }
 }
 
 /// Information about the [`Block`] at each sequencer commitment level.
@@ -381,9 +986,16 @@ pub struct CommitmentState {
     /// The base height of celestia from which to search for blocks after this
     /// commitment state.
     base_celestia_height: u64,
+    /// The version of the commitment state semantics.
+    epoch: u32,
 }
 
 impl CommitmentState {
+    /// The version of the commitment state semantics implemented by this type.
+    ///
+    /// Commitment states received without an epoch are assumed to be of this epoch.
+    pub const CURRENT_EPOCH: u32 = 1;
+
     #[must_use = "a commitment state must be built to be useful"]
     pub fn builder() -> CommitmentStateBuilder {
         CommitmentStateBuilder::new()
@@ -399,9 +1011,172 @@ impl CommitmentState {
         &self.soft
     }
 
+    /// Returns if the firm and soft blocks of `self` and `other` have the same contents.
+    ///
+    /// The base celestia heights and epochs of the two commitment states are not compared.
+    #[must_use]
+    pub fn same_blocks(&self, other: &Self) -> bool {
+        self.firm.content_eq(&other.firm) && self.soft.content_eq(&other.soft)
+    }
+
     pub fn base_celestia_height(&self) -> u64 {
         self.base_celestia_height
     }
+
+    /// Returns the Celestia heights in which to search for blobs after this commitment state.
+    ///
+    /// The range starts at the base celestia height and spans the `celestia_block_variance` of
+    /// `genesis`. The upper bound saturates at `u32::MAX`.
+    ///
+    /// Base celestia heights and variances are `u64` on the wire, but Celestia heights beyond
+    /// `u32::MAX` will not be reached, so both are clamped to `u32::MAX`.
+    #[must_use]
+    pub fn celestia_search_range(&self, genesis: &GenesisInfo) -> RangeInclusive<u32> {
+        let start = u32::try_from(self.base_celestia_height).unwrap_or(u32::MAX);
+        let variance = u32::try_from(genesis.celestia_block_variance()).unwrap_or(u32::MAX);
+        start..=start.saturating_add(variance)
+    }
+
+    /// Checks that `next_sequencer_height` is the sequencer height right after the one the soft
+    /// block maps to under `genesis`.
+    ///
+    /// Rollup block number `n` maps to sequencer height `sequencer_genesis_block_height + n`, so
+    /// the next sequencer height is expected to be that of the soft block plus one.
+    ///
+    /// # Errors
+    /// Returns an error if `next_sequencer_height` does not match the expected height, or if the
+    /// expected height overflows `u64`.
+    pub fn validate_next_sequencer_height(
+        &self,
+        genesis: &GenesisInfo,
+        next_sequencer_height: u64,
+    ) -> Result<(), CommitmentStateError> {
+        let expected = genesis
+            .sequencer_genesis_block_height()
+            .value()
+            .checked_add(self.soft.number().into())
+            .and_then(|soft_sequencer_height| soft_sequencer_height.checked_add(1));
+        if expected != Some(next_sequencer_height) {
+            return Err(CommitmentStateError::next_sequencer_height_mismatch(
+                expected,
+                next_sequencer_height,
+            ));
+        }
+        Ok(())
+    }
+
+    /// Returns a commitment state with its soft block rewound to `to`.
+    ///
+    /// Soft blocks are otherwise never allowed to decrease in number (see
+    /// [`commitment_state_transition_is_valid`]). Rewinding is reserved for the rare case of a
+    /// sequencer rollback, so every rewind is logged at warn level. The firm block, the base
+    /// celestia height, and the epoch are kept.
+    ///
+    /// # Errors
+    /// Returns an error if `to` is not below the current soft block, if it is below the firm
+    /// block, or if it has the number of the firm block but another hash.
+    pub fn rewind_soft(&self, to: Block) -> Result<CommitmentState, RewindError> {
+        if to.number() >= self.soft.number() {
+            return Err(RewindError::not_a_rewind(self.soft.number(), to.number()));
+        }
+        if to.number() < self.firm.number() {
+            return Err(RewindError::below_firm(self.firm.number(), to.number()));
+        }
+        if to.number() == self.firm.number() && to.hash() != self.firm.hash() {
+            return Err(RewindError::conflicts_with_firm(
+                to.number(),
+                self.firm.hash().clone(),
+                to.hash().clone(),
+            ));
+        }
+        tracing::warn!(
+            from.number = self.soft.number(),
+            from.hash = %hex::encode(self.soft.hash()),
+            to.number = to.number(),
+            to.hash = %hex::encode(to.hash()),
+            firm.number = self.firm.number(),
+            "rewinding soft commitment to an earlier block",
+        );
+        Ok(CommitmentState {
+            soft: to,
+            firm: self.firm.clone(),
+            base_celestia_height: self.base_celestia_height,
+            epoch: self.epoch,
+        })
+    }
+
+    /// Returns the version of the commitment state semantics.
+    #[must_use]
+    pub fn epoch(&self) -> u32 {
+        self.epoch
+    }
+
+    /// Returns the canonical JSON encoding of this commitment state.
+    ///
+    /// Keys are sorted and no whitespace is emitted, so the output is stable and can be hashed or
+    /// signed.
+    #[cfg(feature = "serde")]
+    #[must_use]
+    pub fn to_canonical_json(&self) -> String {
+        crate::serde::to_canonical_json(self)
+    }
+}
+
+/// Checks that `new` is a valid successor of the commitment state `old`.
+///
+/// A transition is valid if:
+/// - neither the firm nor the soft block number decrease,
+/// - the firm block number does not exceed the soft block number,
+/// - the base celestia height does not decrease,
+/// - a block keeping its number also keeps its hash.
+///
+/// [`CommitmentStateBuilder::build_successor_of`] and [`CommitmentState::apply`] build commitment
+/// states that pass this check.
+///
+/// # Errors
+/// Returns an error describing the first of the above rules that is violated.
+pub fn commitment_state_transition_is_valid(
+    old: &CommitmentState,
+    new: &CommitmentState,
+) -> Result<(), CommitmentStateError> {
+    if new.firm().number() > new.soft().number() {
+        return Err(CommitmentStateError::firm_exceeds_soft(FirmExceedsSoft {
+            firm: new.firm().number(),
+            soft: new.soft().number(),
+        }));
+    }
+    block_transition_is_valid("firm", old.firm(), new.firm())?;
+    block_transition_is_valid("soft", old.soft(), new.soft())?;
+    if new.base_celestia_height() < old.base_celestia_height() {
+        return Err(CommitmentStateError::base_celestia_height_decreased(
+            old.base_celestia_height(),
+            new.base_celestia_height(),
+        ));
+    }
+    Ok(())
+}
+
+fn block_transition_is_valid(
+    field: &'static str,
+    old: &Block,
+    new: &Block,
+) -> Result<(), CommitmentStateError> {
+    match new.number().cmp(&old.number()) {
+        std::cmp::Ordering::Less => Err(CommitmentStateError::block_number_decreased(
+            field,
+            old.number(),
+            new.number(),
+        )),
+        std::cmp::Ordering::Equal if new.hash() != old.hash() => {
+            Err(CommitmentStateError::hash_changed(
+                field,
+                new.number(),
+                old.hash().clone(),
+                new.hash().clone(),
+            ))
+        }
+        _ => Ok(()),
+    }
 }
 
 impl From<CommitmentState> for raw::CommitmentState {
@@ -419,24 +1194,20 @@ impl Protobuf for CommitmentState {
             soft,
             firm,
             base_celestia_height,
+            epoch,
         } = raw;
-        let soft = 'soft: {
-            let Some(soft) = soft else {
-                break 'soft Err(Self::Error::field_not_set(".soft"));
-            };
-            Block::try_from_raw_ref(soft).map_err(Self::Error::soft)
-        }?;
-        let firm = 'firm: {
-            let Some(firm) = firm else {
-                break 'firm Err(Self::Error::field_not_set(".firm"));
-            };
-            Block::try_from_raw_ref(firm).map_err(Self::Error::firm)
-        }?;
+        let Some(soft) = soft else {
+            return Err(Self::Error::field_not_set(".soft"));
+        };
+        let Some(firm) = firm else {
+            return Err(Self::Error::field_not_set(".firm"));
+        };
 
         Self::builder()
-            .firm(firm)
-            .soft(soft)
+            .soft_raw(soft)?
+            .firm_raw(firm)?
             .base_celestia_height(*base_celestia_height)
+            .epoch(epoch.unwrap_or(Self::CURRENT_EPOCH))
             .build()
             .map_err(Self::Error::firm_exceeds_soft)
     }
@@ -446,6 +1217,7 @@ impl Protobuf for CommitmentState {
             soft,
             firm,
             base_celestia_height,
+            epoch,
         } = self;
         let soft = soft.to_raw();
         let firm = firm.to_raw();
@@ -454,6 +1226,1331 @@ impl Protobuf for CommitmentState {
             soft: Some(soft),
             firm: Some(firm),
             base_celestia_height,
+            epoch: Some(*epoch),
         }
     }
 }
+
+/// A bitmask of the fields changed by a [`CommitmentStateUpdate`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ChangedFields(u8);
+
+impl ChangedFields {
+    pub const BASE_CELESTIA_HEIGHT: Self = Self(1 << 2);
+    pub const FIRM: Self = Self(1 << 1);
+    pub const NONE: Self = Self(0);
+    pub const SOFT: Self = Self(1);
+
+    #[must_use]
+    pub fn bits(self) -> u8 {
+        self.0
+    }
+
+    /// Returns if all fields set in `other` are also set in `self`.
+    #[must_use]
+    pub fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl std::ops::BitOr for ChangedFields {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
+
+/// A block in a [`CommitmentStateUpdate`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum BlockUpdate {
+    /// The block changed and is sent in full.
+    Changed(Block),
+    /// The block is unchanged and only referenced by its hash.
+    Unchanged { hash: Bytes },
+}
+
+impl BlockUpdate {
+    fn between(previous: &Block, next: &Block) -> Self {
+        if previous == next {
+            Self::Unchanged {
+                hash: next.hash().clone(),
+            }
+        } else {
+            Self::Changed(next.clone())
+        }
+    }
+
+    fn is_changed(&self) -> bool {
+        matches!(self, Self::Changed(_))
+    }
+
+    fn apply(self, previous: &Block, field: &'static str) -> Result<Block, CommitmentUpdateError> {
+        match self {
+            Self::Changed(block) => Ok(block),
+            Self::Unchanged {
+                hash,
+            } => {
+                if &hash != previous.hash() {
+                    return Err(CommitmentUpdateError::hash_mismatch(
+                        field,
+                        previous.hash().clone(),
+                        hash,
+                    ));
+                }
+                Ok(previous.clone())
+            }
+        }
+    }
+}
+
+impl Protobuf for BlockUpdate {
+    type Error = BlockUpdateError;
+    type Raw = raw::BlockUpdate;
+
+    fn try_from_raw_ref(raw: &Self::Raw) -> Result<Self, Self::Error> {
+        let raw::BlockUpdate {
+            value,
+        } = raw;
+        match value {
+            None => Err(Self::Error::field_not_set(".value")),
+            Some(raw::block_update::Value::Changed(block)) => Block::try_from_raw_ref(block)
+                .map(Self::Changed)
+                .map_err(Self::Error::changed),
+            Some(raw::block_update::Value::UnchangedHash(hash)) => Ok(Self::Unchanged {
+                hash: hash.clone(),
+            }),
+        }
+    }
+
+    fn to_raw(&self) -> Self::Raw {
+        let value = match self {
+            Self::Changed(block) => raw::block_update::Value::Changed(block.to_raw()),
+            Self::Unchanged {
+                hash,
+            } => raw::block_update::Value::UnchangedHash(hash.clone()),
+        };
+        raw::BlockUpdate {
+            value: Some(value),
+        }
+    }
+}
+
+/// An error when transforming a [`raw::BlockUpdate`] into a [`BlockUpdate`].
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct BlockUpdateError(BlockUpdateErrorKind);
+
+impl BlockUpdateError {
+    fn field_not_set(field: &'static str) -> Self {
+        Self(BlockUpdateErrorKind::FieldNotSet(field))
+    }
+
+    fn changed(source: BlockError) -> Self {
+        Self(BlockUpdateErrorKind::Changed(source))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum BlockUpdateErrorKind {
+    #[error("{0} field not set")]
+    FieldNotSet(&'static str),
+    #[error(".changed field did not contain a valid block")]
+    Changed(#[source] BlockError),
+}
+
+/// A delta between two [`CommitmentState`]s.
+///
+/// Only the blocks that changed are carried in full, unchanged blocks are referenced by their
+/// hash. This is smaller than a full [`CommitmentState`] in the common case where only the soft
+/// block advanced, both natively and when encoded as a [`raw::CommitmentStateUpdate`].
+///
+/// Construct it with [`CommitmentStateUpdate::between`] and apply it with
+/// [`CommitmentState::apply`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct CommitmentStateUpdate {
+    soft: BlockUpdate,
+    firm: BlockUpdate,
+    base_celestia_height: Option<u64>,
+}
+
+impl CommitmentStateUpdate {
+    /// Constructs the update that takes `previous` to `next`.
+    #[must_use]
+    pub fn between(previous: &CommitmentState, next: &CommitmentState) -> Self {
+        Self {
+            soft: BlockUpdate::between(previous.soft(), next.soft()),
+            firm: BlockUpdate::between(previous.firm(), next.firm()),
+            base_celestia_height: (previous.base_celestia_height() != next.base_celestia_height())
+                .then_some(next.base_celestia_height()),
+        }
+    }
+
+    /// Constructs an update that carries all fields of `next`.
+    #[must_use]
+    pub fn full(next: &CommitmentState) -> Self {
+        Self {
+            soft: BlockUpdate::Changed(next.soft().clone()),
+            firm: BlockUpdate::Changed(next.firm().clone()),
+            base_celestia_height: Some(next.base_celestia_height()),
+        }
+    }
+
+    #[must_use]
+    pub fn soft(&self) -> &BlockUpdate {
+        &self.soft
+    }
+
+    #[must_use]
+    pub fn firm(&self) -> &BlockUpdate {
+        &self.firm
+    }
+
+    #[must_use]
+    pub fn base_celestia_height(&self) -> Option<u64> {
+        self.base_celestia_height
+    }
+
+    /// Returns the bitmask of fields carried in full by this update.
+    #[must_use]
+    pub fn changed_fields(&self) -> ChangedFields {
+        let mut changed = ChangedFields::NONE;
+        if self.soft.is_changed() {
+            changed = changed | ChangedFields::SOFT;
+        }
+        if self.firm.is_changed() {
+            changed = changed | ChangedFields::FIRM;
+        }
+        if self.base_celestia_height.is_some() {
+            changed = changed | ChangedFields::BASE_CELESTIA_HEIGHT;
+        }
+        changed
+    }
+}
+
+impl Protobuf for CommitmentStateUpdate {
+    type Error = CommitmentStateUpdateError;
+    type Raw = raw::CommitmentStateUpdate;
+
+    fn try_from_raw_ref(raw: &Self::Raw) -> Result<Self, Self::Error> {
+        let Self::Raw {
+            soft,
+            firm,
+            base_celestia_height,
+        } = raw;
+        let Some(soft) = soft else {
+            return Err(Self::Error::field_not_set(".soft"));
+        };
+        let Some(firm) = firm else {
+            return Err(Self::Error::field_not_set(".firm"));
+        };
+        Ok(Self {
+            soft: BlockUpdate::try_from_raw_ref(soft).map_err(Self::Error::soft)?,
+            firm: BlockUpdate::try_from_raw_ref(firm).map_err(Self::Error::firm)?,
+            base_celestia_height: *base_celestia_height,
+        })
+    }
+
+    fn to_raw(&self) -> Self::Raw {
+        let Self {
+            soft,
+            firm,
+            base_celestia_height,
+        } = self;
+        Self::Raw {
+            soft: Some(soft.to_raw()),
+            firm: Some(firm.to_raw()),
+            base_celestia_height: *base_celestia_height,
+        }
+    }
+}
+
+/// An error when transforming a [`raw::CommitmentStateUpdate`] into a [`CommitmentStateUpdate`].
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct CommitmentStateUpdateError(CommitmentStateUpdateErrorKind);
+
+impl CommitmentStateUpdateError {
+    fn field_not_set(field: &'static str) -> Self {
+        Self(CommitmentStateUpdateErrorKind::FieldNotSet(field))
+    }
+
+    fn soft(source: BlockUpdateError) -> Self {
+        Self(CommitmentStateUpdateErrorKind::Soft(source))
+    }
+
+    fn firm(source: BlockUpdateError) -> Self {
+        Self(CommitmentStateUpdateErrorKind::Firm(source))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum CommitmentStateUpdateErrorKind {
+    #[error("{0} field not set")]
+    FieldNotSet(&'static str),
+    #[error(".soft field did not contain a valid block update")]
+    Soft(#[source] BlockUpdateError),
+    #[error(".firm field did not contain a valid block update")]
+    Firm(#[source] BlockUpdateError),
+}
+
+impl CommitmentState {
+    /// Applies `delta` on top of `self`, returning the new commitment state.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - an unchanged block in `delta` does not reference the hash of the block in `self`,
+    /// - the resulting firm block exceeds the resulting soft block,
+    /// - the resulting commitment state is not a valid successor of `self`, see
+    ///   [`commitment_state_transition_is_valid`].
+    pub fn apply(&self, delta: CommitmentStateUpdate) -> Result<Self, CommitmentUpdateError> {
+        let CommitmentStateUpdate {
+            soft,
+            firm,
+            base_celestia_height,
+        } = delta;
+        let soft = soft.apply(self.soft(), "soft")?;
+        let firm = firm.apply(self.firm(), "firm")?;
+        let next = Self::builder()
+            .firm(firm)
+            .soft(soft)
+            .base_celestia_height(base_celestia_height.unwrap_or(self.base_celestia_height()))
+            .epoch(self.epoch())
+            .build()
+            .map_err(CommitmentUpdateError::firm_exceeds_soft)?;
+        commitment_state_transition_is_valid(self, &next)
+            .map_err(CommitmentUpdateError::invalid_transition)?;
+        Ok(next)
+    }
+}
+
+/// An error when applying a [`CommitmentStateUpdate`] to a [`CommitmentState`].
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct CommitmentUpdateError(CommitmentUpdateErrorKind);
+
+impl CommitmentUpdateError {
+    fn hash_mismatch(field: &'static str, expected: Bytes, actual: Bytes) -> Self {
+        Self(CommitmentUpdateErrorKind::HashMismatch {
+            field,
+            expected,
+            actual,
+        })
+    }
+
+    fn firm_exceeds_soft(source: FirmExceedsSoft) -> Self {
+        Self(CommitmentUpdateErrorKind::FirmExceedsSoft(source))
+    }
+
+    fn invalid_transition(source: CommitmentStateError) -> Self {
+        Self(CommitmentUpdateErrorKind::InvalidTransition(source))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum CommitmentUpdateErrorKind {
+    #[error(
+        "unchanged {field} block referenced hash `{actual:?}`, but the previous {field} block has \
+         hash `{expected:?}`"
+    )]
+    HashMismatch {
+        field: &'static str,
+        expected: Bytes,
+        actual: Bytes,
+    },
+    #[error(transparent)]
+    FirmExceedsSoft(FirmExceedsSoft),
+    #[error("the updated commitment state is not a valid successor of the previous one")]
+    InvalidTransition(#[source] CommitmentStateError),
+}
+
+/// Asserts that two [`CommitmentState`]s are equal.
+///
+/// On mismatch, the panic message lists only the fields that differ instead of the `Debug` output
+/// of both commitment states.
+#[cfg(test)]
+macro_rules! assert_commitment_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        if let Some(mismatch) =
+            $crate::execution::v1alpha2::describe_commitment_state_mismatch(&$left, &$right)
+        {
+            panic!("commitment states are not equal:\n{mismatch}");
+        }
+    };
+}
+
+/// Describes the fields in which `left` and `right` differ, one per line, or returns `None` if
+/// they are equal.
+///
+/// Builds on [`CommitmentStateUpdate::between`] to find the blocks that changed.
+#[cfg(test)]
+fn describe_commitment_state_mismatch(
+    left: &CommitmentState,
+    right: &CommitmentState,
+) -> Option<String> {
+    use std::fmt::Write as _;
+
+    fn describe_block_mismatch(field: &str, left: &Block, right: &Block, out: &mut String) {
+        if left.number() != right.number() {
+            writeln!(
+                out,
+                "{field}.number: {} != {}",
+                left.number(),
+                right.number()
+            )
+            .unwrap();
+        }
+        if left.hash() != right.hash() {
+            writeln!(
+                out,
+                "{field}.hash: {} != {}",
+                hex::encode(left.hash()),
+                hex::encode(right.hash()),
+            )
+            .unwrap();
+        }
+        if left.parent_block_hash() != right.parent_block_hash() {
+            writeln!(
+                out,
+                "{field}.parent_block_hash: {} != {}",
+                hex::encode(left.parent_block_hash()),
+                hex::encode(right.parent_block_hash()),
+            )
+            .unwrap();
+        }
+        if left.timestamp() != right.timestamp() {
+            writeln!(
+                out,
+                "{field}.timestamp: {:?} != {:?}",
+                left.timestamp(),
+                right.timestamp(),
+            )
+            .unwrap();
+        }
+    }
+
+    let changed = CommitmentStateUpdate::between(left, right).changed_fields();
+    let mut out = String::new();
+    if changed.contains(ChangedFields::FIRM) {
+        describe_block_mismatch("firm", left.firm(), right.firm(), &mut out);
+    }
+    if changed.contains(ChangedFields::SOFT) {
+        describe_block_mismatch("soft", left.soft(), right.soft(), &mut out);
+    }
+    if changed.contains(ChangedFields::BASE_CELESTIA_HEIGHT) {
+        writeln!(
+            out,
+            "base_celestia_height: {} != {}",
+            left.base_celestia_height(),
+            right.base_celestia_height(),
+        )
+        .unwrap();
+    }
+    if left.epoch() != right.epoch() {
+        writeln!(out, "epoch: {} != {}", left.epoch(), right.epoch()).unwrap();
+    }
+    (!out.is_empty()).then_some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{
+        Duration,
+        SystemTime,
+    };
+
+    use bytes::Bytes;
+    use pbjson_types::Timestamp;
+
+    use super::{
+        commitment_state_transition_is_valid,
+        Block,
+        BlockErrorKind,
+        BlockRef,
+        BlockUpdate,
+        CelestiaBlockVarianceAdvisory,
+        ChangedFields,
+        CommitmentState,
+        CommitmentStateErrorKind,
+        CommitmentStateUpdate,
+        GenesisInfo,
+        RewindErrorKind,
+    };
+    use crate::{
+        generated::execution::v1alpha2 as raw,
+        primitive::v1::RollupId,
+        Protobuf as _,
+    };
+
+    fn make_block(number: u32) -> Block {
+        Block {
+            number,
+            hash: Bytes::from(vec![u8::try_from(number).unwrap(); 32]),
+            parent_block_hash: Bytes::from(vec![0; 32]),
+            timestamp: Timestamp {
+                seconds: i64::from(number),
+                nanos: 0,
+            },
+        }
+    }
+
+    fn make_commitment_state(firm: u32, soft: u32, base_celestia_height: u64) -> CommitmentState {
+        CommitmentState::builder()
+            .firm(make_block(firm))
+            .soft(make_block(soft))
+            .base_celestia_height(base_celestia_height)
+            .build()
+            .unwrap()
+    }
+
+    fn make_genesis_info(rollup_id: RollupId) -> GenesisInfo {
+        GenesisInfo {
+            rollup_id,
+            sequencer_genesis_block_height: 1u32.into(),
+            celestia_block_variance: 0,
+        }
+    }
+
+    fn make_raw_block_with_timestamp(seconds: i64, nanos: i32) -> raw::Block {
+        let mut block = make_block(1).to_raw();
+        block.timestamp = Some(Timestamp {
+            seconds,
+            nanos,
+        });
+        block
+    }
+
+    #[test]
+    fn block_timestamp_nanos_overflow_is_carried_into_seconds() {
+        let block = Block::try_from_raw(make_raw_block_with_timestamp(10, 1_500_000_000)).unwrap();
+        assert_eq!(
+            Timestamp {
+                seconds: 11,
+                nanos: 500_000_000,
+            },
+            block.timestamp(),
+        );
+    }
+
+    #[test]
+    fn block_timestamp_negative_nanos_are_normalized() {
+        let block = Block::try_from_raw(make_raw_block_with_timestamp(10, -500_000_000)).unwrap();
+        assert_eq!(
+            Timestamp {
+                seconds: 9,
+                nanos: 500_000_000,
+            },
+            block.timestamp(),
+        );
+    }
+
+    #[test]
+    fn normalized_block_timestamp_is_unchanged() {
+        let block = Block::try_from_raw(make_raw_block_with_timestamp(10, 999_999_999)).unwrap();
+        assert_eq!(
+            Timestamp {
+                seconds: 10,
+                nanos: 999_999_999,
+            },
+            block.timestamp(),
+        );
+    }
+
+    #[test]
+    fn block_timestamp_overflowing_seconds_is_rejected() {
+        assert!(
+            Block::try_from_raw(make_raw_block_with_timestamp(i64::MAX, 1_500_000_000)).is_err()
+        );
+    }
+
+    #[test]
+    fn age_of_past_block_is_time_since_its_timestamp() {
+        let block = make_block(10);
+        let now = SystemTime::UNIX_EPOCH
+            .checked_add(Duration::from_secs(15))
+            .unwrap();
+        assert_eq!(Ok(Duration::from_secs(5)), block.age(now));
+    }
+
+    #[test]
+    fn age_of_future_block_is_time_ahead_of_now() {
+        let block = make_block(10);
+        let now = SystemTime::UNIX_EPOCH
+            .checked_add(Duration::from_secs(4))
+            .unwrap();
+        assert_eq!(Err(Duration::from_secs(6)), block.age(now));
+    }
+
+    #[test]
+    fn well_formed_blocks_are_built_into_validated_commitment_state() {
+        CommitmentState::builder()
+            .firm(make_block(1))
+            .soft(make_block(2))
+            .base_celestia_height(1)
+            .build_validated()
+            .unwrap();
+    }
+
+    #[test]
+    fn firm_block_with_empty_hash_is_rejected_by_validated_build() {
+        let mut firm = make_block(1);
+        firm.hash = Bytes::new();
+        let error = CommitmentState::builder()
+            .firm(firm)
+            .soft(make_block(2))
+            .base_celestia_height(1)
+            .build_validated()
+            .unwrap_err();
+        assert!(error.to_string().contains(".firm"), "{error}");
+    }
+
+    #[test]
+    fn soft_block_with_out_of_range_timestamp_is_rejected_by_validated_build() {
+        let mut soft = make_block(2);
+        soft.timestamp.seconds = i64::MAX;
+        let error = CommitmentState::builder()
+            .firm(make_block(1))
+            .soft(soft)
+            .base_celestia_height(1)
+            .build_validated()
+            .unwrap_err();
+        assert!(error.to_string().contains(".soft"), "{error}");
+    }
+
+    #[test]
+    fn validated_build_still_rejects_firm_exceeding_soft() {
+        let _ = CommitmentState::builder()
+            .firm(make_block(2))
+            .soft(make_block(1))
+            .base_celestia_height(1)
+            .build_validated()
+            .expect_err("firm block exceeding soft block must be rejected");
+    }
+
+    #[test]
+    fn unvalidated_build_accepts_malformed_blocks() {
+        let mut firm = make_block(1);
+        firm.parent_block_hash = Bytes::new();
+        CommitmentState::builder()
+            .firm(firm)
+            .soft(make_block(2))
+            .base_celestia_height(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn blocks_from_differently_sized_buffers_are_equal() {
+        let block = make_block(3);
+
+        let mut hash = Vec::with_capacity(1024);
+        hash.extend_from_slice(&[3; 32]);
+        let mut padded_parent = vec![9; 8];
+        padded_parent.extend_from_slice(&[0; 32]);
+        padded_parent.extend_from_slice(&[9; 8]);
+        let other = Block {
+            number: 3,
+            hash: Bytes::from(hash),
+            parent_block_hash: Bytes::from(padded_parent).slice(8..40),
+            timestamp: block.timestamp(),
+        };
+
+        assert!(block.content_eq(&other));
+        assert!(other.content_eq(&block));
+        assert_eq!(block, other);
+    }
+
+    #[test]
+    fn commitment_states_with_same_blocks_but_other_celestia_height_have_same_blocks() {
+        let state = make_commitment_state(2, 3, 10);
+        assert!(state.same_blocks(&make_commitment_state(2, 3, 10)));
+        assert!(state.same_blocks(&make_commitment_state(2, 3, 11)));
+        assert!(!state.same_blocks(&make_commitment_state(2, 4, 10)));
+        assert!(!state.same_blocks(&make_commitment_state(3, 3, 10)));
+    }
+
+    #[test]
+    fn building_from_raw_blocks_matches_building_from_native_blocks() {
+        let from_raw = CommitmentState::builder()
+            .firm_raw(&make_block(2).into_raw())
+            .unwrap()
+            .soft_raw(&make_block(3).into_raw())
+            .unwrap()
+            .base_celestia_height(10)
+            .build()
+            .unwrap();
+        assert_eq!(make_commitment_state(2, 3, 10), from_raw);
+    }
+
+    #[test]
+    fn building_from_invalid_raw_block_names_the_offending_field() {
+        let mut raw = make_block(2).into_raw();
+        raw.timestamp = None;
+        let error = CommitmentState::builder()
+            .firm_raw(&make_block(1).into_raw())
+            .unwrap()
+            .soft_raw(&raw)
+            .err()
+            .expect("a block without timestamp must be rejected");
+        assert!(
+            matches!(error.0, CommitmentStateErrorKind::Soft(_)),
+            "expected a soft block error, got {error:?}"
+        );
+    }
+
+    #[test]
+    fn block_with_hashes_of_expected_length_is_accepted() {
+        Block::try_from_raw_with_hash_length(make_block(1).to_raw(), 32).unwrap();
+    }
+
+    #[track_caller]
+    fn assert_invalid_hash_length(raw: raw::Block, field: &'static str, actual: usize) {
+        let error = Block::try_from_raw_with_hash_length(raw, 32)
+            .expect_err("a block with a hash of the wrong length must be rejected");
+        let BlockErrorKind::InvalidHashLength {
+            field: actual_field,
+            expected: 32,
+            actual: actual_length,
+        } = error.0
+        else {
+            panic!("expected an invalid hash length error, got {error:?}");
+        };
+        assert_eq!(field, actual_field);
+        assert_eq!(actual, actual_length);
+    }
+
+    #[test]
+    fn block_with_hashes_of_wrong_length_is_rejected() {
+        let mut raw = make_block(1).to_raw();
+        raw.hash = Bytes::from(vec![1; 31]);
+        assert_invalid_hash_length(raw, ".hash", 31);
+
+        let mut raw = make_block(1).to_raw();
+        raw.parent_block_hash = Bytes::from(vec![0; 64]);
+        assert_invalid_hash_length(raw, ".parent_block_hash", 64);
+    }
+
+    #[test]
+    fn blocks_with_different_contents_are_not_equal() {
+        let block = make_block(3);
+
+        let mut other_hash = block.clone();
+        other_hash.hash = Bytes::from(vec![4; 32]);
+        assert!(!block.content_eq(&other_hash));
+
+        let mut other_parent = block.clone();
+        other_parent.parent_block_hash = Bytes::from(vec![1; 32]);
+        assert!(!block.content_eq(&other_parent));
+
+        let mut other_timestamp = block.clone();
+        other_timestamp.timestamp.nanos = 1;
+        assert!(!block.content_eq(&other_timestamp));
+
+        assert!(!block.content_eq(&make_block(4)));
+    }
+
+    #[test]
+    fn block_equals_its_raw_form() {
+        let block = make_block(3);
+        let raw = block.to_raw();
+        assert_eq!(block, raw);
+        assert_eq!(raw, block);
+
+        let mut other_hash = raw.clone();
+        other_hash.hash = Bytes::from(vec![4; 32]);
+        assert_ne!(block, other_hash);
+        assert_ne!(other_hash, block);
+
+        let mut without_timestamp = raw;
+        without_timestamp.timestamp = None;
+        assert_ne!(block, without_timestamp);
+    }
+
+    #[test]
+    fn block_ref_lite_keeps_number_and_hash() {
+        let block = make_block(3);
+        let block_ref: BlockRef = block.as_ref_lite().unwrap();
+        assert_eq!(3, block_ref.number());
+        assert_eq!(&[3; 32], block_ref.hash());
+    }
+
+    #[test]
+    fn block_ref_lite_requires_exact_hash_length() {
+        let block = make_block(3);
+        assert!(block.as_ref_lite::<20>().is_none());
+        assert!(block.as_ref_lite::<64>().is_none());
+    }
+
+    #[test]
+    fn block_refs_are_equal_for_same_number_and_hash() {
+        let block_ref: BlockRef = make_block(3).as_ref_lite().unwrap();
+        let copied = block_ref;
+        assert_eq!(block_ref, copied);
+        assert_eq!(block_ref, make_block(3).as_ref_lite().unwrap());
+        assert_ne!(block_ref, make_block(4).as_ref_lite().unwrap());
+
+        let mut other_number = make_block(3);
+        other_number.number = 5;
+        assert_ne!(block_ref, other_number.as_ref_lite().unwrap());
+    }
+
+    #[test]
+    fn genesis_info_matches_same_rollup() {
+        let rollup_id = RollupId::new([42; 32]);
+        make_genesis_info(rollup_id)
+            .assert_matches_rollup(&rollup_id)
+            .unwrap();
+    }
+
+    #[test]
+    fn genesis_info_rejects_other_rollup() {
+        let genesis = RollupId::new([42; 32]);
+        let reported = RollupId::new([24; 32]);
+        let err = make_genesis_info(genesis)
+            .assert_matches_rollup(&reported)
+            .expect_err("different rollup IDs must not match");
+        assert_eq!(genesis, err.genesis());
+        assert_eq!(reported, err.reported());
+    }
+
+    fn make_genesis_info_with_variance(celestia_block_variance: u64) -> GenesisInfo {
+        GenesisInfo {
+            celestia_block_variance,
+            ..make_genesis_info(RollupId::new([42; 32]))
+        }
+    }
+
+    // Sequencer heights 2 to 5 land on Celestia heights 10, 11, 14, and 15. Listed out of order
+    // to check that observations are ordered by sequencer height.
+    const OBSERVATIONS: [(u64, u64); 4] = [(4, 14), (2, 10), (5, 15), (3, 11)];
+
+    #[test]
+    fn variance_below_observed_is_too_small() {
+        assert_eq!(
+            CelestiaBlockVarianceAdvisory::TooSmall {
+                configured: 2,
+                observed: 3,
+            },
+            make_genesis_info_with_variance(2).advise_celestia_block_variance(OBSERVATIONS),
+        );
+    }
+
+    #[test]
+    fn variance_within_headroom_of_observed_is_adequate() {
+        for configured in [3, 6] {
+            assert_eq!(
+                CelestiaBlockVarianceAdvisory::Adequate {
+                    configured,
+                    observed: 3,
+                },
+                make_genesis_info_with_variance(configured)
+                    .advise_celestia_block_variance(OBSERVATIONS),
+            );
+        }
+    }
+
+    #[test]
+    fn variance_beyond_headroom_of_observed_is_too_large() {
+        assert_eq!(
+            CelestiaBlockVarianceAdvisory::TooLarge {
+                configured: 7,
+                observed: 3,
+            },
+            make_genesis_info_with_variance(7).advise_celestia_block_variance(OBSERVATIONS),
+        );
+    }
+
+    #[test]
+    fn variance_advisory_needs_two_observations() {
+        let genesis_info = make_genesis_info_with_variance(10);
+        assert_eq!(
+            CelestiaBlockVarianceAdvisory::InsufficientData,
+            genesis_info.advise_celestia_block_variance([]),
+        );
+        assert_eq!(
+            CelestiaBlockVarianceAdvisory::InsufficientData,
+            genesis_info.advise_celestia_block_variance([(2, 10)]),
+        );
+    }
+
+    #[test]
+    fn equal_commitment_states_pass_assertion() {
+        assert_commitment_eq!(
+            make_commitment_state(1, 2, 3),
+            make_commitment_state(1, 2, 3)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "commitment states are not equal:\nsoft.number: 2 != 3\n")]
+    fn commitment_state_assertion_reports_mismatched_field() {
+        let mut right = make_commitment_state(1, 2, 3);
+        right.soft.number = 3;
+        assert_commitment_eq!(make_commitment_state(1, 2, 3), right);
+    }
+
+    #[test]
+    fn mismatch_description_lists_only_differing_fields() {
+        let left = make_commitment_state(1, 2, 3);
+        let right = make_commitment_state(1, 4, 5);
+        let description = super::describe_commitment_state_mismatch(&left, &right).unwrap();
+        assert_eq!(
+            format!(
+                "soft.number: 2 != 4\nsoft.hash: {} != {}\nsoft.timestamp: {:?} != \
+                 {:?}\nbase_celestia_height: 3 != 5\n",
+                hex::encode([2; 32]),
+                hex::encode([4; 32]),
+                left.soft().timestamp(),
+                right.soft().timestamp(),
+            ),
+            description,
+        );
+    }
+
+    #[test]
+    fn celestia_search_range_spans_block_variance() {
+        let genesis = GenesisInfo {
+            celestia_block_variance: 5,
+            ..make_genesis_info(RollupId::new([42; 32]))
+        };
+        assert_eq!(
+            10..=15,
+            make_commitment_state(1, 2, 10).celestia_search_range(&genesis),
+        );
+    }
+
+    #[test]
+    fn next_sequencer_height_following_soft_block_is_accepted() {
+        // sequencer genesis height 1 maps soft block 2 to sequencer height 3
+        make_commitment_state(1, 2, 1)
+            .validate_next_sequencer_height(&make_genesis_info(RollupId::new([42; 32])), 4)
+            .unwrap();
+    }
+
+    #[test]
+    fn off_by_one_next_sequencer_height_is_rejected() {
+        let genesis = make_genesis_info(RollupId::new([42; 32]));
+        let commitment_state = make_commitment_state(1, 2, 1);
+        commitment_state
+            .validate_next_sequencer_height(&genesis, 3)
+            .unwrap_err();
+        commitment_state
+            .validate_next_sequencer_height(&genesis, 5)
+            .unwrap_err();
+    }
+
+    #[test]
+    fn celestia_search_range_saturates_upper_bound() {
+        let genesis = GenesisInfo {
+            celestia_block_variance: 5,
+            ..make_genesis_info(RollupId::new([42; 32]))
+        };
+        assert_eq!(
+            u32::MAX - 2..=u32::MAX,
+            make_commitment_state(1, 2, u64::from(u32::MAX - 2)).celestia_search_range(&genesis),
+        );
+    }
+
+    #[test]
+    fn soft_only_delta_is_applied() {
+        let previous = make_commitment_state(1, 2, 10);
+        let next = make_commitment_state(1, 3, 10);
+
+        let delta = CommitmentStateUpdate::between(&previous, &next);
+        assert_eq!(ChangedFields::SOFT, delta.changed_fields());
+        assert_eq!(
+            &BlockUpdate::Unchanged {
+                hash: previous.firm().hash().clone(),
+            },
+            delta.firm(),
+        );
+
+        assert_eq!(next, previous.apply(delta).unwrap());
+    }
+
+    #[test]
+    fn full_delta_is_applied() {
+        let previous = make_commitment_state(1, 2, 10);
+        let next = make_commitment_state(3, 4, 12);
+
+        let delta = CommitmentStateUpdate::full(&next);
+        assert_eq!(
+            ChangedFields::SOFT | ChangedFields::FIRM | ChangedFields::BASE_CELESTIA_HEIGHT,
+            delta.changed_fields(),
+        );
+        assert_eq!(delta, CommitmentStateUpdate::between(&previous, &next));
+
+        assert_eq!(next, previous.apply(delta).unwrap());
+    }
+
+    #[test]
+    fn deltas_roundtrip_through_raw() {
+        let previous = make_commitment_state(1, 2, 10);
+        for delta in [
+            CommitmentStateUpdate::between(&previous, &make_commitment_state(1, 3, 10)),
+            CommitmentStateUpdate::full(&make_commitment_state(3, 4, 12)),
+        ] {
+            assert_eq!(
+                delta,
+                CommitmentStateUpdate::try_from_raw(delta.to_raw()).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn soft_only_delta_is_smaller_on_the_wire_than_commitment_state() {
+        use prost::Message as _;
+
+        let previous = make_commitment_state(1, 2, 10);
+        let next = make_commitment_state(1, 3, 10);
+        let delta = CommitmentStateUpdate::between(&previous, &next);
+        assert!(delta.to_raw().encoded_len() < next.to_raw().encoded_len());
+    }
+
+    #[test]
+    fn raw_block_update_without_value_is_rejected() {
+        let mut raw = CommitmentStateUpdate::full(&make_commitment_state(1, 2, 10)).to_raw();
+        raw.soft = Some(raw::BlockUpdate {
+            value: None,
+        });
+        let _ = CommitmentStateUpdate::try_from_raw(raw)
+            .expect_err("a block update must either carry a block or a hash");
+    }
+
+    #[test]
+    fn unchanged_block_with_wrong_hash_is_rejected() {
+        let previous = make_commitment_state(1, 2, 10);
+        let other = make_commitment_state(2, 3, 10);
+        let delta = CommitmentStateUpdate::between(&other, &other);
+        let _ = previous
+            .apply(delta)
+            .expect_err("unchanged blocks referencing different hashes must be rejected");
+    }
+
+    #[test]
+    fn decreasing_block_number_is_rejected() {
+        let previous = make_commitment_state(1, 3, 10);
+        let next = make_commitment_state(1, 2, 10);
+        let delta = CommitmentStateUpdate::between(&previous, &next);
+        let _ = previous
+            .apply(delta)
+            .expect_err("soft block number must not decrease");
+    }
+
+    #[test]
+    fn commitment_state_transitions_are_validated() {
+        let with_firm_hash = |state: &CommitmentState, hash: u8| CommitmentState {
+            firm: Block {
+                hash: Bytes::from(vec![hash; 32]),
+                ..state.firm().clone()
+            },
+            ..state.clone()
+        };
+        let with_soft_hash = |state: &CommitmentState, hash: u8| CommitmentState {
+            soft: Block {
+                hash: Bytes::from(vec![hash; 32]),
+                ..state.soft().clone()
+            },
+            ..state.clone()
+        };
+        let old = make_commitment_state(2, 4, 10);
+
+        let valid = [
+            ("unchanged", old.clone()),
+            ("soft advances", make_commitment_state(2, 5, 10)),
+            ("firm advances", make_commitment_state(3, 4, 10)),
+            ("firm catches up with soft", make_commitment_state(4, 4, 10)),
+            (
+                "base celestia height advances",
+                make_commitment_state(2, 4, 11),
+            ),
+        ];
+        for (rule, new) in valid {
+            commitment_state_transition_is_valid(&old, &new)
+                .unwrap_or_else(|err| panic!("`{rule}` must be valid, but got: {err}"));
+        }
+
+        let invalid = [
+            ("firm decreases", make_commitment_state(1, 4, 10)),
+            ("soft decreases", make_commitment_state(2, 3, 10)),
+            (
+                "firm exceeds soft",
+                CommitmentState {
+                    firm: make_block(5),
+                    soft: make_block(4),
+                    base_celestia_height: 10,
+                    epoch: CommitmentState::CURRENT_EPOCH,
+                },
+            ),
+            (
+                "base celestia height decreases",
+                make_commitment_state(2, 4, 9),
+            ),
+            ("firm hash changes at same number", with_firm_hash(&old, 42)),
+            ("soft hash changes at same number", with_soft_hash(&old, 42)),
+        ];
+        for (rule, new) in invalid {
+            assert!(
+                commitment_state_transition_is_valid(&old, &new).is_err(),
+                "`{rule}` must be invalid",
+            );
+        }
+    }
+
+    #[test]
+    fn builder_only_builds_valid_successors() {
+        let previous = make_commitment_state(2, 4, 10);
+
+        let next = CommitmentState::builder()
+            .firm(make_block(3))
+            .soft(make_block(5))
+            .base_celestia_height(11)
+            .build_successor_of(&previous)
+            .unwrap();
+        assert_eq!(make_commitment_state(3, 5, 11), next);
+
+        let _ = CommitmentState::builder()
+            .firm(make_block(2))
+            .soft(make_block(3))
+            .base_celestia_height(10)
+            .build_successor_of(&previous)
+            .expect_err("soft must not decrease");
+        let _ = CommitmentState::builder()
+            .firm(make_block(2))
+            .soft(make_block(4))
+            .base_celestia_height(9)
+            .build_successor_of(&previous)
+            .expect_err("base celestia height must not decrease");
+    }
+
+    #[test]
+    fn decreasing_base_celestia_height_is_rejected_on_apply() {
+        let previous = make_commitment_state(1, 2, 10);
+        let next = make_commitment_state(1, 2, 9);
+        let delta = CommitmentStateUpdate::between(&previous, &next);
+        let _ = previous
+            .apply(delta)
+            .expect_err("base celestia height must not decrease");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn canonical_json_is_independent_of_construction_order() {
+        let firm_first = CommitmentState::builder()
+            .firm(make_block(1))
+            .soft(make_block(2))
+            .base_celestia_height(10)
+            .build()
+            .unwrap();
+        let soft_first = CommitmentState::builder()
+            .base_celestia_height(10)
+            .soft(make_block(2))
+            .firm(make_block(1))
+            .build()
+            .unwrap();
+        let json = firm_first.to_canonical_json();
+        assert_eq!(json, firm_first.to_canonical_json());
+        assert_eq!(json, soft_first.to_canonical_json());
+        assert!(!json.contains(char::is_whitespace));
+
+        let base_celestia_height = json.find("\"base_celestia_height\"").unwrap();
+        let firm = json.find("\"firm\"").unwrap();
+        let soft = json.find("\"soft\"").unwrap();
+        assert!(
+            base_celestia_height < firm && firm < soft,
+            "keys must be sorted, but got: {json}",
+        );
+
+        let genesis_json = GenesisInfo {
+            celestia_block_variance: 5,
+            ..make_genesis_info(RollupId::new([42; 32]))
+        }
+        .to_canonical_json();
+        let variance = genesis_json.find("\"celestia_block_variance\"").unwrap();
+        let rollup_id = genesis_json.find("\"rollup_id\"").unwrap();
+        let rollup_id_hex = genesis_json.find("\"rollup_id_hex\"").unwrap();
+        let height = genesis_json
+            .find("\"sequencer_genesis_block_height\"")
+            .unwrap();
+        assert!(
+            variance < rollup_id && rollup_id < rollup_id_hex && rollup_id_hex < height,
+            "keys must be sorted, but got: {genesis_json}",
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn genesis_info_json_extends_protobuf_json_by_hex_rollup_id() {
+        let genesis_info = GenesisInfo {
+            celestia_block_variance: 5,
+            ..make_genesis_info(RollupId::new([42; 32]))
+        };
+        let mut expected = serde_json::to_value(genesis_info.to_raw()).unwrap();
+        expected.as_object_mut().unwrap().insert(
+            "rollup_id_hex".to_string(),
+            serde_json::Value::String(hex::encode([42; 32])),
+        );
+        assert_eq!(expected, serde_json::to_value(genesis_info).unwrap());
+        // the protobuf JSON mapping encodes 64 bit integers as strings
+        assert_eq!(serde_json::json!("5"), expected["celestia_block_variance"]);
+    }
+
+    #[test]
+    fn borrowing_conversion_to_raw_matches_consuming_conversion() {
+        let block = make_block(3);
+        let borrowed = raw::Block::from(&block);
+        let consumed = raw::Block::from(block);
+        assert_eq!(consumed, borrowed);
+    }
+
+    #[test]
+    fn block_commitment_matches_golden_value() {
+        assert_eq!(
+            "3959efda7d20896913106d241ec0ec0e407465d1c18e5bc3002515ef2645ce04",
+            hex::encode(make_block(7).commitment()),
+        );
+    }
+
+    #[test]
+    fn block_commitment_ignores_reported_hash() {
+        assert_eq!(
+            make_block(7).commitment(),
+            make_block_with_hash(7, 42).commitment(),
+        );
+    }
+
+    #[test]
+    fn block_commitment_changes_with_every_committed_field() {
+        let block = make_block(7);
+        let changed = [
+            Block {
+                number: 8,
+                ..block.clone()
+            },
+            Block {
+                parent_block_hash: Bytes::from(vec![1; 32]),
+                ..block.clone()
+            },
+            Block {
+                parent_block_hash: Bytes::from(vec![0; 31]),
+                ..block.clone()
+            },
+            Block {
+                timestamp: Timestamp {
+                    seconds: 8,
+                    nanos: 0,
+                },
+                ..block.clone()
+            },
+            Block {
+                timestamp: Timestamp {
+                    seconds: 7,
+                    nanos: 1,
+                },
+                ..block.clone()
+            },
+        ];
+        for other in changed {
+            assert_ne!(
+                block.commitment(),
+                other.commitment(),
+                "commitment did not change for {other:?}",
+            );
+        }
+    }
+
+    fn make_block_with_hash(number: u32, hash_byte: u8) -> Block {
+        Block {
+            hash: Bytes::from(vec![hash_byte; 32]),
+            ..make_block(number)
+        }
+    }
+
+    #[test]
+    fn soft_is_rewound_to_block_between_firm_and_soft() {
+        let state = make_commitment_state(2, 5, 10);
+        let rewound = state.rewind_soft(make_block_with_hash(3, 9)).unwrap();
+        assert_eq!(&make_block_with_hash(3, 9), rewound.soft());
+        assert_eq!(state.firm(), rewound.firm());
+        assert_eq!(state.base_celestia_height(), rewound.base_celestia_height());
+        assert_eq!(state.epoch(), rewound.epoch());
+    }
+
+    #[test]
+    fn soft_is_rewound_to_firm() {
+        let state = make_commitment_state(2, 5, 10);
+        let rewound = state.rewind_soft(make_block(2)).unwrap();
+        assert_eq!(rewound.firm(), rewound.soft());
+    }
+
+    #[test]
+    fn soft_cannot_be_rewound_below_firm() {
+        let state = make_commitment_state(2, 5, 10);
+        let error = state.rewind_soft(make_block(1)).unwrap_err();
+        assert!(
+            matches!(
+                error.0,
+                RewindErrorKind::BelowFirm {
+                    firm: 2,
+                    to: 1,
+                }
+            ),
+            "expected a below firm error, got {error:?}"
+        );
+    }
+
+    #[test]
+    fn soft_cannot_be_rewound_to_firm_number_with_other_hash() {
+        let state = make_commitment_state(2, 5, 10);
+        let error = state.rewind_soft(make_block_with_hash(2, 9)).unwrap_err();
+        assert!(
+            matches!(
+                error.0,
+                RewindErrorKind::ConflictsWithFirm {
+                    number: 2,
+                    ..
+                }
+            ),
+            "expected a conflicting firm error, got {error:?}"
+        );
+    }
+
+    #[test]
+    fn soft_cannot_be_rewound_forward() {
+        let state = make_commitment_state(2, 5, 10);
+        for number in [5, 6] {
+            let error = state.rewind_soft(make_block(number)).unwrap_err();
+            assert!(
+                matches!(
+                    error.0,
+                    RewindErrorKind::NotARewind {
+                        soft: 5,
+                        ..
+                    }
+                ),
+                "expected a not-a-rewind error, got {error:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn versioned_commitment_state_keeps_its_epoch() {
+        let mut raw = make_commitment_state(1, 2, 10).into_raw();
+        raw.epoch = Some(7);
+        let commitment_state = CommitmentState::try_from_raw(raw).unwrap();
+        assert_eq!(7, commitment_state.epoch());
+        assert_eq!(Some(7), commitment_state.into_raw().epoch);
+    }
+
+    #[test]
+    fn unversioned_commitment_state_defaults_to_current_epoch() {
+        let mut raw = make_commitment_state(1, 2, 10).into_raw();
+        raw.epoch = None;
+        let commitment_state = CommitmentState::try_from_raw(raw).unwrap();
+        assert_eq!(CommitmentState::CURRENT_EPOCH, commitment_state.epoch());
+        assert_eq!(
+            Some(CommitmentState::CURRENT_EPOCH),
+            commitment_state.into_raw().epoch,
+        );
+    }
+}
@@ -172,6 +172,10 @@ pub struct CommitmentState {
     /// The lowest block number of celestia chain to be searched for rollup blocks given current state
     #[prost(uint64, tag = "3")]
     pub base_celestia_height: u64,
+    /// The version of the commitment state semantics. If not set, the current
+    /// version is assumed.
+    #[prost(uint32, optional, tag = "4")]
+    pub epoch: ::core::option::Option<u32>,
 }
 impl ::prost::Name for CommitmentState {
     const NAME: &'static str = "CommitmentState";
@@ -180,6 +184,56 @@ impl ::prost::Name for CommitmentState {
         ::prost::alloc::format!("astria.execution.v1alpha2.{}", Self::NAME)
     }
 }
+/// A delta between two CommitmentStates.
+///
+/// Only the blocks that changed are sent in full, unchanged blocks are
+/// referenced by their hash. This is smaller than a full CommitmentState in the
+/// common case where only the soft block advanced.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CommitmentStateUpdate {
+    #[prost(message, optional, tag = "1")]
+    pub soft: ::core::option::Option<BlockUpdate>,
+    #[prost(message, optional, tag = "2")]
+    pub firm: ::core::option::Option<BlockUpdate>,
+    /// The new base celestia height. Not set if it did not change.
+    #[prost(uint64, optional, tag = "3")]
+    pub base_celestia_height: ::core::option::Option<u64>,
+}
+impl ::prost::Name for CommitmentStateUpdate {
+    const NAME: &'static str = "CommitmentStateUpdate";
+    const PACKAGE: &'static str = "astria.execution.v1alpha2";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.execution.v1alpha2.{}", Self::NAME)
+    }
+}
+/// A block in a CommitmentStateUpdate.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BlockUpdate {
+    #[prost(oneof = "block_update::Value", tags = "1, 2")]
+    pub value: ::core::option::Option<block_update::Value>,
+}
+/// Nested message and enum types in `BlockUpdate`.
+pub mod block_update {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Value {
+        /// The block changed and is sent in full.
+        #[prost(message, tag = "1")]
+        Changed(super::Block),
+        /// The block is unchanged and only referenced by its hash.
+        #[prost(bytes, tag = "2")]
+        UnchangedHash(::prost::bytes::Bytes),
+    }
+}
+impl ::prost::Name for BlockUpdate {
+    const NAME: &'static str = "BlockUpdate";
+    const PACKAGE: &'static str = "astria.execution.v1alpha2";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.execution.v1alpha2.{}", Self::NAME)
+    }
+}
 /// There is only one CommitmentState object, so the request is empty.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -441,6 +441,116 @@ impl<'de> serde::Deserialize<'de> for BlockIdentifier {
         deserializer.deserialize_struct("astria.execution.v1alpha2.BlockIdentifier", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for BlockUpdate {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.value.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.execution.v1alpha2.BlockUpdate", len)?;
+        if let Some(v) = self.value.as_ref() {
+            match v {
+                block_update::Value::Changed(v) => {
+                    struct_ser.serialize_field("changed", v)?;
+                }
+                block_update::Value::UnchangedHash(v) => {
+                    #[allow(clippy::needless_borrow)]
+                    struct_ser.serialize_field("unchanged_hash", pbjson::private::base64::encode(&v).as_str())?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BlockUpdate {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "changed",
+            "unchanged_hash",
+            "unchangedHash",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Changed,
+            UnchangedHash,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "changed" => Ok(GeneratedField::Changed),
+                            "unchangedHash" | "unchanged_hash" => Ok(GeneratedField::UnchangedHash),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BlockUpdate;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.execution.v1alpha2.BlockUpdate")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BlockUpdate, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut value__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Changed => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("changed"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(block_update::Value::Changed)
+;
+                        }
+                        GeneratedField::UnchangedHash => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("unchangedHash"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<::pbjson::private::BytesDeserialize<_>>>()?.map(|x| block_update::Value::UnchangedHash(x.0));
+                        }
+                    }
+                }
+                Ok(BlockUpdate {
+                    value: value__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.execution.v1alpha2.BlockUpdate", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for CommitmentState {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -458,6 +568,9 @@ impl serde::Serialize for CommitmentState {
         if self.base_celestia_height != 0 {
             len += 1;
         }
+        if self.epoch.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("astria.execution.v1alpha2.CommitmentState", len)?;
         if let Some(v) = self.soft.as_ref() {
             struct_ser.serialize_field("soft", v)?;
@@ -469,6 +582,9 @@ impl serde::Serialize for CommitmentState {
             #[allow(clippy::needless_borrow)]
             struct_ser.serialize_field("base_celestia_height", ToString::to_string(&self.base_celestia_height).as_str())?;
         }
+        if let Some(v) = self.epoch.as_ref() {
+            struct_ser.serialize_field("epoch", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -483,6 +599,7 @@ impl<'de> serde::Deserialize<'de> for CommitmentState {
             "firm",
             "base_celestia_height",
             "baseCelestiaHeight",
+            "epoch",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -490,6 +607,7 @@ impl<'de> serde::Deserialize<'de> for CommitmentState {
             Soft,
             Firm,
             BaseCelestiaHeight,
+            Epoch,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -514,6 +632,7 @@ impl<'de> serde::Deserialize<'de> for CommitmentState {
                             "soft" => Ok(GeneratedField::Soft),
                             "firm" => Ok(GeneratedField::Firm),
                             "baseCelestiaHeight" | "base_celestia_height" => Ok(GeneratedField::BaseCelestiaHeight),
+                            "epoch" => Ok(GeneratedField::Epoch),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -536,6 +655,7 @@ impl<'de> serde::Deserialize<'de> for CommitmentState {
                 let mut soft__ = None;
                 let mut firm__ = None;
                 let mut base_celestia_height__ = None;
+                let mut epoch__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Soft => {
@@ -558,18 +678,156 @@ impl<'de> serde::Deserialize<'de> for CommitmentState {
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::Epoch => {
+                            if epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epoch"));
+                            }
+                            epoch__ = 
+                                map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
                     }
                 }
                 Ok(CommitmentState {
                     soft: soft__,
                     firm: firm__,
                     base_celestia_height: base_celestia_height__.unwrap_or_default(),
+                    epoch: epoch__,
                 })
             }
         }
         deserializer.deserialize_struct("astria.execution.v1alpha2.CommitmentState", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for CommitmentStateUpdate {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.soft.is_some() {
+            len += 1;
+        }
+        if self.firm.is_some() {
+            len += 1;
+        }
+        if self.base_celestia_height.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.execution.v1alpha2.CommitmentStateUpdate", len)?;
+        if let Some(v) = self.soft.as_ref() {
+            struct_ser.serialize_field("soft", v)?;
+        }
+        if let Some(v) = self.firm.as_ref() {
+            struct_ser.serialize_field("firm", v)?;
+        }
+        if let Some(v) = self.base_celestia_height.as_ref() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("base_celestia_height", ToString::to_string(&v).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for CommitmentStateUpdate {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "soft",
+            "firm",
+            "base_celestia_height",
+            "baseCelestiaHeight",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Soft,
+            Firm,
+            BaseCelestiaHeight,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "soft" => Ok(GeneratedField::Soft),
+                            "firm" => Ok(GeneratedField::Firm),
+                            "baseCelestiaHeight" | "base_celestia_height" => Ok(GeneratedField::BaseCelestiaHeight),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CommitmentStateUpdate;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.execution.v1alpha2.CommitmentStateUpdate")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CommitmentStateUpdate, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut soft__ = None;
+                let mut firm__ = None;
+                let mut base_celestia_height__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Soft => {
+                            if soft__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("soft"));
+                            }
+                            soft__ = map_.next_value()?;
+                        }
+                        GeneratedField::Firm => {
+                            if firm__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("firm"));
+                            }
+                            firm__ = map_.next_value()?;
+                        }
+                        GeneratedField::BaseCelestiaHeight => {
+                            if base_celestia_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("baseCelestiaHeight"));
+                            }
+                            base_celestia_height__ = 
+                                map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(CommitmentStateUpdate {
+                    soft: soft__,
+                    firm: firm__,
+                    base_celestia_height: base_celestia_height__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.execution.v1alpha2.CommitmentStateUpdate", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for ExecuteBlockRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
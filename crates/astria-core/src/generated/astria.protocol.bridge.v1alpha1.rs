@@ -7,6 +7,11 @@ pub struct BridgeAccountLastTxHashResponse {
     pub height: u64,
     #[prost(bytes = "vec", optional, tag = "3")]
     pub tx_hash: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+    /// The hash of the sequencer block that included the transaction. Not set if
+    /// there is no last transaction, or if it was executed before the heights of
+    /// last transactions were recorded.
+    #[prost(bytes = "vec", optional, tag = "4")]
+    pub block_hash: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
 }
 impl ::prost::Name for BridgeAccountLastTxHashResponse {
     const NAME: &'static str = "BridgeAccountLastTxHashResponse";
@@ -15,3 +20,134 @@ impl ::prost::Name for BridgeAccountLastTxHashResponse {
         ::prost::alloc::format!("astria.protocol.bridge.v1alpha1.{}", Self::NAME)
     }
 }
+/// A response containing the height at which a bridge account was created,
+/// if it is known.
+///
+/// `creation_height` is not set if the address is not a bridge account, or if
+/// the bridge account was created before creation heights were recorded.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BridgeAccountCreationHeightResponse {
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    #[prost(uint64, optional, tag = "2")]
+    pub creation_height: ::core::option::Option<u64>,
+}
+impl ::prost::Name for BridgeAccountCreationHeightResponse {
+    const NAME: &'static str = "BridgeAccountCreationHeightResponse";
+    const PACKAGE: &'static str = "astria.protocol.bridge.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.protocol.bridge.v1alpha1.{}", Self::NAME)
+    }
+}
+/// A response containing the information of a bridge account given some
+/// bridge address, if it exists.
+///
+/// If the address is not a bridge account, only `height` is set.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BridgeAccountInfoResponse {
+    #[prost(uint64, tag = "2")]
+    pub height: u64,
+    #[prost(message, optional, tag = "3")]
+    pub rollup_id: ::core::option::Option<super::super::super::primitive::v1::RollupId>,
+    #[prost(bytes = "vec", optional, tag = "4")]
+    pub asset_id: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+    #[prost(message, optional, tag = "5")]
+    pub sudo_address: ::core::option::Option<
+        super::super::super::primitive::v1::Address,
+    >,
+    #[prost(message, optional, tag = "6")]
+    pub withdrawer_address: ::core::option::Option<
+        super::super::super::primitive::v1::Address,
+    >,
+}
+impl ::prost::Name for BridgeAccountInfoResponse {
+    const NAME: &'static str = "BridgeAccountInfoResponse";
+    const PACKAGE: &'static str = "astria.protocol.bridge.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.protocol.bridge.v1alpha1.{}", Self::NAME)
+    }
+}
+/// A bridge account and its information.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BridgeAccount {
+    #[prost(message, optional, tag = "1")]
+    pub address: ::core::option::Option<super::super::super::primitive::v1::Address>,
+    #[prost(message, optional, tag = "2")]
+    pub rollup_id: ::core::option::Option<super::super::super::primitive::v1::RollupId>,
+    #[prost(bytes = "vec", tag = "3")]
+    pub asset_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag = "4")]
+    pub sudo_address: ::core::option::Option<
+        super::super::super::primitive::v1::Address,
+    >,
+    #[prost(message, optional, tag = "5")]
+    pub withdrawer_address: ::core::option::Option<
+        super::super::super::primitive::v1::Address,
+    >,
+}
+impl ::prost::Name for BridgeAccount {
+    const NAME: &'static str = "BridgeAccount";
+    const PACKAGE: &'static str = "astria.protocol.bridge.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.protocol.bridge.v1alpha1.{}", Self::NAME)
+    }
+}
+/// A response containing one page of all bridge accounts.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BridgeAccountsListResponse {
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    #[prost(message, repeated, tag = "2")]
+    pub accounts: ::prost::alloc::vec::Vec<BridgeAccount>,
+    /// An opaque cursor to send as the data of the next query to fetch the
+    /// following page. Not set on the last page.
+    #[prost(bytes = "vec", optional, tag = "3")]
+    pub next_cursor: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+}
+impl ::prost::Name for BridgeAccountsListResponse {
+    const NAME: &'static str = "BridgeAccountsListResponse";
+    const PACKAGE: &'static str = "astria.protocol.bridge.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.protocol.bridge.v1alpha1.{}", Self::NAME)
+    }
+}
+/// A response containing the cumulative amounts withdrawn from a bridge
+/// account, per asset.
+///
+/// Assets that were never withdrawn from the bridge account are not listed.
+/// Withdrawals executed before the totals were recorded are not included.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BridgeAccountTotalWithdrawnResponse {
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    #[prost(message, repeated, tag = "2")]
+    pub totals: ::prost::alloc::vec::Vec<AssetWithdrawnTotal>,
+}
+impl ::prost::Name for BridgeAccountTotalWithdrawnResponse {
+    const NAME: &'static str = "BridgeAccountTotalWithdrawnResponse";
+    const PACKAGE: &'static str = "astria.protocol.bridge.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.protocol.bridge.v1alpha1.{}", Self::NAME)
+    }
+}
+/// The cumulative amount of a single asset withdrawn from a bridge account.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AssetWithdrawnTotal {
+    #[prost(bytes = "vec", tag = "1")]
+    pub asset_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag = "2")]
+    pub amount: ::core::option::Option<super::super::super::primitive::v1::Uint128>,
+}
+impl ::prost::Name for AssetWithdrawnTotal {
+    const NAME: &'static str = "AssetWithdrawnTotal";
+    const PACKAGE: &'static str = "astria.protocol.bridge.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.protocol.bridge.v1alpha1.{}", Self::NAME)
+    }
+}
@@ -1 +1,73 @@
 pub mod v1;
+
+/// Converts `bytes` to an array of exactly `N` bytes.
+///
+/// # Errors
+/// Returns an error if `bytes` is not exactly `N` bytes long.
+pub fn try_into_fixed<const N: usize>(bytes: &[u8]) -> Result<[u8; N], WrongLength> {
+    <[u8; N]>::try_from(bytes).map_err(|_| WrongLength {
+        expected: N,
+        actual: bytes.len(),
+    })
+}
+
+/// The error returned by [`try_into_fixed`] if the bytes do not have the expected length.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, thiserror::Error)]
+#[error("expected `{expected}` bytes, got `{actual}`")]
+pub struct WrongLength {
+    expected: usize,
+    actual: usize,
+}
+
+impl WrongLength {
+    /// Returns the number of bytes that were expected.
+    #[must_use]
+    pub fn expected(&self) -> usize {
+        self.expected
+    }
+
+    /// Returns the number of bytes that were received.
+    #[must_use]
+    pub fn actual(&self) -> usize {
+        self.actual
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        try_into_fixed,
+        WrongLength,
+    };
+
+    #[test]
+    fn bytes_of_correct_length_are_converted() {
+        assert_eq!([7; 20], try_into_fixed::<20>(&[7; 20]).unwrap());
+        assert_eq!([7; 32], try_into_fixed::<32>(&[7; 32]).unwrap());
+    }
+
+    #[test]
+    fn bytes_of_wrong_length_are_rejected() {
+        assert_eq!(
+            WrongLength {
+                expected: 20,
+                actual: 32,
+            },
+            try_into_fixed::<20>(&[7; 32]).unwrap_err(),
+        );
+        assert_eq!(
+            WrongLength {
+                expected: 32,
+                actual: 31,
+            },
+            try_into_fixed::<32>(&[7; 31]).unwrap_err(),
+        );
+        assert_eq!(
+            WrongLength {
+                expected: 32,
+                actual: 0,
+            },
+            try_into_fixed::<32>(&[]).unwrap_err(),
+        );
+    }
+}
@@ -96,3 +96,17 @@ impl Display for Id {
 pub struct IncorrectAssetIdLength {
     received: usize,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Id;
+
+    #[test]
+    fn id_as_ref_is_its_bytes() {
+        let bytes: [u8; 32] = std::array::from_fn(|i| u8::try_from(i).unwrap());
+        let id = Id::new(bytes);
+        assert_eq!(32, id.as_ref().len());
+        assert_eq!(&bytes[..], id.as_ref());
+        assert_eq!(id.get().as_slice(), id.as_ref());
+    }
+}
@@ -4,6 +4,7 @@ pub mod u128;
 use base64::{
     display::Base64Display,
     prelude::BASE64_STANDARD,
+    Engine as _,
 };
 use bytes::Bytes;
 use sha2::{
@@ -186,6 +187,29 @@ impl RollupId {
         Ok(Self::new(inner))
     }
 
+    /// Converts a standard base64 encoded string to a rollup ID.
+    ///
+    /// This is the inverse of the [`Display`](std::fmt::Display) implementation of
+    /// [`RollupId`] and matches how the protobuf JSON mapping encodes bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// use astria_core::primitive::v1::RollupId;
+    /// let rollup_id = RollupId::new([42u8; 32]);
+    /// let encoded = rollup_id.to_string();
+    /// assert_eq!(rollup_id, RollupId::try_from_base64(&encoded).unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `input` is not valid base64 or does not decode to 32 bytes.
+    pub fn try_from_base64(input: &str) -> Result<Self, RollupIdFromBase64Error> {
+        let bytes = BASE64_STANDARD
+            .decode(input)
+            .map_err(RollupIdFromBase64Error::decode)?;
+        Self::try_from_vec(bytes).map_err(RollupIdFromBase64Error::incorrect_length)
+    }
+
     #[must_use]
     pub fn to_raw(&self) -> raw::RollupId {
         raw::RollupId {
@@ -244,21 +268,104 @@ impl std::fmt::Display for RollupId {
     }
 }
 
+/// Serializes and deserializes a [`RollupId`] as a hex encoded string.
+///
+/// Intended for human-facing serialization where the default base64 encoding of a rollup ID is
+/// hard to compare against other tooling. Use it through serde's field attributes:
+///
+/// ```
+/// use astria_core::primitive::v1::RollupId;
+///
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Report {
+///     #[serde(with = "astria_core::primitive::v1::rollup_id_hex")]
+///     rollup_id: RollupId,
+/// }
+/// ```
+#[cfg(feature = "serde")]
+pub mod rollup_id_hex {
+    use serde::{
+        de::Error as _,
+        Deserialize as _,
+        Deserializer,
+        Serializer,
+    };
+
+    use super::RollupId;
+
+    /// Serializes `rollup_id` as a lower case hex string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying serializer fails.
+    pub fn serialize<S>(rollup_id: &RollupId, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&hex::encode(rollup_id))
+    }
+
+    /// Deserializes a rollup ID from a hex string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is not a hex string or does not decode to 32 bytes.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<RollupId, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let hex_str = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+        let bytes = hex::decode(&*hex_str).map_err(D::Error::custom)?;
+        RollupId::try_from_vec(bytes).map_err(D::Error::custom)
+    }
+}
+
 #[derive(Debug, thiserror::Error)]
 #[error("expected 32 bytes, got {received}")]
 pub struct IncorrectRollupIdLength {
     received: usize,
 }
 
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct RollupIdFromBase64Error(RollupIdFromBase64ErrorKind);
+
+impl RollupIdFromBase64Error {
+    fn decode(source: base64::DecodeError) -> Self {
+        Self(RollupIdFromBase64ErrorKind::Decode {
+            source,
+        })
+    }
+
+    fn incorrect_length(source: IncorrectRollupIdLength) -> Self {
+        Self(RollupIdFromBase64ErrorKind::IncorrectLength {
+            source,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum RollupIdFromBase64ErrorKind {
+    #[error("input is not a valid base64 string")]
+    Decode { source: base64::DecodeError },
+    #[error("base64 string did not decode to a valid rollup ID")]
+    IncorrectLength { source: IncorrectRollupIdLength },
+}
+
 #[derive(Debug, thiserror::Error)]
 #[error(transparent)]
 pub struct AddressError(AddressErrorKind);
 
 impl AddressError {
     fn bech32m_decode(source: bech32::DecodeError) -> Self {
-        Self(AddressErrorKind::Bech32mDecode {
-            source,
-        })
+        match source {
+            bech32::DecodeError::Checksum(source) => Self(AddressErrorKind::Bech32mChecksum {
+                source,
+            }),
+            source => Self(AddressErrorKind::Bech32mDecode {
+                source,
+            }),
+        }
     }
 
     fn invalid_prefix(source: bech32::primitives::hrp::Error) -> Self {
@@ -276,10 +383,21 @@ impl AddressError {
             received,
         })
     }
+
+    fn prefix_mismatch(expected: &str, actual: &str) -> Self {
+        Self(AddressErrorKind::PrefixMismatch {
+            expected: expected.to_string(),
+            actual: actual.to_string(),
+        })
+    }
 }
 
 #[derive(Debug, thiserror::Error, PartialEq)]
 enum AddressErrorKind {
+    #[error("the checksum of the provided bech32m string is invalid")]
+    Bech32mChecksum {
+        source: bech32::primitives::decode::ChecksumError,
+    },
     #[error("failed decoding provided bech32m string")]
     Bech32mDecode { source: bech32::DecodeError },
     #[error("fields `inner` and `bech32m` are mutually exclusive, only one can be set")]
@@ -290,6 +408,8 @@ enum AddressErrorKind {
     InvalidPrefix {
         source: bech32::primitives::hrp::Error,
     },
+    #[error("expected an address with prefix `{expected}`, got `{actual}`")]
+    PrefixMismatch { expected: String, actual: String },
 }
 
 pub struct NoBytes;
@@ -429,6 +549,12 @@ impl Address {
         self.bytes
     }
 
+    /// Returns the bech32 human readable prefix of the address.
+    #[must_use]
+    pub fn prefix(&self) -> &str {
+        self.prefix.as_str()
+    }
+
     /// Convert a string containing a bech32m string to an astria address.
     ///
     /// # Errors
@@ -444,6 +570,26 @@ impl Address {
             .try_build()
     }
 
+    /// Convert a string containing a bech32m string to an astria address, requiring that its
+    /// human readable prefix is `expected_prefix`.
+    ///
+    /// # Errors
+    /// Returns the same errors as [`Address::try_from_bech32m`], and additionally if the prefix
+    /// of the decoded address does not match `expected_prefix`.
+    pub fn try_from_bech32m_with_prefix(
+        input: &str,
+        expected_prefix: &str,
+    ) -> Result<Self, AddressError> {
+        let address = Self::try_from_bech32m(input)?;
+        if address.prefix() != expected_prefix {
+            return Err(AddressError::prefix_mismatch(
+                expected_prefix,
+                address.prefix(),
+            ));
+        }
+        Ok(address)
+    }
+
     /// Convert [`Address`] to a [`raw::Address`].
     // allow: panics are checked to not happen
     #[allow(clippy::missing_panics_doc)]
@@ -552,8 +698,12 @@ mod tests {
         Address,
         AddressError,
         AddressErrorKind,
+        RollupId,
+        RollupIdFromBase64Error,
+        RollupIdFromBase64ErrorKind,
         ADDRESS_LEN,
         ASTRIA_ADDRESS_PREFIX,
+        ROLLUP_ID_LEN,
     };
 
     #[track_caller]
@@ -583,6 +733,135 @@ mod tests {
         assert_wrong_address_bytes(&[42; 100]);
     }
 
+    #[test]
+    fn bech32m_of_incorrect_length_gives_length_error() {
+        let input = bech32::encode_lower::<bech32::Bech32m>(
+            bech32::Hrp::parse(ASTRIA_ADDRESS_PREFIX).unwrap(),
+            &[42; 19],
+        )
+        .unwrap();
+        let error = Address::try_from_bech32m(&input)
+            .expect_err("a bech32m string encoding 19 bytes is not a valid address");
+        assert_eq!(
+            AddressErrorKind::IncorrectAddressLength {
+                received: 19
+            },
+            error.0,
+        );
+    }
+
+    #[test]
+    fn bech32m_with_bad_checksum_gives_checksum_error() {
+        let mut input = Address::builder()
+            .array([42; 20])
+            .prefix(ASTRIA_ADDRESS_PREFIX)
+            .try_build()
+            .unwrap()
+            .to_string();
+        let last = input.pop().unwrap();
+        input.push(if last == 'q' { 'p' } else { 'q' });
+        let error = Address::try_from_bech32m(&input)
+            .expect_err("a bech32m string with a corrupted checksum is not a valid address");
+        assert!(
+            matches!(error.0, AddressErrorKind::Bech32mChecksum { .. }),
+            "expected AddressErrorKind::Bech32mChecksum, got {error:?}",
+        );
+    }
+
+    #[test]
+    fn bech32m_with_unexpected_prefix_gives_prefix_mismatch_error() {
+        let input = Address::builder()
+            .array([42; 20])
+            .prefix("other")
+            .try_build()
+            .unwrap()
+            .to_string();
+        let error = Address::try_from_bech32m_with_prefix(&input, ASTRIA_ADDRESS_PREFIX)
+            .expect_err("an address with prefix `other` must not be accepted for prefix `astria`");
+        assert_eq!(
+            AddressErrorKind::PrefixMismatch {
+                expected: ASTRIA_ADDRESS_PREFIX.to_string(),
+                actual: "other".to_string(),
+            },
+            error.0,
+        );
+    }
+
+    #[test]
+    fn bech32m_with_expected_prefix_is_accepted() {
+        let expected = Address::builder()
+            .array([42; 20])
+            .prefix(ASTRIA_ADDRESS_PREFIX)
+            .try_build()
+            .unwrap();
+        let actual =
+            Address::try_from_bech32m_with_prefix(&expected.to_string(), ASTRIA_ADDRESS_PREFIX)
+                .unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn rollup_id_is_constructed_from_base64() {
+        let expected = RollupId::new([42; 32]);
+        let actual = RollupId::try_from_base64(&expected.to_string()).unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn rollup_id_from_base64_of_wrong_length_gives_error() {
+        let error = RollupId::try_from_base64("KioqKg==")
+            .expect_err("four bytes of base64 are not a valid rollup ID");
+        let RollupIdFromBase64Error(RollupIdFromBase64ErrorKind::IncorrectLength {
+            source,
+        }) = error
+        else {
+            panic!("expected RollupIdFromBase64ErrorKind::IncorrectLength, got {error:?}");
+        };
+        assert_eq!(4, source.received);
+    }
+
+    #[test]
+    fn rollup_id_from_invalid_base64_gives_error() {
+        let error = RollupId::try_from_base64("not base64!")
+            .expect_err("a string with non-base64 characters is not a valid rollup ID");
+        assert!(
+            matches!(
+                error,
+                RollupIdFromBase64Error(RollupIdFromBase64ErrorKind::Decode { .. })
+            ),
+            "expected RollupIdFromBase64ErrorKind::Decode, got {error:?}",
+        );
+    }
+
+    #[test]
+    fn rollup_id_hex_roundtrips_through_json() {
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Wrapper {
+            #[serde(with = "super::rollup_id_hex")]
+            rollup_id: RollupId,
+        }
+
+        let expected = Wrapper {
+            rollup_id: RollupId::new([42; 32]),
+        };
+        let json = serde_json::to_string(&expected).unwrap();
+        assert_eq!(format!(r#"{{"rollup_id":"{}"}}"#, "2a".repeat(32)), json);
+        let actual: Wrapper = serde_json::from_str(&json).unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn rollup_id_hex_rejects_wrong_length() {
+        #[derive(Debug, serde::Deserialize)]
+        struct Wrapper {
+            #[serde(with = "super::rollup_id_hex")]
+            _rollup_id: RollupId,
+        }
+
+        let _ = serde_json::from_str::<Wrapper>(r#"{"_rollup_id":"2a2a"}"#)
+            .expect_err("a two byte hex string is not a valid rollup ID");
+    }
+
     #[test]
     fn snapshots() {
         let address = Address::builder()
@@ -680,4 +959,26 @@ mod tests {
             output.bech32m
         );
     }
+
+    #[test]
+    fn rollup_id_as_ref_is_its_bytes() {
+        let bytes: [u8; ROLLUP_ID_LEN] = std::array::from_fn(|i| u8::try_from(i).unwrap());
+        let rollup_id = RollupId::new(bytes);
+        assert_eq!(ROLLUP_ID_LEN, rollup_id.as_ref().len());
+        assert_eq!(&bytes[..], rollup_id.as_ref());
+        assert_eq!(rollup_id.get().as_slice(), rollup_id.as_ref());
+    }
+
+    #[test]
+    fn address_as_ref_is_its_bytes() {
+        let bytes: [u8; ADDRESS_LEN] = std::array::from_fn(|i| u8::try_from(i).unwrap());
+        let address = Address::builder()
+            .array(bytes)
+            .prefix(ASTRIA_ADDRESS_PREFIX)
+            .try_build()
+            .unwrap();
+        assert_eq!(ADDRESS_LEN, address.as_ref().len());
+        assert_eq!(&bytes[..], address.as_ref());
+        assert_eq!(address.bytes().as_slice(), address.as_ref());
+    }
 }
@@ -20,6 +20,9 @@ impl AbciErrorCode {
     pub const VALUE_NOT_FOUND: Self = Self(8);
     pub const TRANSACTION_EXPIRED: Self = Self(9);
     pub const TRANSACTION_FAILED: Self = Self(10);
+    pub const CHAIN_NOT_READY: Self = Self(11);
+    pub const RESOURCE_EXHAUSTED: Self = Self(12);
+    pub const NODE_NOT_INITIALIZED: Self = Self(13);
 }
 
 impl AbciErrorCode {
@@ -37,6 +40,9 @@ impl AbciErrorCode {
             8 => "the requested value was not found".into(),
             9 => "the transaction expired in the app's mempool".into(),
             10 => "the transaction failed to execute in prepare_proposal()".into(),
+            11 => "the chain has not produced any blocks yet".into(),
+            12 => "the node is handling too many requests; try again later".into(),
+            13 => "the node has not been initialized with a genesis state yet".into(),
             other => format!("unknown non-zero abci error code: {other}").into(),
         }
     }
@@ -67,6 +73,9 @@ impl From<NonZeroU32> for AbciErrorCode {
             8 => Self::VALUE_NOT_FOUND,
             9 => Self::TRANSACTION_EXPIRED,
             10 => Self::TRANSACTION_FAILED,
+            11 => Self::CHAIN_NOT_READY,
+            12 => Self::RESOURCE_EXHAUSTED,
+            13 => Self::NODE_NOT_INITIALIZED,
             other => Self(other),
         }
     }
@@ -1,18 +1,70 @@
+use std::fmt::{
+    self,
+    Debug,
+    Formatter,
+};
+
+use prost::Name as _;
+
 use super::raw;
+use crate::primitive::{
+    try_into_fixed,
+    v1::{
+        asset,
+        Address,
+        AddressError,
+        IncorrectRollupIdLength,
+        RollupId,
+    },
+};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
 pub struct BridgeAccountLastTxHashResponse {
     pub height: u64,
+    /// Accepted as either a hex or a base64 string when deserializing.
+    ///
+    /// The field is named `tx_hash` like in the canonical JSON, with `txHash` accepted as an alias
+    /// for the protobuf JSON mapping. It must be present; it is `null` if there is no last
+    /// transaction.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            alias = "txHash",
+            deserialize_with = "crate::serde::deserialize_optional_hex_or_base64_hash"
+        )
+    )]
     pub tx_hash: Option<[u8; 32]>,
+    /// The hash of the sequencer block that included the transaction with `tx_hash`. `None` if
+    /// there is no last transaction, or if its block is not known.
+    ///
+    /// Accepted as either a hex or a base64 string when deserializing, under `block_hash` or its
+    /// alias `blockHash`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            alias = "blockHash",
+            deserialize_with = "crate::serde::deserialize_optional_hex_or_base64_hash"
+        )
+    )]
+    pub block_hash: Option<[u8; 32]>,
 }
 
 impl BridgeAccountLastTxHashResponse {
+    /// Returns the type URL of the protobuf [`raw::BridgeAccountLastTxHashResponse`], for use in
+    /// a `google.protobuf.Any`.
+    #[must_use]
+    pub fn type_url() -> String {
+        raw::BridgeAccountLastTxHashResponse::type_url()
+    }
+
     /// Converts a native [`BridgeAccountLastTxHashResponse`] to a protobuf
     /// [`raw::BridgeAccountLastTxHashResponse`].
     ///
     /// # Errors
     ///
     /// - if the transaction hash is not 32 bytes
+    /// - if the block hash is not 32 bytes
     pub fn try_from_raw(
         raw: raw::BridgeAccountLastTxHashResponse,
     ) -> Result<Self, BridgeAccountLastTxHashResponseError> {
@@ -20,10 +72,19 @@ impl BridgeAccountLastTxHashResponse {
             height: raw.height,
             tx_hash: raw
                 .tx_hash
-                .map(TryInto::<[u8; 32]>::try_into)
+                .as_deref()
+                .map(try_into_fixed)
                 .transpose()
-                .map_err(|bytes: Vec<u8>| {
-                    BridgeAccountLastTxHashResponseError::invalid_tx_hash(bytes.len())
+                .map_err(|source| {
+                    BridgeAccountLastTxHashResponseError::invalid_tx_hash(source.actual())
+                })?,
+            block_hash: raw
+                .block_hash
+                .as_deref()
+                .map(try_into_fixed)
+                .transpose()
+                .map_err(|source| {
+                    BridgeAccountLastTxHashResponseError::invalid_block_hash(source.actual())
                 })?,
         })
     }
@@ -33,6 +94,7 @@ impl BridgeAccountLastTxHashResponse {
         raw::BridgeAccountLastTxHashResponse {
             height: self.height,
             tx_hash: self.tx_hash.map(Into::into),
+            block_hash: self.block_hash.map(Into::into),
         }
     }
 }
@@ -44,6 +106,7 @@ impl raw::BridgeAccountLastTxHashResponse {
     /// # Errors
     ///
     /// - if the transaction hash is not 32 bytes
+    /// - if the block hash is not 32 bytes
     pub fn try_into_native(
         self,
     ) -> Result<BridgeAccountLastTxHashResponse, BridgeAccountLastTxHashResponseError> {
@@ -69,10 +132,1138 @@ impl BridgeAccountLastTxHashResponseError {
             bytes,
         ))
     }
+
+    #[must_use]
+    pub fn invalid_block_hash(bytes: usize) -> Self {
+        Self(BridgeAccountLastTxHashResponseErrorKind::InvalidBlockHash(
+            bytes,
+        ))
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
 enum BridgeAccountLastTxHashResponseErrorKind {
     #[error("invalid tx hash; must be 32 bytes, got {0} bytes")]
     InvalidTxHash(usize),
+    #[error("invalid block hash; must be 32 bytes, got {0} bytes")]
+    InvalidBlockHash(usize),
+}
+
+/// A response containing the height at which a bridge account was created.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BridgeAccountCreationHeightResponse {
+    pub height: u64,
+    /// The height of the block in which the bridge account was created. `None` if the address is
+    /// not a bridge account, or if the account predates the tracking of creation heights.
+    pub creation_height: Option<u64>,
+}
+
+impl BridgeAccountCreationHeightResponse {
+    /// Returns the type URL of the protobuf [`raw::BridgeAccountCreationHeightResponse`], for use
+    /// in a `google.protobuf.Any`.
+    #[must_use]
+    pub fn type_url() -> String {
+        raw::BridgeAccountCreationHeightResponse::type_url()
+    }
+
+    /// Converts a protobuf [`raw::BridgeAccountCreationHeightResponse`] to a native
+    /// [`BridgeAccountCreationHeightResponse`].
+    #[must_use]
+    pub fn from_raw(raw: raw::BridgeAccountCreationHeightResponse) -> Self {
+        Self {
+            height: raw.height,
+            creation_height: raw.creation_height,
+        }
+    }
+
+    #[must_use]
+    pub fn into_raw(self) -> raw::BridgeAccountCreationHeightResponse {
+        raw::BridgeAccountCreationHeightResponse {
+            height: self.height,
+            creation_height: self.creation_height,
+        }
+    }
+}
+
+impl raw::BridgeAccountCreationHeightResponse {
+    /// Converts a protobuf [`raw::BridgeAccountCreationHeightResponse`] to a native
+    /// [`BridgeAccountCreationHeightResponse`].
+    #[must_use]
+    pub fn into_native(self) -> BridgeAccountCreationHeightResponse {
+        BridgeAccountCreationHeightResponse::from_raw(self)
+    }
+
+    #[must_use]
+    pub fn from_native(
+        native: BridgeAccountCreationHeightResponse,
+    ) -> raw::BridgeAccountCreationHeightResponse {
+        native.into_raw()
+    }
+}
+
+/// Information about a bridge account stored on the sequencer.
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct BridgeAccountInfo {
+    pub rollup_id: RollupId,
+    pub asset_id: asset::Id,
+    pub sudo_address: Address,
+    pub withdrawer_address: Address,
+}
+
+/// Renders the rollup ID, asset ID, and addresses as hex strings instead of byte arrays.
+impl Debug for BridgeAccountInfo {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("BridgeAccountInfo")
+            .field("rollup_id", &hex::encode(self.rollup_id))
+            .field("asset_id", &hex::encode(self.asset_id))
+            .field("sudo_address", &hex::encode(self.sudo_address))
+            .field("withdrawer_address", &hex::encode(self.withdrawer_address))
+            .finish()
+    }
+}
+
+/// A response containing the information of a bridge account, if it exists.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct BridgeAccountInfoResponse {
+    pub height: u64,
+    pub info: Option<BridgeAccountInfo>,
+}
+
+impl BridgeAccountInfoResponse {
+    /// Returns the type URL of the protobuf [`raw::BridgeAccountInfoResponse`], for use in a
+    /// `google.protobuf.Any`.
+    #[must_use]
+    pub fn type_url() -> String {
+        raw::BridgeAccountInfoResponse::type_url()
+    }
+
+    /// Returns the canonical JSON encoding of this response.
+    ///
+    /// Keys are sorted and no whitespace is emitted, so the output is stable and can be hashed or
+    /// signed.
+    #[cfg(feature = "serde")]
+    #[must_use]
+    pub fn to_canonical_json(&self) -> String {
+        crate::serde::to_canonical_json(self)
+    }
+
+    /// Returns the bridge account info, or an error carrying the height at which the
+    /// account was not found.
+    ///
+    /// # Errors
+    ///
+    /// - if the response does not contain bridge account info
+    pub fn info_or_not_found(&self) -> Result<&BridgeAccountInfo, NotFound> {
+        self.info.as_ref().ok_or(NotFound {
+            height: self.height,
+        })
+    }
+
+    /// Converts a protobuf [`raw::BridgeAccountInfoResponse`] to a native
+    /// [`BridgeAccountInfoResponse`].
+    ///
+    /// # Errors
+    ///
+    /// - if some but not all of the bridge account fields are set
+    /// - if the rollup ID is not 32 bytes
+    /// - if the asset ID is not 32 bytes
+    /// - if the sudo or withdrawer addresses are invalid
+    pub fn try_from_raw(
+        raw: raw::BridgeAccountInfoResponse,
+    ) -> Result<Self, BridgeAccountInfoResponseError> {
+        let raw::BridgeAccountInfoResponse {
+            height,
+            rollup_id,
+            asset_id,
+            sudo_address,
+            withdrawer_address,
+        } = raw;
+
+        if rollup_id.is_none()
+            && asset_id.is_none()
+            && sudo_address.is_none()
+            && withdrawer_address.is_none()
+        {
+            return Ok(Self {
+                height,
+                info: None,
+            });
+        }
+
+        let Some(rollup_id) = rollup_id else {
+            return Err(BridgeAccountInfoResponseError::field_not_set("rollup_id"));
+        };
+        let Some(asset_id) = asset_id else {
+            return Err(BridgeAccountInfoResponseError::field_not_set("asset_id"));
+        };
+        let Some(sudo_address) = sudo_address else {
+            return Err(BridgeAccountInfoResponseError::field_not_set(
+                "sudo_address",
+            ));
+        };
+        let Some(withdrawer_address) = withdrawer_address else {
+            return Err(BridgeAccountInfoResponseError::field_not_set(
+                "withdrawer_address",
+            ));
+        };
+
+        Ok(Self {
+            height,
+            info: Some(bridge_account_info_from_raw_parts(
+                &rollup_id,
+                &asset_id,
+                &sudo_address,
+                &withdrawer_address,
+            )?),
+        })
+    }
+
+    #[must_use]
+    pub fn into_raw(self) -> raw::BridgeAccountInfoResponse {
+        let Some(info) = self.info else {
+            return raw::BridgeAccountInfoResponse {
+                height: self.height,
+                rollup_id: None,
+                asset_id: None,
+                sudo_address: None,
+                withdrawer_address: None,
+            };
+        };
+        raw::BridgeAccountInfoResponse {
+            height: self.height,
+            rollup_id: Some(info.rollup_id.into_raw()),
+            asset_id: Some(info.asset_id.as_ref().to_vec()),
+            sudo_address: Some(info.sudo_address.into_raw()),
+            withdrawer_address: Some(info.withdrawer_address.into_raw()),
+        }
+    }
+}
+
+impl raw::BridgeAccountInfoResponse {
+    /// Converts a protobuf [`raw::BridgeAccountInfoResponse`] to a native
+    /// [`BridgeAccountInfoResponse`].
+    ///
+    /// # Errors
+    ///
+    /// - if the raw response could not be converted, see
+    ///   [`BridgeAccountInfoResponse::try_from_raw`]
+    pub fn try_into_native(
+        self,
+    ) -> Result<BridgeAccountInfoResponse, BridgeAccountInfoResponseError> {
+        BridgeAccountInfoResponse::try_from_raw(self)
+    }
+
+    /// Converts a protobuf [`raw::BridgeAccountInfoResponse`] to a native
+    /// [`BridgeAccountInfoResponse`], checking that the bridge account is on the `expected`
+    /// rollup.
+    ///
+    /// A response without bridge account info is returned as is, since it carries no rollup ID
+    /// to check.
+    ///
+    /// # Errors
+    ///
+    /// - if the raw response could not be converted, see
+    ///   [`BridgeAccountInfoResponse::try_from_raw`]
+    /// - if the rollup ID of the bridge account is not `expected`
+    pub fn try_into_native_expecting(
+        self,
+        expected: &RollupId,
+    ) -> Result<BridgeAccountInfoResponse, BridgeAccountInfoResponseError> {
+        let native = BridgeAccountInfoResponse::try_from_raw(self)?;
+        if let Some(info) = &native.info {
+            if info.rollup_id != *expected {
+                return Err(BridgeAccountInfoResponseError::rollup_id_mismatch(
+                    *expected,
+                    info.rollup_id,
+                ));
+            }
+        }
+        Ok(native)
+    }
+
+    #[must_use]
+    pub fn from_native(native: BridgeAccountInfoResponse) -> raw::BridgeAccountInfoResponse {
+        native.into_raw()
+    }
+}
+
+fn bridge_account_info_from_raw_parts(
+    rollup_id: &crate::generated::primitive::v1::RollupId,
+    asset_id: &[u8],
+    sudo_address: &crate::generated::primitive::v1::Address,
+    withdrawer_address: &crate::generated::primitive::v1::Address,
+) -> Result<BridgeAccountInfo, BridgeAccountInfoResponseError> {
+    Ok(BridgeAccountInfo {
+        rollup_id: RollupId::try_from_raw(rollup_id)
+            .map_err(BridgeAccountInfoResponseError::invalid_rollup_id)?,
+        asset_id: asset::Id::try_from_slice(asset_id)
+            .map_err(|_| BridgeAccountInfoResponseError::invalid_asset_id(asset_id.len()))?,
+        sudo_address: Address::try_from_raw(sudo_address)
+            .map_err(BridgeAccountInfoResponseError::invalid_sudo_address)?,
+        withdrawer_address: Address::try_from_raw(withdrawer_address)
+            .map_err(BridgeAccountInfoResponseError::invalid_withdrawer_address)?,
+    })
+}
+
+/// A page of all bridge accounts, as returned by the bridge accounts list query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BridgeAccountsListResponse {
+    pub height: u64,
+    pub accounts: Vec<(Address, BridgeAccountInfo)>,
+    /// The opaque cursor to request the next page with. `None` if this is the last page.
+    pub next_cursor: Option<Vec<u8>>,
+}
+
+impl BridgeAccountsListResponse {
+    /// Returns the type URL of the protobuf [`raw::BridgeAccountsListResponse`], for use in a
+    /// `google.protobuf.Any`.
+    #[must_use]
+    pub fn type_url() -> String {
+        raw::BridgeAccountsListResponse::type_url()
+    }
+
+    /// Converts a protobuf [`raw::BridgeAccountsListResponse`] to a native
+    /// [`BridgeAccountsListResponse`].
+    ///
+    /// # Errors
+    ///
+    /// - if an account is missing its address, rollup ID, sudo address, or withdrawer address
+    /// - if an account's address is invalid
+    /// - if an account's information is invalid, see [`BridgeAccountInfoResponse::try_from_raw`]
+    pub fn try_from_raw(
+        raw: raw::BridgeAccountsListResponse,
+    ) -> Result<Self, BridgeAccountsListResponseError> {
+        let raw::BridgeAccountsListResponse {
+            height,
+            accounts,
+            next_cursor,
+        } = raw;
+        let accounts = accounts
+            .into_iter()
+            .map(bridge_account_from_raw)
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            height,
+            accounts,
+            next_cursor,
+        })
+    }
+
+    #[must_use]
+    pub fn into_raw(self) -> raw::BridgeAccountsListResponse {
+        raw::BridgeAccountsListResponse {
+            height: self.height,
+            accounts: self
+                .accounts
+                .into_iter()
+                .map(|(address, info)| raw::BridgeAccount {
+                    address: Some(address.into_raw()),
+                    rollup_id: Some(info.rollup_id.into_raw()),
+                    asset_id: info.asset_id.as_ref().to_vec(),
+                    sudo_address: Some(info.sudo_address.into_raw()),
+                    withdrawer_address: Some(info.withdrawer_address.into_raw()),
+                })
+                .collect(),
+            next_cursor: self.next_cursor,
+        }
+    }
+}
+
+impl raw::BridgeAccountsListResponse {
+    /// Converts a protobuf [`raw::BridgeAccountsListResponse`] to a native
+    /// [`BridgeAccountsListResponse`].
+    ///
+    /// # Errors
+    ///
+    /// - if the raw response could not be converted, see
+    ///   [`BridgeAccountsListResponse::try_from_raw`]
+    pub fn try_into_native(
+        self,
+    ) -> Result<BridgeAccountsListResponse, BridgeAccountsListResponseError> {
+        BridgeAccountsListResponse::try_from_raw(self)
+    }
+
+    #[must_use]
+    pub fn from_native(native: BridgeAccountsListResponse) -> raw::BridgeAccountsListResponse {
+        native.into_raw()
+    }
+}
+
+fn bridge_account_from_raw(
+    raw: raw::BridgeAccount,
+) -> Result<(Address, BridgeAccountInfo), BridgeAccountsListResponseError> {
+    let raw::BridgeAccount {
+        address,
+        rollup_id,
+        asset_id,
+        sudo_address,
+        withdrawer_address,
+    } = raw;
+    let Some(address) = address else {
+        return Err(BridgeAccountsListResponseError::field_not_set("address"));
+    };
+    let Some(rollup_id) = rollup_id else {
+        return Err(BridgeAccountsListResponseError::field_not_set("rollup_id"));
+    };
+    let Some(sudo_address) = sudo_address else {
+        return Err(BridgeAccountsListResponseError::field_not_set(
+            "sudo_address",
+        ));
+    };
+    let Some(withdrawer_address) = withdrawer_address else {
+        return Err(BridgeAccountsListResponseError::field_not_set(
+            "withdrawer_address",
+        ));
+    };
+    let address = Address::try_from_raw(&address)
+        .map_err(BridgeAccountsListResponseError::invalid_address)?;
+    let info = bridge_account_info_from_raw_parts(
+        &rollup_id,
+        &asset_id,
+        &sudo_address,
+        &withdrawer_address,
+    )
+    .map_err(|source| BridgeAccountsListResponseError::invalid_account(address, source))?;
+    Ok((address, info))
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct BridgeAccountsListResponseError(BridgeAccountsListResponseErrorKind);
+
+impl BridgeAccountsListResponseError {
+    fn field_not_set(field: &'static str) -> Self {
+        Self(BridgeAccountsListResponseErrorKind::FieldNotSet(field))
+    }
+
+    fn invalid_address(source: AddressError) -> Self {
+        Self(BridgeAccountsListResponseErrorKind::InvalidAddress(source))
+    }
+
+    fn invalid_account(address: Address, source: BridgeAccountInfoResponseError) -> Self {
+        Self(BridgeAccountsListResponseErrorKind::InvalidAccount {
+            address,
+            source,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum BridgeAccountsListResponseErrorKind {
+    #[error("the expected field in the raw bridge account was not set: `{0}`")]
+    FieldNotSet(&'static str),
+    #[error("the `address` field of a bridge account was invalid")]
+    InvalidAddress(#[source] AddressError),
+    #[error("the information of bridge account `{address}` was invalid")]
+    InvalidAccount {
+        address: Address,
+        source: BridgeAccountInfoResponseError,
+    },
+}
+
+/// The cumulative amounts withdrawn from a bridge account per asset, as returned by the bridge
+/// account total withdrawn query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BridgeAccountTotalWithdrawnResponse {
+    pub height: u64,
+    /// The total withdrawn of every asset that was withdrawn from the bridge account, ordered by
+    /// asset ID.
+    pub totals: Vec<(asset::Id, u128)>,
+}
+
+impl BridgeAccountTotalWithdrawnResponse {
+    /// Returns the type URL of the protobuf [`raw::BridgeAccountTotalWithdrawnResponse`], for use
+    /// in a `google.protobuf.Any`.
+    #[must_use]
+    pub fn type_url() -> String {
+        raw::BridgeAccountTotalWithdrawnResponse::type_url()
+    }
+
+    /// Returns the total amount of `asset_id` withdrawn from the bridge account.
+    ///
+    /// Returns 0 if the asset was never withdrawn.
+    #[must_use]
+    pub fn total_withdrawn(&self, asset_id: &asset::Id) -> u128 {
+        self.totals
+            .iter()
+            .find_map(|(id, amount)| (id == asset_id).then_some(*amount))
+            .unwrap_or_default()
+    }
+
+    /// Converts a protobuf [`raw::BridgeAccountTotalWithdrawnResponse`] to a native
+    /// [`BridgeAccountTotalWithdrawnResponse`].
+    ///
+    /// # Errors
+    ///
+    /// - if the asset ID of a total is not 32 bytes
+    pub fn try_from_raw(
+        raw: raw::BridgeAccountTotalWithdrawnResponse,
+    ) -> Result<Self, BridgeAccountTotalWithdrawnResponseError> {
+        let raw::BridgeAccountTotalWithdrawnResponse {
+            height,
+            totals,
+        } = raw;
+        let totals = totals
+            .into_iter()
+            .map(asset_withdrawn_total_from_raw)
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            height,
+            totals,
+        })
+    }
+
+    #[must_use]
+    pub fn into_raw(self) -> raw::BridgeAccountTotalWithdrawnResponse {
+        raw::BridgeAccountTotalWithdrawnResponse {
+            height: self.height,
+            totals: self
+                .totals
+                .into_iter()
+                .map(|(asset_id, amount)| raw::AssetWithdrawnTotal {
+                    asset_id: asset_id.as_ref().to_vec(),
+                    amount: Some(amount.into()),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl raw::BridgeAccountTotalWithdrawnResponse {
+    /// Converts a protobuf [`raw::BridgeAccountTotalWithdrawnResponse`] to a native
+    /// [`BridgeAccountTotalWithdrawnResponse`].
+    ///
+    /// # Errors
+    ///
+    /// - if the raw response could not be converted, see
+    ///   [`BridgeAccountTotalWithdrawnResponse::try_from_raw`]
+    pub fn try_into_native(
+        self,
+    ) -> Result<BridgeAccountTotalWithdrawnResponse, BridgeAccountTotalWithdrawnResponseError> {
+        BridgeAccountTotalWithdrawnResponse::try_from_raw(self)
+    }
+
+    #[must_use]
+    pub fn from_native(
+        native: BridgeAccountTotalWithdrawnResponse,
+    ) -> raw::BridgeAccountTotalWithdrawnResponse {
+        native.into_raw()
+    }
+}
+
+fn asset_withdrawn_total_from_raw(
+    raw: raw::AssetWithdrawnTotal,
+) -> Result<(asset::Id, u128), BridgeAccountTotalWithdrawnResponseError> {
+    let raw::AssetWithdrawnTotal {
+        asset_id,
+        amount,
+    } = raw;
+    let asset_id = asset::Id::try_from_slice(&asset_id)
+        .map_err(|_| BridgeAccountTotalWithdrawnResponseError::invalid_asset_id(asset_id.len()))?;
+    Ok((asset_id, amount.map_or(0, Into::into)))
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct BridgeAccountTotalWithdrawnResponseError(BridgeAccountTotalWithdrawnResponseErrorKind);
+
+impl BridgeAccountTotalWithdrawnResponseError {
+    fn invalid_asset_id(bytes: usize) -> Self {
+        Self(BridgeAccountTotalWithdrawnResponseErrorKind::InvalidAssetId(bytes))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum BridgeAccountTotalWithdrawnResponseErrorKind {
+    #[error("invalid asset id in totals; must be 32 bytes, got {0} bytes")]
+    InvalidAssetId(usize),
+}
+
+/// The error returned by [`BridgeAccountInfoResponse::info_or_not_found`] if the queried
+/// address is not a bridge account.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error("bridge account not found at height {height}")]
+pub struct NotFound {
+    height: u64,
+}
+
+impl NotFound {
+    /// Returns the height at which the bridge account was not found.
+    #[must_use]
+    pub fn height(&self) -> u64 {
+        self.height
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct BridgeAccountInfoResponseError(BridgeAccountInfoResponseErrorKind);
+
+impl BridgeAccountInfoResponseError {
+    fn field_not_set(field: &'static str) -> Self {
+        Self(BridgeAccountInfoResponseErrorKind::FieldNotSet(field))
+    }
+
+    fn invalid_rollup_id(source: IncorrectRollupIdLength) -> Self {
+        Self(BridgeAccountInfoResponseErrorKind::InvalidRollupId(source))
+    }
+
+    fn invalid_asset_id(bytes: usize) -> Self {
+        Self(BridgeAccountInfoResponseErrorKind::InvalidAssetId(bytes))
+    }
+
+    fn invalid_sudo_address(source: AddressError) -> Self {
+        Self(BridgeAccountInfoResponseErrorKind::InvalidSudoAddress(
+            source,
+        ))
+    }
+
+    fn invalid_withdrawer_address(source: AddressError) -> Self {
+        Self(BridgeAccountInfoResponseErrorKind::InvalidWithdrawerAddress(source))
+    }
+
+    fn rollup_id_mismatch(expected: RollupId, actual: RollupId) -> Self {
+        Self(BridgeAccountInfoResponseErrorKind::RollupIdMismatch {
+            expected,
+            actual,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum BridgeAccountInfoResponseErrorKind {
+    #[error("the expected field in the raw source type was not set: `{0}`")]
+    FieldNotSet(&'static str),
+    #[error("the `rollup_id` field was invalid")]
+    InvalidRollupId(#[source] IncorrectRollupIdLength),
+    #[error("invalid asset id; must be 32 bytes, got {0} bytes")]
+    InvalidAssetId(usize),
+    #[error("the `sudo_address` field was invalid")]
+    InvalidSudoAddress(#[source] AddressError),
+    #[error("the `withdrawer_address` field was invalid")]
+    InvalidWithdrawerAddress(#[source] AddressError),
+    #[error("expected bridge account on rollup `{expected}`, but it is on rollup `{actual}`")]
+    RollupIdMismatch {
+        expected: RollupId,
+        actual: RollupId,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use prost::Name as _;
+
+    use super::{
+        raw,
+        BridgeAccountCreationHeightResponse,
+        BridgeAccountInfo,
+        BridgeAccountInfoResponse,
+        BridgeAccountInfoResponseError,
+        BridgeAccountInfoResponseErrorKind,
+        BridgeAccountLastTxHashResponse,
+        BridgeAccountLastTxHashResponseErrorKind,
+        BridgeAccountTotalWithdrawnResponse,
+        BridgeAccountTotalWithdrawnResponseErrorKind,
+        BridgeAccountsListResponse,
+    };
+    use crate::primitive::v1::{
+        asset,
+        Address,
+        AddressError,
+        IncorrectRollupIdLength,
+        RollupId,
+        ASTRIA_ADDRESS_PREFIX,
+    };
+
+    fn address(byte: u8) -> Address {
+        Address::builder()
+            .array([byte; 20])
+            .prefix(ASTRIA_ADDRESS_PREFIX)
+            .try_build()
+            .unwrap()
+    }
+
+    fn bridge_account_info() -> BridgeAccountInfo {
+        BridgeAccountInfo {
+            rollup_id: RollupId::new([1; 32]),
+            asset_id: asset::Id::from_str_unchecked("nria"),
+            sudo_address: address(2),
+            withdrawer_address: address(3),
+        }
+    }
+
+    #[test]
+    fn info_or_not_found_returns_info_if_found() {
+        let response = BridgeAccountInfoResponse {
+            height: 42,
+            info: Some(bridge_account_info()),
+        };
+        assert_eq!(
+            &bridge_account_info(),
+            response.info_or_not_found().unwrap()
+        );
+    }
+
+    #[test]
+    fn info_or_not_found_returns_height_if_not_found() {
+        let response = BridgeAccountInfoResponse {
+            height: 42,
+            info: None,
+        };
+        let error = response.info_or_not_found().unwrap_err();
+        assert_eq!(42, error.height());
+    }
+
+    #[test]
+    fn found_response_roundtrips_through_raw() {
+        let expected = BridgeAccountInfoResponse {
+            height: 42,
+            info: Some(bridge_account_info()),
+        };
+        let actual = BridgeAccountInfoResponse::try_from_raw(expected.clone().into_raw()).unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn not_found_response_roundtrips_through_raw() {
+        let expected = BridgeAccountInfoResponse {
+            height: 42,
+            info: None,
+        };
+        let actual = BridgeAccountInfoResponse::try_from_raw(expected.clone().into_raw()).unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn partially_set_raw_response_is_rejected() {
+        let mut raw = BridgeAccountInfoResponse {
+            height: 42,
+            info: Some(bridge_account_info()),
+        }
+        .into_raw();
+        raw.withdrawer_address = None;
+        let _ = BridgeAccountInfoResponse::try_from_raw(raw)
+            .expect_err("a response with only some fields set must be rejected");
+    }
+
+    #[test]
+    fn debug_output_renders_ids_and_addresses_as_hex() {
+        let info = bridge_account_info();
+        let debug = format!("{info:?}");
+        for expected in [
+            hex::encode(info.rollup_id),
+            hex::encode(info.asset_id),
+            hex::encode(address(2)),
+            hex::encode(address(3)),
+        ] {
+            assert!(
+                debug.contains(&expected),
+                "debug output `{debug}` does not contain `{expected}`"
+            );
+        }
+        for unexpected in [
+            format!("{:?}", info.rollup_id.get()),
+            format!("{:?}", address(2).bytes()),
+        ] {
+            assert!(
+                !debug.contains(&unexpected),
+                "debug output `{debug}` contains byte array `{unexpected}`"
+            );
+        }
+    }
+
+    #[test]
+    fn accounts_list_response_roundtrips_through_raw() {
+        let expected = BridgeAccountsListResponse {
+            height: 42,
+            accounts: vec![
+                (address(10), bridge_account_info()),
+                (
+                    address(11),
+                    BridgeAccountInfo {
+                        withdrawer_address: address(4),
+                        ..bridge_account_info()
+                    },
+                ),
+            ],
+            next_cursor: Some(b"cursor".to_vec()),
+        };
+        let actual = BridgeAccountsListResponse::try_from_raw(expected.clone().into_raw()).unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn type_urls_match_generated_full_names() {
+        assert_eq!(
+            "/astria.protocol.bridge.v1alpha1.BridgeAccountInfoResponse",
+            BridgeAccountInfoResponse::type_url(),
+        );
+        assert_eq!(
+            format!("/{}", raw::BridgeAccountInfoResponse::full_name()),
+            BridgeAccountInfoResponse::type_url(),
+        );
+        assert_eq!(
+            format!("/{}", raw::BridgeAccountLastTxHashResponse::full_name()),
+            BridgeAccountLastTxHashResponse::type_url(),
+        );
+        assert_eq!(
+            format!("/{}", raw::BridgeAccountsListResponse::full_name()),
+            BridgeAccountsListResponse::type_url(),
+        );
+        assert_eq!(
+            format!("/{}", raw::BridgeAccountCreationHeightResponse::full_name()),
+            BridgeAccountCreationHeightResponse::type_url(),
+        );
+    }
+
+    fn raw_response() -> raw::BridgeAccountInfoResponse {
+        BridgeAccountInfoResponse {
+            height: 42,
+            info: Some(bridge_account_info()),
+        }
+        .into_raw()
+    }
+
+    fn invalidate(address: &mut Option<crate::generated::primitive::v1::Address>) {
+        address.as_mut().unwrap().bech32m = "not-a-bech32m-address".to_string();
+    }
+
+    #[track_caller]
+    fn assert_source_is<T: Error + 'static>(error: &dyn Error) {
+        let source = error.source().expect("error must have a source");
+        assert!(
+            source.is::<T>(),
+            "expected source of type `{}`, got `{source:?}`",
+            std::any::type_name::<T>(),
+        );
+    }
+
+    #[test]
+    fn invalid_rollup_id_error_exposes_source() {
+        let mut raw = raw_response();
+        raw.rollup_id.as_mut().unwrap().inner = vec![1; 31].into();
+        let error = BridgeAccountInfoResponse::try_from_raw(raw).unwrap_err();
+        assert_source_is::<IncorrectRollupIdLength>(&error);
+    }
+
+    #[test]
+    fn invalid_sudo_address_error_exposes_source() {
+        let mut raw = raw_response();
+        invalidate(&mut raw.sudo_address);
+        let error = BridgeAccountInfoResponse::try_from_raw(raw).unwrap_err();
+        assert_source_is::<AddressError>(&error);
+    }
+
+    #[test]
+    fn invalid_withdrawer_address_error_exposes_source() {
+        let mut raw = raw_response();
+        invalidate(&mut raw.withdrawer_address);
+        let error = BridgeAccountInfoResponse::try_from_raw(raw).unwrap_err();
+        assert_source_is::<AddressError>(&error);
+    }
+
+    #[test]
+    fn invalid_asset_id_error_has_no_source() {
+        let mut raw = raw_response();
+        raw.asset_id = Some(vec![1; 31]);
+        let error = BridgeAccountInfoResponse::try_from_raw(raw).unwrap_err();
+        assert!(error.source().is_none());
+    }
+
+    #[test]
+    fn conversion_expecting_matching_rollup_id_succeeds() {
+        let expected = BridgeAccountInfoResponse::try_from_raw(raw_response()).unwrap();
+        let actual = raw_response()
+            .try_into_native_expecting(&RollupId::new([1; 32]))
+            .unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn conversion_expecting_other_rollup_id_fails() {
+        let error = raw_response()
+            .try_into_native_expecting(&RollupId::new([2; 32]))
+            .unwrap_err();
+        let BridgeAccountInfoResponseErrorKind::RollupIdMismatch {
+            expected,
+            actual,
+        } = error.0
+        else {
+            panic!("expected a rollup ID mismatch, got {error:?}");
+        };
+        assert_eq!(RollupId::new([2; 32]), expected);
+        assert_eq!(RollupId::new([1; 32]), actual);
+    }
+
+    #[test]
+    fn conversion_expecting_rollup_id_accepts_response_without_info() {
+        let raw = BridgeAccountInfoResponse {
+            height: 42,
+            info: None,
+        }
+        .into_raw();
+        let response = raw
+            .try_into_native_expecting(&RollupId::new([2; 32]))
+            .unwrap();
+        assert_eq!(None, response.info);
+    }
+
+    #[test]
+    fn invalid_account_in_list_exposes_full_source_chain() {
+        let mut raw = BridgeAccountsListResponse {
+            height: 42,
+            accounts: vec![(address(1), bridge_account_info())],
+            next_cursor: None,
+        }
+        .into_raw();
+        invalidate(&mut raw.accounts[0].sudo_address);
+        let error = BridgeAccountsListResponse::try_from_raw(raw).unwrap_err();
+        assert_source_is::<BridgeAccountInfoResponseError>(&error);
+        assert_source_is::<AddressError>(error.source().unwrap());
+    }
+
+    #[test]
+    fn invalid_address_in_list_exposes_source() {
+        let mut raw = BridgeAccountsListResponse {
+            height: 42,
+            accounts: vec![(address(1), bridge_account_info())],
+            next_cursor: None,
+        }
+        .into_raw();
+        invalidate(&mut raw.accounts[0].address);
+        let error = BridgeAccountsListResponse::try_from_raw(raw).unwrap_err();
+        assert_source_is::<AddressError>(&error);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn bridge_account_info_response_canonical_json_has_sorted_keys() {
+        let response = BridgeAccountInfoResponse {
+            height: 42,
+            info: Some(bridge_account_info()),
+        };
+        let json = response.to_canonical_json();
+        assert_eq!(json, response.clone().to_canonical_json());
+        assert!(!json.contains(char::is_whitespace));
+
+        let keys = [
+            "\"asset_id\"",
+            "\"rollup_id\"",
+            "\"sudo_address\"",
+            "\"withdrawer_address\"",
+        ];
+        let positions: Vec<_> = keys
+            .iter()
+            .map(|key| json.find(key).expect("all keys must be present"))
+            .collect();
+        assert!(
+            positions.windows(2).all(|pair| pair[0] < pair[1]),
+            "keys must be sorted, but got: {json}",
+        );
+        assert!(json.starts_with(r#"{"height":42,"info":{"#));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn last_tx_hash_response_deserializes_hex_tx_hash() {
+        let json = format!(
+            r#"{{"block_hash":null,"height":42,"tx_hash":"{}"}}"#,
+            hex::encode([7; 32])
+        );
+        let response: BridgeAccountLastTxHashResponse = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            BridgeAccountLastTxHashResponse {
+                height: 42,
+                tx_hash: Some([7; 32]),
+                block_hash: None,
+            },
+            response,
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn last_tx_hash_response_deserializes_base64_tx_hash() {
+        use base64::Engine as _;
+
+        let json = format!(
+            r#"{{"block_hash":null,"height":42,"tx_hash":"{}"}}"#,
+            base64::engine::general_purpose::STANDARD.encode([7; 32])
+        );
+        let response: BridgeAccountLastTxHashResponse = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            BridgeAccountLastTxHashResponse {
+                height: 42,
+                tx_hash: Some([7; 32]),
+                block_hash: None,
+            },
+            response,
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn last_tx_hash_response_with_null_tx_hash_deserializes_to_none() {
+        let response: BridgeAccountLastTxHashResponse =
+            serde_json::from_str(r#"{"block_hash":null,"height":42,"tx_hash":null}"#).unwrap();
+        assert_eq!(None, response.tx_hash);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn last_tx_hash_response_accepts_camel_case_aliases() {
+        let json = format!(
+            r#"{{"blockHash":"{}","height":42,"txHash":"{}"}}"#,
+            hex::encode([8; 32]),
+            hex::encode([7; 32])
+        );
+        let response: BridgeAccountLastTxHashResponse = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            BridgeAccountLastTxHashResponse {
+                height: 42,
+                tx_hash: Some([7; 32]),
+                block_hash: Some([8; 32]),
+            },
+            response,
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn last_tx_hash_response_with_misnamed_tx_hash_is_rejected() {
+        let json = format!(
+            r#"{{"block_hash":null,"height":42,"txhash":"{}"}}"#,
+            hex::encode([7; 32])
+        );
+        let error = serde_json::from_str::<BridgeAccountLastTxHashResponse>(&json).unwrap_err();
+        assert!(
+            error.to_string().contains("missing field `tx_hash`"),
+            "unexpected error: {error}",
+        );
+    }
+
+    #[test]
+    fn last_tx_hash_response_with_block_hash_roundtrips_through_raw() {
+        let response = BridgeAccountLastTxHashResponse {
+            height: 42,
+            tx_hash: Some([7; 32]),
+            block_hash: Some([8; 32]),
+        };
+        assert_eq!(
+            response,
+            BridgeAccountLastTxHashResponse::try_from_raw(response.clone().into_raw()).unwrap(),
+        );
+    }
+
+    #[test]
+    fn last_tx_hash_response_without_block_hash_roundtrips_through_raw() {
+        let raw = raw::BridgeAccountLastTxHashResponse {
+            height: 42,
+            tx_hash: Some(vec![7; 32]),
+            block_hash: None,
+        };
+        let response = BridgeAccountLastTxHashResponse::try_from_raw(raw.clone()).unwrap();
+        assert_eq!(None, response.block_hash);
+        assert_eq!(raw, response.into_raw());
+    }
+
+    #[test]
+    fn last_tx_hash_response_with_wrong_length_block_hash_is_rejected() {
+        let raw = raw::BridgeAccountLastTxHashResponse {
+            height: 42,
+            tx_hash: Some(vec![7; 32]),
+            block_hash: Some(vec![8; 31]),
+        };
+        let error = BridgeAccountLastTxHashResponse::try_from_raw(raw).unwrap_err();
+        assert!(
+            error.to_string().contains("block hash"),
+            "unexpected error: {error}",
+        );
+    }
+
+    #[test]
+    fn last_tx_hash_response_with_wrong_length_raw_tx_hash_reports_lengths() {
+        let raw = raw::BridgeAccountLastTxHashResponse {
+            height: 42,
+            tx_hash: Some(vec![7; 33]),
+            block_hash: None,
+        };
+        let error = BridgeAccountLastTxHashResponse::try_from_raw(raw).unwrap_err();
+        assert!(
+            matches!(
+                error.0,
+                BridgeAccountLastTxHashResponseErrorKind::InvalidTxHash(33)
+            ),
+            "expected an invalid tx hash error, got {error:?}",
+        );
+        assert_eq!(
+            "invalid tx hash; must be 32 bytes, got 33 bytes",
+            error.to_string()
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn last_tx_hash_response_with_wrong_length_tx_hash_is_rejected() {
+        let json = format!(r#"{{"height":42,"txHash":"{}"}}"#, hex::encode([7; 31]));
+        let error = serde_json::from_str::<BridgeAccountLastTxHashResponse>(&json).unwrap_err();
+        assert!(
+            error.to_string().contains("got 31 bytes"),
+            "unexpected error: {error}",
+        );
+    }
+
+    #[test]
+    fn total_withdrawn_response_roundtrips_through_raw() {
+        let expected = BridgeAccountTotalWithdrawnResponse {
+            height: 42,
+            totals: vec![
+                (asset::Id::from_str_unchecked("nria"), 100),
+                (asset::Id::from_str_unchecked("utia"), u128::MAX),
+            ],
+        };
+        let actual =
+            BridgeAccountTotalWithdrawnResponse::try_from_raw(expected.clone().into_raw()).unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn total_withdrawn_of_never_withdrawn_asset_is_zero() {
+        let response = BridgeAccountTotalWithdrawnResponse {
+            height: 42,
+            totals: vec![(asset::Id::from_str_unchecked("nria"), 100)],
+        };
+        assert_eq!(
+            100,
+            response.total_withdrawn(&asset::Id::from_str_unchecked("nria"))
+        );
+        assert_eq!(
+            0,
+            response.total_withdrawn(&asset::Id::from_str_unchecked("utia"))
+        );
+    }
+
+    #[test]
+    fn total_withdrawn_response_with_invalid_asset_id_is_rejected() {
+        let raw = raw::BridgeAccountTotalWithdrawnResponse {
+            height: 42,
+            totals: vec![raw::AssetWithdrawnTotal {
+                asset_id: vec![1; 31],
+                amount: Some(100u128.into()),
+            }],
+        };
+        let error = BridgeAccountTotalWithdrawnResponse::try_from_raw(raw).unwrap_err();
+        assert!(
+            matches!(
+                error.0,
+                BridgeAccountTotalWithdrawnResponseErrorKind::InvalidAssetId(31)
+            ),
+            "unexpected error: {error:?}"
+        );
+    }
 }
@@ -1,5 +1,12 @@
+use base64::Engine as _;
 use base64_serde::base64_serde_type;
-use serde::Serializer;
+use serde::{
+    de::Error as _,
+    Deserialize as _,
+    Deserializer,
+    Serialize,
+    Serializer,
+};
 
 base64_serde_type!(pub(crate) Base64Standard, base64::engine::general_purpose::STANDARD);
 pub(crate) fn base64_serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
@@ -9,3 +16,179 @@ where
 {
     Base64Standard::serialize(value, serializer)
 }
+
+/// Deserializes an optional 32 byte hash given either as a hex or as a standard base64 string.
+///
+/// Hex is tried first. A string that is neither valid hex nor valid base64, or that does not
+/// decode to exactly 32 bytes, is rejected.
+pub(crate) fn deserialize_optional_hex_or_base64_hash<'de, D>(
+    deserializer: D,
+) -> Result<Option<[u8; 32]>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let Some(encoded) = Option::<String>::deserialize(deserializer)? else {
+        return Ok(None);
+    };
+    let bytes = hex::decode(&encoded)
+        .or_else(|_| base64::engine::general_purpose::STANDARD.decode(&encoded))
+        .map_err(|_| D::Error::custom("hash is neither a valid hex nor a valid base64 string"))?;
+    <[u8; 32]>::try_from(bytes).map(Some).map_err(|bytes| {
+        D::Error::custom(format_args!(
+            "hash must be 32 bytes after decoding, got {} bytes",
+            bytes.len()
+        ))
+    })
+}
+
+/// Serializes `value` to canonical JSON: object keys sorted lexicographically and no
+/// insignificant whitespace.
+///
+/// Keys follow the protobuf field names in `snake_case`, which is how the protobuf JSON mapping
+/// of the generated types names them.
+///
+/// The output only depends on the contents of `value`, which makes it suitable as input to hashing
+/// or signing.
+pub(crate) fn to_canonical_json<T: Serialize>(value: &T) -> String {
+    serde_json::to_value(value)
+        .and_then(|value| serde_json::to_string(&sort_keys(value)))
+        .expect("astria-core types serialize to JSON objects with string keys")
+}
+
+/// Sorts the keys of all objects in `value`, recursively.
+///
+/// Keys are sorted explicitly instead of relying on the key order of `serde_json::Map`, which
+/// keeps insertion order if the `preserve_order` feature of `serde_json` is enabled anywhere in
+/// the dependency graph.
+fn sort_keys(value: serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(object) => {
+            let mut entries: Vec<_> = object
+                .into_iter()
+                .map(|(key, value)| (key, sort_keys(value)))
+                .collect();
+            entries.sort_unstable_by(|(left, _), (right, _)| left.cmp(right));
+            serde_json::Value::Object(entries.into_iter().collect())
+        }
+        serde_json::Value::Array(values) => {
+            serde_json::Value::Array(values.into_iter().map(sort_keys).collect())
+        }
+        other => other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+    use pbjson_types::Timestamp;
+
+    use crate::{
+        execution::v1alpha2::{
+            Block,
+            CommitmentState,
+            GenesisInfo,
+        },
+        generated::execution::v1alpha2 as raw,
+        primitive::v1::{
+            asset,
+            Address,
+            RollupId,
+            ASTRIA_ADDRESS_PREFIX,
+        },
+        protocol::bridge::v1alpha1::{
+            BridgeAccountInfo,
+            BridgeAccountInfoResponse,
+        },
+        Protobuf as _,
+    };
+
+    /// Returns all object keys in `json` that are not `snake_case`, recursively.
+    fn keys_not_in_snake_case(json: &str) -> Vec<String> {
+        fn collect(value: &serde_json::Value, keys: &mut Vec<String>) {
+            match value {
+                serde_json::Value::Object(object) => {
+                    for (key, value) in object {
+                        if key.contains(|c: char| c.is_ascii_uppercase()) {
+                            keys.push(key.clone());
+                        }
+                        collect(value, keys);
+                    }
+                }
+                serde_json::Value::Array(values) => {
+                    for value in values {
+                        collect(value, keys);
+                    }
+                }
+                _ => {}
+            }
+        }
+        let mut keys = Vec::new();
+        collect(&serde_json::from_str(json).unwrap(), &mut keys);
+        keys
+    }
+
+    fn block(number: u32) -> Block {
+        Block::try_from_raw(raw::Block {
+            number,
+            hash: Bytes::from(vec![u8::try_from(number).unwrap(); 32]),
+            parent_block_hash: Bytes::from(vec![0; 32]),
+            timestamp: Some(Timestamp {
+                seconds: i64::from(number),
+                nanos: 0,
+            }),
+        })
+        .unwrap()
+    }
+
+    fn address(byte: u8) -> Address {
+        Address::builder()
+            .array([byte; 20])
+            .prefix(ASTRIA_ADDRESS_PREFIX)
+            .try_build()
+            .unwrap()
+    }
+
+    #[test]
+    fn canonical_json_keys_are_snake_case_for_all_types() {
+        let commitment_state = CommitmentState::builder()
+            .firm(block(1))
+            .soft(block(2))
+            .base_celestia_height(10)
+            .build()
+            .unwrap()
+            .to_canonical_json();
+        let genesis_info = GenesisInfo::try_from_raw(raw::GenesisInfo {
+            rollup_id: Bytes::from(vec![1; 32]),
+            sequencer_genesis_block_height: 1,
+            celestia_block_variance: 5,
+        })
+        .unwrap()
+        .to_canonical_json();
+        let bridge_account_info = BridgeAccountInfoResponse {
+            height: 42,
+            info: Some(BridgeAccountInfo {
+                rollup_id: RollupId::new([1; 32]),
+                asset_id: asset::Id::from_str_unchecked("nria"),
+                sudo_address: address(2),
+                withdrawer_address: address(3),
+            }),
+        }
+        .to_canonical_json();
+
+        for (json, multi_word_key) in [
+            (&commitment_state, "\"base_celestia_height\""),
+            (&genesis_info, "\"celestia_block_variance\""),
+            (&bridge_account_info, "\"withdrawer_address\""),
+        ] {
+            assert!(
+                json.contains(multi_word_key),
+                "missing {multi_word_key} in: {json}"
+            );
+            assert_eq!(
+                Vec::<String>::new(),
+                keys_not_in_snake_case(json),
+                "all keys must be snake_case, but got: {json}",
+            );
+        }
+    }
+}
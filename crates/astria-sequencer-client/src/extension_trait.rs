@@ -561,7 +561,14 @@ pub trait SequencerClientExt: Client {
             .await
             .map_err(|e| Error::tendermint_rpc("abci_query", e))?;
 
-        let proto_response =
+        let proto_response = {
+            #[cfg(feature = "serialization-timing")]
+            let _span = tracing::info_span!(
+                "decode_query_response",
+                message = "astria.protocol.bridge.v1alpha1.BridgeAccountLastTxHashResponse",
+                bytes = response.value.len(),
+            )
+            .entered();
             astria_core::generated::protocol::bridge::v1alpha1::BridgeAccountLastTxHashResponse::decode(
                 &*response.value,
             )
@@ -571,7 +578,8 @@ pub trait SequencerClientExt: Client {
                     response,
                     e,
                 )
-            })?;
+            })?
+        };
         let native = proto_response.try_into_native().map_err(|e| {
             Error::native_conversion(
                 "astria.protocol.bridge.v1alpha1.BridgeAccountLastTxHashResponse",
@@ -272,6 +272,7 @@ async fn get_bridge_account_last_transaction_hash() {
     let expected_response = BridgeAccountLastTxHashResponse {
         height: 10,
         tx_hash: Some([0; 32].to_vec()),
+        block_hash: Some([1; 32].to_vec()),
     };
 
     let _guard = register_abci_query_response(
@@ -15,7 +15,10 @@ use tracing::instrument;
 
 use crate::{
     accounts::action::transfer_check_stateful,
-    bridge::state_ext::StateReadExt as _,
+    bridge::state_ext::{
+        StateReadExt as _,
+        StateWriteExt as _,
+    },
     state_ext::{
         StateReadExt,
         StateWriteExt,
@@ -86,6 +89,10 @@ impl ActionHandler for BridgeUnlockAction {
             .execute(state, bridge_address)
             .await
             .context("failed to execute bridge unlock action as transfer action")?;
+        state
+            .increase_bridge_account_total_withdrawn(&bridge_address, &asset_id, self.amount)
+            .await
+            .context("failed to increase bridge account total withdrawn")?;
 
         Ok(())
     }
@@ -370,6 +377,13 @@ mod test {
             .execute(&mut state, bridge_address)
             .await
             .unwrap();
+        assert_eq!(
+            transfer_amount,
+            state
+                .get_bridge_account_total_withdrawn(&bridge_address, &asset_id)
+                .await
+                .unwrap()
+        );
     }
 
     #[tokio::test]
@@ -1,13 +1,19 @@
 use std::sync::Arc;
 
-use anyhow::Result;
+use anyhow::{
+    Context,
+    Result,
+};
 use tendermint::abci::request::{
     BeginBlock,
     EndBlock,
 };
 use tracing::instrument;
 
-use super::state_ext::StateWriteExt;
+use super::state_ext::{
+    StateReadExt,
+    StateWriteExt,
+};
 use crate::{
     component::Component,
     genesis::GenesisState,
@@ -28,11 +34,25 @@ impl Component for BridgeComponent {
         Ok(())
     }
 
-    #[instrument(name = "BridgeComponent::begin_block", skip(_state))]
+    #[instrument(name = "BridgeComponent::begin_block", skip(state))]
     async fn begin_block<S: StateWriteExt + 'static>(
-        _state: &mut Arc<S>,
+        state: &mut Arc<S>,
         _begin_block: &BeginBlock,
     ) -> Result<()> {
+        // bridge accounts created before the bridge account index was introduced are added to it
+        // once, in the first block executed by a node that knows about the index.
+        if !state
+            .is_bridge_account_index_backfilled()
+            .await
+            .context("failed to check if bridge account index was backfilled")?
+        {
+            let state = Arc::get_mut(state)
+                .context("must only have one reference to the state; this is a bug")?;
+            state
+                .backfill_bridge_account_index()
+                .await
+                .context("failed to backfill bridge account index")?;
+        }
         Ok(())
     }
 
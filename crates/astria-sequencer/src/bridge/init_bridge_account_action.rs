@@ -78,7 +78,13 @@ impl ActionHandler for InitBridgeAccountAction {
             .await
             .context("failed to get base fee for initializing bridge account")?;
 
+        let height = state
+            .get_block_height()
+            .await
+            .context("failed to get block height")?;
+
         state.put_bridge_account_rollup_id(&from, &self.rollup_id);
+        state.put_bridge_account_creation_height(&from, height);
         state
             .put_bridge_account_asset_id(&from, &self.asset_id)
             .context("failed to put asset ID")?;
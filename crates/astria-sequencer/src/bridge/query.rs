@@ -1,16 +1,29 @@
+use std::{
+    future::Future,
+    sync::Arc,
+};
+
 use anyhow::Context as _;
 use astria_core::{
     primitive::v1::Address,
-    protocol::abci::AbciErrorCode,
+    protocol::{
+        abci::AbciErrorCode,
+        bridge::v1alpha1::BridgeAccountInfo,
+    },
+};
+use cnidarium::{
+    StateRead,
+    Storage,
 };
-use cnidarium::Storage;
-use prost::Message as _;
+use prost::Message;
 use tendermint::abci::{
     request,
     response,
 };
+use tokio::sync::Semaphore;
 
 use crate::{
+    api_state_ext::StateReadExt as _,
     bridge::state_ext::StateReadExt as _,
     state_ext::StateReadExt as _,
 };
@@ -19,50 +32,290 @@ pub(crate) async fn bridge_account_last_tx_hash_request(
     storage: Storage,
     request: request::Query,
     params: Vec<(String, String)>,
+    address_prefix: String,
 ) -> response::Query {
     use astria_core::protocol::bridge::v1alpha1::BridgeAccountLastTxHashResponse;
 
-    let address = match preprocess_request(&params) {
+    let address = match preprocess_request(&params, &address_prefix) {
         Ok(tup) => tup,
         Err(err_rsp) => return err_rsp,
     };
 
     // use latest snapshot, as this is a query for latest tx
     let snapshot = storage.latest_snapshot();
-    let height = match snapshot.get_block_height().await {
+    let height = match get_ready_block_height(&snapshot).await {
         Ok(height) => height,
+        Err(err_rsp) => return err_rsp,
+    };
+
+    let resp = match get_last_transaction_and_block_hash(&snapshot, &address).await {
+        Ok((tx_hash, block_hash)) => BridgeAccountLastTxHashResponse {
+            height,
+            tx_hash,
+            block_hash,
+        },
         Err(err) => {
             return response::Query {
                 code: AbciErrorCode::INTERNAL_ERROR.into(),
                 info: AbciErrorCode::INTERNAL_ERROR.to_string(),
-                log: format!("failed getting block height: {err:#}"),
+                log: format!("failed getting balance for provided address: {err:?}"),
                 ..response::Query::default()
             };
         }
     };
+    let payload = encode_response(&resp.into_raw()).into();
+
+    let height = tendermint::block::Height::try_from(height).expect("height must fit into an i64");
+    response::Query {
+        code: 0.into(),
+        key: request.path.clone().into_bytes().into(),
+        value: payload,
+        height,
+        ..response::Query::default()
+    }
+}
 
-    let resp = match snapshot
-        .get_last_transaction_hash_for_bridge_account(&address)
+/// Returns the hash of the last transaction of the bridge account at `address` and the hash of
+/// the block that included it.
+///
+/// The block hash is `None` if the transaction was executed before the heights of last
+/// transactions were recorded.
+async fn get_last_transaction_and_block_hash<S: StateRead>(
+    state: &S,
+    address: &Address,
+) -> anyhow::Result<(Option<[u8; 32]>, Option<[u8; 32]>)> {
+    let Some(tx_hash) = state
+        .get_last_transaction_hash_for_bridge_account(address)
         .await
+        .context("failed to get last transaction hash")?
+    else {
+        return Ok((None, None));
+    };
+    let Some(tx_height) = state
+        .get_last_transaction_height_for_bridge_account(address)
+        .await
+        .context("failed to get height of last transaction")?
+    else {
+        return Ok((Some(tx_hash), None));
+    };
+    let block_hash = state
+        .get_block_hash_by_height(tx_height)
+        .await
+        .context("failed to get hash of block that included the last transaction")?;
+    Ok((Some(tx_hash), Some(block_hash)))
+}
+
+pub(crate) async fn bridge_account_info_request(
+    storage: Storage,
+    request: request::Query,
+    params: Vec<(String, String)>,
+    address_prefix: String,
+) -> response::Query {
+    use astria_core::protocol::bridge::v1alpha1::BridgeAccountInfoResponse;
+
+    let address = match preprocess_request(&params, &address_prefix) {
+        Ok(tup) => tup,
+        Err(err_rsp) => return err_rsp,
+    };
+
+    // use latest snapshot, as this is a query for the latest bridge account info
+    let snapshot = storage.latest_snapshot();
+    let height = match get_ready_block_height(&snapshot).await {
+        Ok(height) => height,
+        Err(err_rsp) => return err_rsp,
+    };
+
+    let info = match get_bridge_account_info(&snapshot, &address).await {
+        Ok(info) => info,
+        Err(err) => {
+            return response::Query {
+                code: AbciErrorCode::INTERNAL_ERROR.into(),
+                info: AbciErrorCode::INTERNAL_ERROR.to_string(),
+                log: format!("failed getting bridge account info for provided address: {err:#}"),
+                ..response::Query::default()
+            };
+        }
+    };
+    let resp = BridgeAccountInfoResponse {
+        height,
+        info,
+    };
+    let payload = encode_response(&resp.into_raw()).into();
+
+    let height = tendermint::block::Height::try_from(height).expect("height must fit into an i64");
+    response::Query {
+        code: 0.into(),
+        key: request.path.clone().into_bytes().into(),
+        value: payload,
+        height,
+        ..response::Query::default()
+    }
+}
+
+pub(crate) async fn bridge_account_creation_height_request(
+    storage: Storage,
+    request: request::Query,
+    params: Vec<(String, String)>,
+    address_prefix: String,
+) -> response::Query {
+    use astria_core::protocol::bridge::v1alpha1::BridgeAccountCreationHeightResponse;
+
+    let address = match preprocess_request(&params, &address_prefix) {
+        Ok(tup) => tup,
+        Err(err_rsp) => return err_rsp,
+    };
+
+    // use latest snapshot, as this is a query for the latest bridge account state
+    let snapshot = storage.latest_snapshot();
+    let height = match get_ready_block_height(&snapshot).await {
+        Ok(height) => height,
+        Err(err_rsp) => return err_rsp,
+    };
+
+    let creation_height = match snapshot.get_bridge_account_creation_height(&address).await {
+        Ok(creation_height) => creation_height,
+        Err(err) => {
+            return response::Query {
+                code: AbciErrorCode::INTERNAL_ERROR.into(),
+                info: AbciErrorCode::INTERNAL_ERROR.to_string(),
+                log: format!(
+                    "failed getting bridge account creation height for provided address: {err:#}"
+                ),
+                ..response::Query::default()
+            };
+        }
+    };
+    let resp = BridgeAccountCreationHeightResponse {
+        height,
+        creation_height,
+    };
+    let payload = encode_response(&resp.into_raw()).into();
+
+    let height = tendermint::block::Height::try_from(height).expect("height must fit into an i64");
+    response::Query {
+        code: 0.into(),
+        key: request.path.clone().into_bytes().into(),
+        value: payload,
+        height,
+        ..response::Query::default()
+    }
+}
+
+/// Returns the total amount withdrawn from a bridge account for every asset withdrawn from it.
+///
+/// Withdrawals executed before the totals were recorded are not included.
+pub(crate) async fn bridge_account_total_withdrawn_request(
+    storage: Storage,
+    request: request::Query,
+    params: Vec<(String, String)>,
+    address_prefix: String,
+) -> response::Query {
+    use astria_core::protocol::bridge::v1alpha1::BridgeAccountTotalWithdrawnResponse;
+
+    let address = match preprocess_request(&params, &address_prefix) {
+        Ok(tup) => tup,
+        Err(err_rsp) => return err_rsp,
+    };
+
+    // use latest snapshot, as this is a query for the latest bridge account state
+    let snapshot = storage.latest_snapshot();
+    let height = match get_ready_block_height(&snapshot).await {
+        Ok(height) => height,
+        Err(err_rsp) => return err_rsp,
+    };
+
+    let totals = match snapshot.get_bridge_account_totals_withdrawn(&address).await {
+        Ok(totals) => totals,
+        Err(err) => {
+            return response::Query {
+                code: AbciErrorCode::INTERNAL_ERROR.into(),
+                info: AbciErrorCode::INTERNAL_ERROR.to_string(),
+                log: format!(
+                    "failed getting bridge account totals withdrawn for provided address: {err:#}"
+                ),
+                ..response::Query::default()
+            };
+        }
+    };
+    let resp = BridgeAccountTotalWithdrawnResponse {
+        height,
+        totals,
+    };
+    let payload = encode_response(&resp.into_raw()).into();
+
+    let height = tendermint::block::Height::try_from(height).expect("height must fit into an i64");
+    response::Query {
+        code: 0.into(),
+        key: request.path.clone().into_bytes().into(),
+        value: payload,
+        height,
+        ..response::Query::default()
+    }
+}
+
+/// The maximum number of bridge accounts returned in a single page by
+/// [`bridge_accounts_list_request`].
+const BRIDGE_ACCOUNTS_PAGE_SIZE: usize = 100;
+
+/// Returns a page of all bridge accounts.
+///
+/// The query data is the opaque cursor returned with the previous page, or empty to request the
+/// first page.
+pub(crate) async fn bridge_accounts_list_request(
+    storage: Storage,
+    request: request::Query,
+    _params: Vec<(String, String)>,
+    address_prefix: String,
+) -> response::Query {
+    use astria_core::protocol::bridge::v1alpha1::BridgeAccountsListResponse;
+
+    let cursor = if request.data.is_empty() {
+        None
+    } else {
+        match String::from_utf8(request.data.to_vec()) {
+            Ok(cursor) => Some(cursor),
+            Err(err) => {
+                return response::Query {
+                    code: AbciErrorCode::INVALID_PARAMETER.into(),
+                    info: AbciErrorCode::INVALID_PARAMETER.to_string(),
+                    log: format!("query data did not contain a valid cursor: {err:#}"),
+                    ..response::Query::default()
+                };
+            }
+        }
+    };
+
+    // use latest snapshot, as this is a query for the latest bridge accounts
+    let snapshot = storage.latest_snapshot();
+    let height = match get_ready_block_height(&snapshot).await {
+        Ok(height) => height,
+        Err(err_rsp) => return err_rsp,
+    };
+
+    let (accounts, next_cursor) = match list_bridge_accounts(
+        &snapshot,
+        cursor.as_deref(),
+        BRIDGE_ACCOUNTS_PAGE_SIZE,
+        &address_prefix,
+    )
+    .await
     {
-        Ok(Some(tx_hash)) => BridgeAccountLastTxHashResponse {
-            height,
-            tx_hash: Some(tx_hash),
-        },
-        Ok(None) => BridgeAccountLastTxHashResponse {
-            height,
-            tx_hash: None,
-        },
+        Ok(page) => page,
         Err(err) => {
             return response::Query {
                 code: AbciErrorCode::INTERNAL_ERROR.into(),
                 info: AbciErrorCode::INTERNAL_ERROR.to_string(),
-                log: format!("failed getting balance for provided address: {err:?}"),
+                log: format!("failed listing bridge accounts: {err:#}"),
                 ..response::Query::default()
             };
         }
     };
-    let payload = resp.into_raw().encode_to_vec().into();
+    let resp = BridgeAccountsListResponse {
+        height,
+        accounts,
+        next_cursor,
+    };
+    let payload = encode_response(&resp.into_raw()).into();
 
     let height = tendermint::block::Height::try_from(height).expect("height must fit into an i64");
     response::Query {
@@ -74,7 +327,158 @@ pub(crate) async fn bridge_account_last_tx_hash_request(
     }
 }
 
-fn preprocess_request(params: &[(String, String)]) -> anyhow::Result<Address, response::Query> {
+/// Reads up to `page_size` bridge accounts from `state`, starting after `cursor`, with addresses
+/// constructed with `address_prefix`.
+///
+/// Returns the accounts together with the cursor to read the next page, which is `None` if there
+/// are no more accounts. The cursor is the last storage key read.
+async fn list_bridge_accounts<S: StateRead>(
+    state: &S,
+    cursor: Option<&str>,
+    page_size: usize,
+    address_prefix: &str,
+) -> anyhow::Result<(Vec<(Address, BridgeAccountInfo)>, Option<Vec<u8>>)> {
+    // read one more than requested to know if there is a next page
+    let mut keys_and_addresses = state
+        .get_bridge_account_addresses(cursor, page_size.saturating_add(1), address_prefix)
+        .await
+        .context("failed to read bridge account addresses")?;
+    let next_cursor = if keys_and_addresses.len() > page_size {
+        keys_and_addresses.truncate(page_size);
+        keys_and_addresses
+            .last()
+            .map(|(key, _)| key.clone().into_bytes())
+    } else {
+        None
+    };
+
+    let mut accounts = Vec::with_capacity(keys_and_addresses.len());
+    for (_, address) in keys_and_addresses {
+        let info = get_bridge_account_info(state, &address)
+            .await
+            .with_context(|| format!("failed to get info of bridge account `{address}`"))?
+            .with_context(|| format!("rollup ID not set for bridge account `{address}`"))?;
+        accounts.push((address, info));
+    }
+    Ok((accounts, next_cursor))
+}
+
+/// Runs the bridge query `handler` while holding one of `permits`.
+///
+/// Returns a resource exhausted response without running `handler` if no permit is available,
+/// i.e. if the maximum number of bridge queries is already in flight.
+pub(crate) async fn with_query_permit(
+    permits: Arc<Semaphore>,
+    handler: impl Future<Output = response::Query>,
+) -> response::Query {
+    let Ok(_permit) = permits.try_acquire_owned() else {
+        return response::Query {
+            code: AbciErrorCode::RESOURCE_EXHAUSTED.into(),
+            info: AbciErrorCode::RESOURCE_EXHAUSTED.to_string(),
+            log: "too many bridge queries in flight".into(),
+            ..response::Query::default()
+        };
+    };
+    handler.await
+}
+
+/// Returns the height of the latest block in `state`.
+///
+/// Returns an error response if the height cannot be read, or if it is 0, i.e. if the chain has
+/// not produced any blocks yet and there is nothing meaningful to report.
+async fn get_ready_block_height<S: StateRead>(state: &S) -> Result<u64, response::Query> {
+    match state.get_block_height().await {
+        Ok(0) => Err(response::Query {
+            code: AbciErrorCode::CHAIN_NOT_READY.into(),
+            info: AbciErrorCode::CHAIN_NOT_READY.to_string(),
+            log: "block height is 0; the chain has not produced any blocks yet".into(),
+            ..response::Query::default()
+        }),
+        Ok(height) => Ok(height),
+        // only look for the cause of the failure once reading the block height failed, so that
+        // queries against an initialized store don't pay for the extra read
+        Err(_) if matches!(state.is_initialized().await, Ok(false)) => Err(response::Query {
+            code: AbciErrorCode::NODE_NOT_INITIALIZED.into(),
+            info: AbciErrorCode::NODE_NOT_INITIALIZED.to_string(),
+            log: "no block height found in state; the node has not been initialized with a \
+                  genesis state yet"
+                .into(),
+            ..response::Query::default()
+        }),
+        Err(err) => Err(response::Query {
+            code: AbciErrorCode::INTERNAL_ERROR.into(),
+            info: AbciErrorCode::INTERNAL_ERROR.to_string(),
+            log: format!("failed getting block height: {err:#}"),
+            ..response::Query::default()
+        }),
+    }
+}
+
+/// Encodes a query response `message` to its protobuf wire format.
+///
+/// With the `serialization-timing` feature enabled the encoding is wrapped in an
+/// `encode_query_response` span, which records the size of the encoded message.
+fn encode_response<M: Message>(message: &M) -> Vec<u8> {
+    #[cfg(feature = "serialization-timing")]
+    {
+        let span = tracing::info_span!(
+            "encode_query_response",
+            message = std::any::type_name::<M>(),
+            bytes = tracing::field::Empty,
+        );
+        let _entered = span.enter();
+        let encoded = message.encode_to_vec();
+        span.record("bytes", encoded.len());
+        encoded
+    }
+    #[cfg(not(feature = "serialization-timing"))]
+    message.encode_to_vec()
+}
+
+/// Reads the bridge account info for `address` from `state`.
+///
+/// Returns `None` if `address` is not a bridge account.
+async fn get_bridge_account_info<S: StateRead>(
+    state: &S,
+    address: &Address,
+) -> anyhow::Result<Option<BridgeAccountInfo>> {
+    let Some(rollup_id) = state
+        .get_bridge_account_rollup_id(address)
+        .await
+        .context("failed to get rollup id")?
+    else {
+        return Ok(None);
+    };
+    let asset_id = state
+        .get_bridge_account_asset_id(address)
+        .await
+        .context("failed to get asset id")?;
+    let sudo_address = state
+        .get_bridge_account_sudo_address(address)
+        .await
+        .context("failed to get sudo address")?
+        .context("sudo address not set for bridge account")?;
+    let withdrawer_address = state
+        .get_bridge_account_withdrawer_address(address)
+        .await
+        .context("failed to get withdrawer address")?
+        .context("withdrawer address not set for bridge account")?;
+    Ok(Some(BridgeAccountInfo {
+        rollup_id,
+        asset_id,
+        sudo_address,
+        withdrawer_address,
+    }))
+}
+
+/// Reads the `address` parameter from `params`.
+///
+/// The address is accepted either as hex-encoded bytes, which are given `address_prefix`, or as a
+/// bech32m string, whose prefix must match `address_prefix`.
+fn preprocess_request(
+    params: &[(String, String)],
+    address_prefix: &str,
+) -> anyhow::Result<Address, response::Query> {
     let Some(address) = params
         .iter()
         .find_map(|(k, v)| (k == "address").then_some(v))
@@ -86,16 +490,730 @@ fn preprocess_request(params: &[(String, String)]) -> anyhow::Result<Address, re
             ..response::Query::default()
         });
     };
-    let address = hex::decode(address)
-        .context("failed decoding hex encoded bytes")
-        .and_then(|addr| {
-            crate::try_astria_address(&addr).context("failed constructing address from bytes")
-        })
-        .map_err(|err| response::Query {
-            code: AbciErrorCode::INVALID_PARAMETER.into(),
-            info: AbciErrorCode::INVALID_PARAMETER.to_string(),
-            log: format!("address could not be constructed from provided parameter: {err:#}"),
-            ..response::Query::default()
-        })?;
+    let address = match hex::decode(address) {
+        Ok(bytes) => crate::try_address_with_prefix(&bytes, address_prefix)
+            .context("failed constructing address from bytes"),
+        Err(_) => Address::try_from_bech32m_with_prefix(address, address_prefix)
+            .context("failed decoding address as either hex encoded bytes or bech32m string"),
+    }
+    .map_err(|err| response::Query {
+        code: AbciErrorCode::INVALID_PARAMETER.into(),
+        info: AbciErrorCode::INVALID_PARAMETER.to_string(),
+        log: format!("address could not be constructed from provided parameter: {err:#}"),
+        ..response::Query::default()
+    })?;
     Ok(address)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        future::Future,
+        sync::Arc,
+    };
+
+    use astria_core::{
+        generated::protocol::bridge::v1alpha1 as raw,
+        primitive::v1::{
+            asset,
+            Address,
+            RollupId,
+        },
+        protocol::{
+            abci::AbciErrorCode,
+            bridge::v1alpha1::{
+                BridgeAccountCreationHeightResponse,
+                BridgeAccountInfoResponse,
+                BridgeAccountLastTxHashResponse,
+                BridgeAccountTotalWithdrawnResponse,
+            },
+            test_utils::ConfigureSequencerBlock,
+        },
+        sequencerblock::v1alpha1::SequencerBlock,
+    };
+    use cnidarium::StateDelta;
+    use prost::Message as _;
+    use tendermint::abci::request;
+    use tokio::sync::Semaphore;
+
+    use crate::{
+        api_state_ext::StateWriteExt as _,
+        bridge::state_ext::StateWriteExt as _,
+        state_ext::StateWriteExt as _,
+    };
+
+    fn address_params(address: String) -> Vec<(String, String)> {
+        vec![("address".to_string(), address)]
+    }
+
+    #[test]
+    fn address_with_matching_prefix_is_accepted() {
+        let address = crate::try_address_with_prefix(&[1; 20], "other").unwrap();
+        assert_eq!(
+            address,
+            super::preprocess_request(&address_params(address.to_string()), "other").unwrap(),
+        );
+        assert_eq!(
+            address,
+            super::preprocess_request(&address_params(hex::encode(address.bytes())), "other")
+                .unwrap(),
+        );
+    }
+
+    #[test]
+    fn address_with_wrong_prefix_is_rejected() {
+        let address = crate::try_address_with_prefix(&[1; 20], "other").unwrap();
+        let response =
+            super::preprocess_request(&address_params(address.to_string()), crate::ADDRESS_PREFIX)
+                .unwrap_err();
+        assert_eq!(
+            tendermint::abci::Code::from(AbciErrorCode::INVALID_PARAMETER),
+            response.code,
+        );
+        assert!(
+            response.log.contains("expected an address with prefix"),
+            "{}",
+            response.log,
+        );
+    }
+
+    #[test]
+    fn address_with_bad_checksum_is_rejected() {
+        let mut address = crate::try_astria_address(&[1; 20]).unwrap().to_string();
+        let last = address.pop().unwrap();
+        address.push(if last == 'q' { 'p' } else { 'q' });
+        let response =
+            super::preprocess_request(&address_params(address), crate::ADDRESS_PREFIX).unwrap_err();
+        assert_eq!(
+            tendermint::abci::Code::from(AbciErrorCode::INVALID_PARAMETER),
+            response.code,
+        );
+        assert!(response.log.contains("checksum"), "{}", response.log);
+    }
+
+    #[test]
+    fn address_of_wrong_length_is_rejected() {
+        let response =
+            super::preprocess_request(&address_params(hex::encode([1; 19])), crate::ADDRESS_PREFIX)
+                .unwrap_err();
+        assert_eq!(
+            tendermint::abci::Code::from(AbciErrorCode::INVALID_PARAMETER),
+            response.code,
+        );
+        assert!(
+            response.log.contains("expected an address of 20 bytes"),
+            "{}",
+            response.log,
+        );
+    }
+
+    /// The state of a bridge account written by [`BridgeQueryFixture::new`].
+    ///
+    /// Fields that are `None` are not written, so that accounts with incomplete or legacy state can
+    /// be set up.
+    #[derive(Default)]
+    struct BridgeAccountState {
+        rollup_id: Option<RollupId>,
+        asset_id: Option<asset::Id>,
+        sudo_address: Option<Address>,
+        withdrawer_address: Option<Address>,
+        creation_height: Option<u64>,
+        last_tx_hash: Option<[u8; 32]>,
+        last_tx_height: Option<u64>,
+        /// Withdrawals added to the account's total withdrawn amounts, in order.
+        withdrawals: Vec<(asset::Id, u128)>,
+    }
+
+    impl BridgeAccountState {
+        /// Returns the state of a fully configured bridge account without a last transaction.
+        fn complete(byte: u8) -> Self {
+            Self {
+                rollup_id: Some(RollupId::new([byte; 32])),
+                asset_id: Some(asset::Id::from_str_unchecked("asset")),
+                sudo_address: Some(crate::astria_address([byte.wrapping_add(1); 20])),
+                withdrawer_address: Some(crate::astria_address([byte.wrapping_add(2); 20])),
+                ..Self::default()
+            }
+        }
+    }
+
+    /// Storage in a temporary directory against which the bridge query handlers are run.
+    struct BridgeQueryFixture {
+        storage: cnidarium::TempStorage,
+    }
+
+    impl BridgeQueryFixture {
+        /// Returns a fresh storage that `init_chain` never wrote to, so that it does not even
+        /// contain a block height.
+        async fn uninitialized() -> Self {
+            Self {
+                storage: cnidarium::TempStorage::new().await.unwrap(),
+            }
+        }
+
+        /// Commits `accounts` to a fresh storage whose latest block is at `block_height`.
+        async fn new(block_height: u64, accounts: Vec<(Address, BridgeAccountState)>) -> Self {
+            Self::with_sequencer_blocks(block_height, vec![], accounts).await
+        }
+
+        /// Like [`BridgeQueryFixture::new`], but also commits the sequencer `blocks`.
+        async fn with_sequencer_blocks(
+            block_height: u64,
+            blocks: Vec<SequencerBlock>,
+            accounts: Vec<(Address, BridgeAccountState)>,
+        ) -> Self {
+            let fixture = Self::uninitialized().await;
+            let mut state = StateDelta::new(fixture.storage.latest_snapshot());
+            state.put_block_height(block_height);
+            for block in blocks {
+                state.put_sequencer_block(block).unwrap();
+            }
+            for (address, account) in accounts {
+                let BridgeAccountState {
+                    rollup_id,
+                    asset_id,
+                    sudo_address,
+                    withdrawer_address,
+                    creation_height,
+                    last_tx_hash,
+                    last_tx_height,
+                    withdrawals,
+                } = account;
+                if let Some(rollup_id) = rollup_id {
+                    state.put_bridge_account_rollup_id(&address, &rollup_id);
+                }
+                if let Some(asset_id) = asset_id {
+                    state
+                        .put_bridge_account_asset_id(&address, &asset_id)
+                        .unwrap();
+                }
+                if let Some(sudo_address) = sudo_address {
+                    state.put_bridge_account_sudo_address(&address, &sudo_address);
+                }
+                if let Some(withdrawer_address) = withdrawer_address {
+                    state.put_bridge_account_withdrawer_address(&address, &withdrawer_address);
+                }
+                if let Some(creation_height) = creation_height {
+                    state.put_bridge_account_creation_height(&address, creation_height);
+                }
+                if let Some(last_tx_hash) = last_tx_hash {
+                    state.put_last_transaction_hash_for_bridge_account(&address, &last_tx_hash);
+                }
+                if let Some(last_tx_height) = last_tx_height {
+                    state.put_last_transaction_height_for_bridge_account(&address, last_tx_height);
+                }
+                for (asset_id, amount) in withdrawals {
+                    state
+                        .increase_bridge_account_total_withdrawn(&address, &asset_id, amount)
+                        .await
+                        .unwrap();
+                }
+            }
+            fixture.storage.commit(state).await.unwrap();
+            fixture
+        }
+
+        fn snapshot(&self) -> cnidarium::Snapshot {
+            self.storage.latest_snapshot()
+        }
+
+        /// Runs the query `handler` serving `bridge/{endpoint}/{address}`.
+        async fn query<F, Fut>(
+            &self,
+            endpoint: &str,
+            address: &Address,
+            handler: F,
+        ) -> tendermint::abci::response::Query
+        where
+            F: FnOnce(cnidarium::Storage, request::Query, Vec<(String, String)>, String) -> Fut,
+            Fut: Future<Output = tendermint::abci::response::Query>,
+        {
+            let address = hex::encode(address.bytes());
+            let request = request::Query {
+                path: format!("bridge/{endpoint}/{address}"),
+                data: vec![].into(),
+                height: 0u32.into(),
+                prove: false,
+            };
+            handler(
+                (*self.storage).clone(),
+                request,
+                address_params(address),
+                crate::ADDRESS_PREFIX.to_string(),
+            )
+            .await
+        }
+
+        /// Runs the `bridge/account_info` query for `address`.
+        async fn account_info(&self, address: &Address) -> tendermint::abci::response::Query {
+            self.query("account_info", address, super::bridge_account_info_request)
+                .await
+        }
+
+        /// Runs the `bridge/account_creation_height` query for `address`, expecting it to
+        /// succeed.
+        async fn creation_height(&self, address: &Address) -> BridgeAccountCreationHeightResponse {
+            let response = self
+                .query(
+                    "account_creation_height",
+                    address,
+                    super::bridge_account_creation_height_request,
+                )
+                .await;
+            assert!(response.code.is_ok(), "{}", response.log);
+            raw::BridgeAccountCreationHeightResponse::decode(response.value)
+                .unwrap()
+                .into_native()
+        }
+
+        /// Runs the `bridge/account_total_withdrawn` query for `address`, expecting it to
+        /// succeed.
+        async fn total_withdrawn(&self, address: &Address) -> BridgeAccountTotalWithdrawnResponse {
+            let response = self
+                .query(
+                    "account_total_withdrawn",
+                    address,
+                    super::bridge_account_total_withdrawn_request,
+                )
+                .await;
+            assert!(response.code.is_ok(), "{}", response.log);
+            raw::BridgeAccountTotalWithdrawnResponse::decode(response.value)
+                .unwrap()
+                .try_into_native()
+                .unwrap()
+        }
+
+        /// Runs the `bridge/account_last_tx_hash` query for `address`, expecting it to succeed.
+        async fn last_tx_hash(&self, address: &Address) -> BridgeAccountLastTxHashResponse {
+            let response = self
+                .query(
+                    "account_last_tx_hash",
+                    address,
+                    super::bridge_account_last_tx_hash_request,
+                )
+                .await;
+            assert!(response.code.is_ok(), "{}", response.log);
+            raw::BridgeAccountLastTxHashResponse::decode(response.value)
+                .unwrap()
+                .try_into_native()
+                .unwrap()
+        }
+    }
+
+    fn decode_account_info(
+        response: tendermint::abci::response::Query,
+    ) -> BridgeAccountInfoResponse {
+        assert!(response.code.is_ok(), "{}", response.log);
+        raw::BridgeAccountInfoResponse::decode(response.value)
+            .unwrap()
+            .try_into_native()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn query_against_uninitialized_store_reports_node_not_initialized() {
+        let fixture = BridgeQueryFixture::uninitialized().await;
+        let response = fixture.account_info(&crate::astria_address([1; 20])).await;
+        assert_eq!(
+            tendermint::abci::Code::from(AbciErrorCode::NODE_NOT_INITIALIZED),
+            response.code,
+        );
+        assert!(
+            response.log.contains("not been initialized"),
+            "{}",
+            response.log,
+        );
+    }
+
+    #[tokio::test]
+    async fn query_at_height_zero_reports_chain_not_ready() {
+        let fixture = BridgeQueryFixture::new(0, vec![]).await;
+        let response = fixture.account_info(&crate::astria_address([1; 20])).await;
+        assert_eq!(
+            tendermint::abci::Code::from(AbciErrorCode::CHAIN_NOT_READY),
+            response.code,
+        );
+    }
+
+    #[tokio::test]
+    async fn query_at_nonzero_height_succeeds() {
+        let fixture = BridgeQueryFixture::new(1, vec![]).await;
+        let response = fixture.account_info(&crate::astria_address([1; 20])).await;
+        assert!(response.code.is_ok(), "{}", response.log);
+        assert_eq!(1, response.height.value());
+    }
+
+    #[tokio::test]
+    async fn queries_beyond_concurrency_limit_are_rejected() {
+        let fixture = BridgeQueryFixture::new(1, vec![]).await;
+        let address = crate::astria_address([1; 20]);
+        let permits = Arc::new(Semaphore::new(2));
+
+        // one query in flight: still under the limit
+        let _in_flight = permits.clone().try_acquire_owned().unwrap();
+        let response =
+            super::with_query_permit(permits.clone(), fixture.account_info(&address)).await;
+        assert!(response.code.is_ok(), "{}", response.log);
+
+        // two queries in flight: the limit is reached
+        let _also_in_flight = permits.clone().try_acquire_owned().unwrap();
+        let response =
+            super::with_query_permit(permits.clone(), fixture.account_info(&address)).await;
+        assert_eq!(
+            tendermint::abci::Code::from(AbciErrorCode::RESOURCE_EXHAUSTED),
+            response.code,
+        );
+    }
+
+    #[tokio::test]
+    async fn permit_is_released_after_query_completes() {
+        let fixture = BridgeQueryFixture::new(1, vec![]).await;
+        let address = crate::astria_address([1; 20]);
+        let permits = Arc::new(Semaphore::new(1));
+        for _ in 0..3 {
+            let response =
+                super::with_query_permit(permits.clone(), fixture.account_info(&address)).await;
+            assert!(response.code.is_ok(), "{}", response.log);
+        }
+        assert_eq!(1, permits.available_permits());
+    }
+
+    #[tokio::test]
+    async fn info_of_bridge_account_is_found() {
+        let address = crate::astria_address([1; 20]);
+        let fixture = BridgeQueryFixture::new(
+            5,
+            vec![(
+                address,
+                BridgeAccountState {
+                    last_tx_hash: Some([7; 32]),
+                    ..BridgeAccountState::complete(1)
+                },
+            )],
+        )
+        .await;
+
+        let response = decode_account_info(fixture.account_info(&address).await);
+        assert_eq!(5, response.height);
+        let info = response
+            .info
+            .expect("the bridge account was written to state");
+        assert_eq!(RollupId::new([1; 32]), info.rollup_id);
+        assert_eq!(asset::Id::from_str_unchecked("asset"), info.asset_id);
+        assert_eq!(crate::astria_address([2; 20]), info.sudo_address);
+        assert_eq!(crate::astria_address([3; 20]), info.withdrawer_address);
+
+        let last_tx_hash = fixture.last_tx_hash(&address).await;
+        assert_eq!(Some([7; 32]), last_tx_hash.tx_hash);
+    }
+
+    #[tokio::test]
+    async fn info_of_unknown_address_is_not_found() {
+        let fixture = BridgeQueryFixture::new(
+            5,
+            vec![(
+                crate::astria_address([1; 20]),
+                BridgeAccountState::complete(1),
+            )],
+        )
+        .await;
+        let unknown = crate::astria_address([9; 20]);
+
+        let response = decode_account_info(fixture.account_info(&unknown).await);
+        assert_eq!(5, response.height);
+        assert_eq!(None, response.info);
+
+        let last_tx_hash = fixture.last_tx_hash(&unknown).await;
+        assert_eq!(None, last_tx_hash.tx_hash);
+        assert_eq!(None, last_tx_hash.block_hash);
+    }
+
+    #[tokio::test]
+    async fn bridge_account_missing_sudo_address_is_an_internal_error() {
+        let address = crate::astria_address([1; 20]);
+        let fixture = BridgeQueryFixture::new(
+            5,
+            vec![(
+                address,
+                BridgeAccountState {
+                    sudo_address: None,
+                    ..BridgeAccountState::complete(1)
+                },
+            )],
+        )
+        .await;
+
+        let response = fixture.account_info(&address).await;
+        assert_eq!(
+            tendermint::abci::Code::from(AbciErrorCode::INTERNAL_ERROR),
+            response.code,
+        );
+        assert!(
+            response.log.contains("sudo address not set"),
+            "{}",
+            response.log,
+        );
+    }
+
+    #[tokio::test]
+    async fn last_tx_hash_is_returned_with_hash_of_including_block() {
+        let address = crate::astria_address([1; 20]);
+        let block = ConfigureSequencerBlock {
+            block_hash: Some([9; 32]),
+            height: 3,
+            ..Default::default()
+        }
+        .make();
+        let fixture = BridgeQueryFixture::with_sequencer_blocks(
+            5,
+            vec![block],
+            vec![(
+                address,
+                BridgeAccountState {
+                    last_tx_hash: Some([7; 32]),
+                    last_tx_height: Some(3),
+                    ..BridgeAccountState::default()
+                },
+            )],
+        )
+        .await;
+
+        let expected = BridgeAccountLastTxHashResponse {
+            height: 5,
+            tx_hash: Some([7; 32]),
+            block_hash: Some([9; 32]),
+        };
+        assert_eq!(expected, fixture.last_tx_hash(&address).await);
+    }
+
+    #[tokio::test]
+    async fn last_tx_hash_of_legacy_transaction_is_returned_without_block_hash() {
+        // a transaction executed before the heights of last transactions were recorded
+        let address = crate::astria_address([1; 20]);
+        let fixture = BridgeQueryFixture::new(
+            5,
+            vec![(
+                address,
+                BridgeAccountState {
+                    last_tx_hash: Some([7; 32]),
+                    ..BridgeAccountState::default()
+                },
+            )],
+        )
+        .await;
+
+        let expected = BridgeAccountLastTxHashResponse {
+            height: 5,
+            tx_hash: Some([7; 32]),
+            block_hash: None,
+        };
+        assert_eq!(expected, fixture.last_tx_hash(&address).await);
+    }
+
+    #[tokio::test]
+    async fn creation_height_of_tracked_account_is_returned() {
+        let address = crate::astria_address([1; 20]);
+        let fixture = BridgeQueryFixture::new(
+            5,
+            vec![(
+                address,
+                BridgeAccountState {
+                    creation_height: Some(3),
+                    ..BridgeAccountState::complete(1)
+                },
+            )],
+        )
+        .await;
+
+        let expected = BridgeAccountCreationHeightResponse {
+            height: 5,
+            creation_height: Some(3),
+        };
+        assert_eq!(expected, fixture.creation_height(&address).await);
+    }
+
+    #[tokio::test]
+    async fn creation_height_of_legacy_account_is_none() {
+        // an account created before creation heights were recorded
+        let address = crate::astria_address([1; 20]);
+        let fixture =
+            BridgeQueryFixture::new(5, vec![(address, BridgeAccountState::complete(1))]).await;
+
+        let expected = BridgeAccountCreationHeightResponse {
+            height: 5,
+            creation_height: None,
+        };
+        assert_eq!(expected, fixture.creation_height(&address).await);
+    }
+
+    #[tokio::test]
+    async fn total_withdrawn_sums_withdrawals_per_asset() {
+        let address = crate::astria_address([1; 20]);
+        let asset_id = asset::Id::from_str_unchecked("nria");
+        let fixture = BridgeQueryFixture::new(
+            5,
+            vec![(
+                address,
+                BridgeAccountState {
+                    withdrawals: [10, 20, 30]
+                        .into_iter()
+                        .map(|amount| (asset_id, amount))
+                        .collect(),
+                    ..BridgeAccountState::complete(1)
+                },
+            )],
+        )
+        .await;
+
+        let response = fixture.total_withdrawn(&address).await;
+        assert_eq!(5, response.height);
+        assert_eq!(60, response.total_withdrawn(&asset_id));
+        assert_eq!(
+            0,
+            response.total_withdrawn(&asset::Id::from_str_unchecked("utia"))
+        );
+    }
+
+    #[tokio::test]
+    async fn total_withdrawn_of_account_without_withdrawals_is_empty() {
+        // also covers accounts whose withdrawals all predate the recorded totals
+        let address = crate::astria_address([1; 20]);
+        let fixture =
+            BridgeQueryFixture::new(5, vec![(address, BridgeAccountState::complete(1))]).await;
+
+        let expected = BridgeAccountTotalWithdrawnResponse {
+            height: 5,
+            totals: vec![],
+        };
+        assert_eq!(expected, fixture.total_withdrawn(&address).await);
+    }
+
+    /// Returns a bridge account that is its own sudo and withdrawer address, and whose rollup ID
+    /// is made of `byte`.
+    fn listed_account(byte: u8) -> (Address, BridgeAccountState) {
+        let address = crate::astria_address([byte; 20]);
+        let account = BridgeAccountState {
+            rollup_id: Some(RollupId::new([byte; 32])),
+            asset_id: Some(asset::Id::from_str_unchecked("asset")),
+            sudo_address: Some(address),
+            withdrawer_address: Some(address),
+            ..BridgeAccountState::default()
+        };
+        (address, account)
+    }
+
+    #[tokio::test]
+    async fn bridge_accounts_are_listed_page_by_page() {
+        let accounts: Vec<_> = (0..5u8)
+            .map(|i| {
+                let (address, account) = listed_account(i);
+                // bridge account keys which are not the rollup ID must not be listed twice
+                let account = BridgeAccountState {
+                    last_tx_hash: Some([i; 32]),
+                    ..account
+                };
+                (address, account)
+            })
+            .collect();
+        let expected: Vec<_> = accounts.iter().map(|(address, _)| *address).collect();
+        let fixture = BridgeQueryFixture::new(1, accounts).await;
+        let snapshot = fixture.snapshot();
+
+        let mut pages = 0;
+        let mut actual = Vec::new();
+        let mut cursor = None;
+        loop {
+            let (accounts, next_cursor) =
+                super::list_bridge_accounts(&snapshot, cursor.as_deref(), 2, crate::ADDRESS_PREFIX)
+                    .await
+                    .unwrap();
+            pages = pages.saturating_add(1);
+            assert!(accounts.len() <= 2);
+            for (address, info) in accounts {
+                assert_eq!(RollupId::new([address.bytes()[0]; 32]), info.rollup_id);
+                actual.push(address);
+            }
+            let Some(next_cursor) = next_cursor else {
+                break;
+            };
+            cursor = Some(String::from_utf8(next_cursor).unwrap());
+        }
+
+        assert_eq!(3, pages);
+        assert_eq!(expected, actual);
+    }
+
+    #[tokio::test]
+    async fn last_full_page_has_no_cursor() {
+        let fixture = BridgeQueryFixture::new(1, (0..2u8).map(listed_account).collect()).await;
+
+        let (accounts, next_cursor) =
+            super::list_bridge_accounts(&fixture.snapshot(), None, 2, crate::ADDRESS_PREFIX)
+                .await
+                .unwrap();
+        assert_eq!(2, accounts.len());
+        assert_eq!(None, next_cursor);
+    }
+
+    #[cfg(feature = "serialization-timing")]
+    mod serialization_timing {
+        use std::sync::{
+            Arc,
+            Mutex,
+        };
+
+        use tracing::{
+            field::{
+                Field,
+                Visit,
+            },
+            span,
+            Subscriber,
+        };
+        use tracing_subscriber::{
+            layer::{
+                Context,
+                SubscriberExt as _,
+            },
+            Layer,
+        };
+
+        use super::BridgeQueryFixture;
+
+        /// Records the values of all `bytes` fields written to spans.
+        #[derive(Clone, Default)]
+        struct ByteSizes(Arc<Mutex<Vec<u64>>>);
+
+        impl Visit for ByteSizes {
+            fn record_u64(&mut self, field: &Field, value: u64) {
+                if field.name() == "bytes" {
+                    self.0.lock().unwrap().push(value);
+                }
+            }
+
+            fn record_debug(&mut self, _field: &Field, _value: &dyn std::fmt::Debug) {}
+        }
+
+        impl<S: Subscriber> Layer<S> for ByteSizes {
+            fn on_record(&self, _id: &span::Id, values: &span::Record<'_>, _ctx: Context<'_, S>) {
+                values.record(&mut self.clone());
+            }
+        }
+
+        #[tokio::test]
+        async fn bridge_account_info_request_records_encoded_size() {
+            let byte_sizes = ByteSizes::default();
+            let _guard = tracing::subscriber::set_default(
+                tracing_subscriber::registry().with(byte_sizes.clone()),
+            );
+
+            let fixture = BridgeQueryFixture::new(99, vec![]).await;
+            let response = fixture.account_info(&crate::astria_address([1; 20])).await;
+            assert!(response.code.is_ok());
+
+            let byte_sizes = byte_sizes.0.lock().unwrap();
+            assert_eq!(1, byte_sizes.len());
+            assert!(byte_sizes[0] > 0);
+        }
+    }
+}
@@ -1,6 +1,9 @@
-use std::collections::{
-    HashMap,
-    HashSet,
+use std::{
+    collections::{
+        HashMap,
+        HashSet,
+    },
+    ops::Bound,
 };
 
 use anyhow::{
@@ -58,6 +61,8 @@ impl From<&asset::Id> for AssetId {
 struct Fee(u128);
 
 const BRIDGE_ACCOUNT_PREFIX: &str = "bridgeacc";
+const BRIDGE_ACCOUNT_INDEX_PREFIX: &str = "bridgeaccindex/";
+const BRIDGE_ACCOUNT_INDEX_BACKFILLED_STORAGE_KEY: &str = "bridgeaccindexbackfilled";
 const BRIDGE_ACCOUNT_SUDO_PREFIX: &str = "bsudo";
 const BRIDGE_ACCOUNT_WITHDRAWER_PREFIX: &str = "bwithdrawer";
 const DEPOSIT_PREFIX: &str = "deposit";
@@ -76,6 +81,14 @@ fn rollup_id_storage_key(address: &Address) -> String {
     )
 }
 
+fn bridge_account_index_storage_key(address: &Address) -> Vec<u8> {
+    bridge_account_index_storage_key_from_hex(&address.encode_hex::<String>())
+}
+
+fn bridge_account_index_storage_key_from_hex(address: &str) -> Vec<u8> {
+    format!("{BRIDGE_ACCOUNT_INDEX_PREFIX}{address}").into_bytes()
+}
+
 fn asset_id_storage_key(address: &Address) -> String {
     format!(
         "{}/assetid",
@@ -83,6 +96,30 @@ fn asset_id_storage_key(address: &Address) -> String {
     )
 }
 
+fn creation_height_storage_key(address: &Address) -> Vec<u8> {
+    format!(
+        "{}/creationheight",
+        bridge_account_storage_key(&address.encode_hex::<String>())
+    )
+    .into_bytes()
+}
+
+fn total_withdrawn_storage_key_prefix(address: &Address) -> String {
+    format!(
+        "{}/totalwithdrawn/",
+        bridge_account_storage_key(&address.encode_hex::<String>())
+    )
+}
+
+fn total_withdrawn_storage_key(address: &Address, asset_id: &asset::Id) -> Vec<u8> {
+    format!(
+        "{}{}",
+        total_withdrawn_storage_key_prefix(address),
+        hex::encode(asset_id)
+    )
+    .into_bytes()
+}
+
 fn deposit_storage_key_prefix(rollup_id: &RollupId) -> String {
     format!("{DEPOSIT_PREFIX}/{}", rollup_id.encode_hex::<String>())
 }
@@ -112,6 +149,14 @@ fn last_transaction_hash_for_bridge_account_storage_key(address: &Address) -> Ve
     .to_vec()
 }
 
+fn last_transaction_height_for_bridge_account_storage_key(address: &Address) -> Vec<u8> {
+    format!(
+        "{}/lasttxheight",
+        bridge_account_storage_key(&address.encode_hex::<String>())
+    )
+    .into_bytes()
+}
+
 #[async_trait]
 pub(crate) trait StateReadExt: StateRead {
     #[instrument(skip(self))]
@@ -310,6 +355,162 @@ pub(crate) trait StateReadExt: StateRead {
             .expect("all transaction hashes stored should be 32 bytes; this is a bug");
         Ok(Some(tx_hash))
     }
+
+    /// Returns the height of the block that included the last transaction of the bridge account
+    /// at `address`.
+    ///
+    /// Returns `None` if the bridge account has no last transaction, or if it was executed before
+    /// the heights of last transactions were recorded.
+    #[instrument(skip(self))]
+    async fn get_last_transaction_height_for_bridge_account(
+        &self,
+        address: &Address,
+    ) -> Result<Option<u64>> {
+        let Some(bytes) = self
+            .nonverifiable_get_raw(&last_transaction_height_for_bridge_account_storage_key(
+                address,
+            ))
+            .await
+            .context("failed reading raw last transaction height for bridge account from state")?
+        else {
+            return Ok(None);
+        };
+        let height = u64::from_be_bytes(
+            bytes
+                .try_into()
+                .expect("all last transaction heights stored should be 8 bytes; this is a bug"),
+        );
+        Ok(Some(height))
+    }
+
+    /// Returns the height of the block in which the bridge account at `address` was created.
+    ///
+    /// Returns `None` if `address` is not a bridge account, or if the account was created before
+    /// creation heights were recorded. The creation height of such accounts cannot be recovered
+    /// from state, so it is not backfilled.
+    #[instrument(skip(self))]
+    async fn get_bridge_account_creation_height(&self, address: &Address) -> Result<Option<u64>> {
+        let Some(bytes) = self
+            .nonverifiable_get_raw(&creation_height_storage_key(address))
+            .await
+            .context("failed reading raw bridge account creation height from state")?
+        else {
+            return Ok(None);
+        };
+        let height = u64::from_be_bytes(
+            bytes
+                .try_into()
+                .expect("all creation heights stored should be 8 bytes; this is a bug"),
+        );
+        Ok(Some(height))
+    }
+
+    /// Returns the total amount of `asset_id` withdrawn from the bridge account at `address`.
+    ///
+    /// Returns 0 if the asset was never withdrawn from the account. Withdrawals executed before
+    /// totals were recorded are not included: they cannot be recovered from state, so the totals
+    /// are not backfilled.
+    #[instrument(skip(self))]
+    async fn get_bridge_account_total_withdrawn(
+        &self,
+        address: &Address,
+        asset_id: &asset::Id,
+    ) -> Result<u128> {
+        let Some(bytes) = self
+            .nonverifiable_get_raw(&total_withdrawn_storage_key(address, asset_id))
+            .await
+            .context("failed reading raw bridge account total withdrawn from state")?
+        else {
+            return Ok(0);
+        };
+        Ok(u128::from_be_bytes(bytes.try_into().expect(
+            "all totals withdrawn stored should be 16 bytes; this is a bug",
+        )))
+    }
+
+    /// Returns the total amount withdrawn from the bridge account at `address` for every asset
+    /// that was withdrawn from it, ordered by asset ID.
+    ///
+    /// See [`StateReadExt::get_bridge_account_total_withdrawn`] for the withdrawals counted.
+    #[instrument(skip(self))]
+    async fn get_bridge_account_totals_withdrawn(
+        &self,
+        address: &Address,
+    ) -> Result<Vec<(asset::Id, u128)>> {
+        let prefix = total_withdrawn_storage_key_prefix(address);
+        let mut stream = std::pin::pin!(self.nonverifiable_prefix_raw(prefix.as_bytes()));
+        let mut totals = Vec::new();
+        while let Some(item) = stream.next().await {
+            let (key, value) = item.context("failed reading bridge account total withdrawn")?;
+            let asset_id = key
+                .strip_prefix(prefix.as_bytes())
+                .map(hex::decode)
+                .transpose()
+                .context("invalid asset ID hex string")?
+                .and_then(|bytes| asset::Id::try_from_slice(&bytes).ok())
+                .context("invalid asset ID in total withdrawn storage key")?;
+            let total = u128::from_be_bytes(
+                value
+                    .try_into()
+                    .expect("all totals withdrawn stored should be 16 bytes; this is a bug"),
+            );
+            totals.push((asset_id, total));
+        }
+        Ok(totals)
+    }
+
+    /// Returns up to `limit` bridge account addresses, each together with its index storage key.
+    ///
+    /// The addresses are ordered by their index storage keys and are constructed with
+    /// `address_prefix`. If `start_after` is set, reading starts at the first index key that sorts
+    /// strictly after it.
+    #[instrument(skip(self))]
+    async fn get_bridge_account_addresses(
+        &self,
+        start_after: Option<&str>,
+        limit: usize,
+        address_prefix: &str,
+    ) -> Result<Vec<(String, Address)>> {
+        let start = start_after.map_or(Bound::Unbounded, |start_after| {
+            Bound::Excluded(start_after.as_bytes().to_vec())
+        });
+        let stream = self
+            .nonverifiable_range_raw(
+                Some(BRIDGE_ACCOUNT_INDEX_PREFIX.as_bytes()),
+                (start, Bound::Unbounded),
+            )
+            .context("failed to read bridge account index from state")?;
+        let mut stream = std::pin::pin!(stream.take(limit));
+        let mut addresses = Vec::new();
+        while let Some(item) = stream.next().await {
+            let (key, _) = item.context("failed reading bridge account index key from state")?;
+            let key = String::from_utf8(key).context("bridge account index key is not utf8")?;
+            let address_bytes = key
+                .strip_prefix(BRIDGE_ACCOUNT_INDEX_PREFIX)
+                .map(hex::decode)
+                .transpose()
+                .context("invalid bridge account address hex string")?
+                .context("bridge account index key is missing its prefix")?;
+            let address = Address::builder()
+                .slice(address_bytes.as_slice())
+                .prefix(address_prefix)
+                .try_build()
+                .context("invalid bridge account address bytes")?;
+            addresses.push((key, address));
+        }
+        Ok(addresses)
+    }
+
+    /// Returns whether the bridge accounts created before the bridge account index was introduced
+    /// were added to it.
+    #[instrument(skip(self))]
+    async fn is_bridge_account_index_backfilled(&self) -> Result<bool> {
+        Ok(self
+            .nonverifiable_get_raw(BRIDGE_ACCOUNT_INDEX_BACKFILLED_STORAGE_KEY.as_bytes())
+            .await
+            .context("failed reading bridge account index backfill marker from state")?
+            .is_some())
+    }
 }
 
 impl<T: StateRead + ?Sized> StateReadExt for T {}
@@ -318,7 +519,80 @@ impl<T: StateRead + ?Sized> StateReadExt for T {}
 pub(crate) trait StateWriteExt: StateWrite {
     #[instrument(skip(self))]
     fn put_bridge_account_rollup_id(&mut self, address: &Address, rollup_id: &RollupId) {
-        self.put_raw(rollup_id_storage_key(address), rollup_id.to_vec());
+        self.put_raw(rollup_id_storage_key(address), rollup_id.as_ref().to_vec());
+        // every bridge account has exactly one rollup ID, which is set when it is created. The
+        // index lists every bridge account so that they can be paged through without reading the
+        // other keys of every account. Accounts created before the index was introduced are added
+        // by `backfill_bridge_account_index`. It is not part of consensus state, just like the
+        // creation height of a bridge account.
+        self.nonverifiable_put_raw(bridge_account_index_storage_key(address), vec![]);
+    }
+
+    /// Adds every bridge account with a rollup ID to the bridge account index, and records that
+    /// the index was backfilled.
+    ///
+    /// Bridge accounts created before the index was introduced are otherwise missing from it.
+    #[instrument(skip(self))]
+    async fn backfill_bridge_account_index(&mut self) -> Result<()> {
+        let prefix = format!("{BRIDGE_ACCOUNT_PREFIX}/");
+        let mut stream = std::pin::pin!(self.prefix_keys(&prefix));
+        let mut index_keys = Vec::new();
+        while let Some(key) = stream.next().await {
+            let key = key.context("failed reading bridge account key from state")?;
+            if let Some(address) = key
+                .strip_prefix(&prefix)
+                .and_then(|rest| rest.strip_suffix("/rollupid"))
+            {
+                index_keys.push(bridge_account_index_storage_key_from_hex(address));
+            }
+        }
+        debug!(
+            bridge_accounts = index_keys.len(),
+            "backfilled bridge account index"
+        );
+        for key in index_keys {
+            self.nonverifiable_put_raw(key, vec![]);
+        }
+        self.nonverifiable_put_raw(
+            BRIDGE_ACCOUNT_INDEX_BACKFILLED_STORAGE_KEY
+                .as_bytes()
+                .to_vec(),
+            vec![],
+        );
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    fn put_bridge_account_creation_height(&mut self, address: &Address, height: u64) {
+        // stored in nonverifiable storage: the creation height is bookkeeping for indexers and not
+        // part of consensus state, just like the last transaction hash of a bridge account.
+        self.nonverifiable_put_raw(
+            creation_height_storage_key(address),
+            height.to_be_bytes().to_vec(),
+        );
+    }
+
+    /// Adds `amount` to the total of `asset_id` withdrawn from the bridge account at `address`.
+    #[instrument(skip(self))]
+    async fn increase_bridge_account_total_withdrawn(
+        &mut self,
+        address: &Address,
+        asset_id: &asset::Id,
+        amount: u128,
+    ) -> Result<()> {
+        let total = self
+            .get_bridge_account_total_withdrawn(address, asset_id)
+            .await
+            .context("failed to get bridge account total withdrawn")?
+            .checked_add(amount)
+            .context("total withdrawn from bridge account overflowed")?;
+        // stored in nonverifiable storage: the totals are bookkeeping for reconciliation and not
+        // part of consensus state, just like the creation height of a bridge account.
+        self.nonverifiable_put_raw(
+            total_withdrawn_storage_key(address, asset_id),
+            total.to_be_bytes().to_vec(),
+        );
+        Ok(())
     }
 
     #[instrument(skip(self))]
@@ -342,7 +616,7 @@ pub(crate) trait StateWriteExt: StateWrite {
     ) {
         self.put_raw(
             bridge_account_sudo_address_storage_key(bridge_address),
-            sudo_address.bytes().to_vec(),
+            sudo_address.as_ref().to_vec(),
         );
     }
 
@@ -354,7 +628,7 @@ pub(crate) trait StateWriteExt: StateWrite {
     ) {
         self.put_raw(
             bridge_account_withdrawer_address_storage_key(bridge_address),
-            withdrawer_address.bytes().to_vec(),
+            withdrawer_address.as_ref().to_vec(),
         );
     }
 
@@ -441,6 +715,14 @@ pub(crate) trait StateWriteExt: StateWrite {
             tx_hash.to_vec(),
         );
     }
+
+    #[instrument(skip(self))]
+    fn put_last_transaction_height_for_bridge_account(&mut self, address: &Address, height: u64) {
+        self.nonverifiable_put_raw(
+            last_transaction_height_for_bridge_account_storage_key(address),
+            height.to_be_bytes().to_vec(),
+        );
+    }
 }
 
 impl<T: StateWrite> StateWriteExt for T {}
@@ -454,7 +736,10 @@ mod test {
         },
         sequencerblock::v1alpha1::block::Deposit,
     };
-    use cnidarium::StateDelta;
+    use cnidarium::{
+        StateDelta,
+        StateWrite as _,
+    };
 
     use super::{
         StateReadExt as _,
@@ -538,6 +823,139 @@ mod test {
         );
     }
 
+    #[tokio::test]
+    async fn put_bridge_account_creation_height() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let address = crate::astria_address([42u8; 20]);
+
+        // uninitialized ok
+        assert_eq!(
+            state
+                .get_bridge_account_creation_height(&address)
+                .await
+                .expect("call to get creation height should not fail for uninitialized addresses"),
+            None,
+        );
+
+        state.put_bridge_account_creation_height(&address, 7);
+        assert_eq!(
+            state
+                .get_bridge_account_creation_height(&address)
+                .await
+                .expect("a creation height was written and must exist inside the database"),
+            Some(7),
+        );
+    }
+
+    #[tokio::test]
+    async fn put_last_transaction_height_for_bridge_account() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let address = crate::astria_address([42u8; 20]);
+
+        // uninitialized ok
+        assert_eq!(
+            state
+                .get_last_transaction_height_for_bridge_account(&address)
+                .await
+                .expect(
+                    "call to get last transaction height should not fail for uninitialized \
+                     addresses"
+                ),
+            None,
+        );
+
+        state.put_last_transaction_height_for_bridge_account(&address, 7);
+        assert_eq!(
+            state
+                .get_last_transaction_height_for_bridge_account(&address)
+                .await
+                .expect("a last transaction height was written and must exist inside the database"),
+            Some(7),
+        );
+    }
+
+    #[tokio::test]
+    async fn bridge_account_totals_withdrawn_are_accumulated_per_asset() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let address = crate::astria_address([42u8; 20]);
+        let other_address = crate::astria_address([43u8; 20]);
+        let nria = Id::from_str_unchecked("nria");
+        let utia = Id::from_str_unchecked("utia");
+
+        // never withdrawn is zero
+        assert_eq!(
+            0,
+            state
+                .get_bridge_account_total_withdrawn(&address, &nria)
+                .await
+                .unwrap()
+        );
+        assert!(state
+            .get_bridge_account_totals_withdrawn(&address)
+            .await
+            .unwrap()
+            .is_empty());
+
+        for amount in [10, 20] {
+            state
+                .increase_bridge_account_total_withdrawn(&address, &nria, amount)
+                .await
+                .unwrap();
+        }
+        state
+            .increase_bridge_account_total_withdrawn(&address, &utia, 5)
+            .await
+            .unwrap();
+        state
+            .increase_bridge_account_total_withdrawn(&other_address, &nria, 7)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            30,
+            state
+                .get_bridge_account_total_withdrawn(&address, &nria)
+                .await
+                .unwrap()
+        );
+        let mut expected = vec![(nria, 30), (utia, 5)];
+        expected.sort_unstable_by_key(|(asset_id, _)| asset_id.get());
+        assert_eq!(
+            expected,
+            state
+                .get_bridge_account_totals_withdrawn(&address)
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn bridge_account_total_withdrawn_overflow_is_an_error() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let address = crate::astria_address([42u8; 20]);
+        let nria = Id::from_str_unchecked("nria");
+        state
+            .increase_bridge_account_total_withdrawn(&address, &nria, u128::MAX)
+            .await
+            .unwrap();
+        state
+            .increase_bridge_account_total_withdrawn(&address, &nria, 1)
+            .await
+            .unwrap_err();
+    }
+
     #[tokio::test]
     async fn get_bridge_account_asset_id_none_should_fail() {
         let storage = cnidarium::TempStorage::new().await.unwrap();
@@ -1112,4 +1530,93 @@ mod test {
             "nonce should have been deleted also"
         );
     }
+
+    #[tokio::test]
+    async fn bridge_account_addresses_start_after_cursor_with_given_prefix() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        for byte in 0..3u8 {
+            let address = crate::astria_address([byte; 20]);
+            state.put_bridge_account_rollup_id(&address, &RollupId::new([byte; 32]));
+            // keys of the bridge account other than its rollup ID are not listed
+            state.put_bridge_account_creation_height(&address, 1);
+            state.put_last_transaction_hash_for_bridge_account(&address, &[byte; 32]);
+        }
+
+        let first_page = state
+            .get_bridge_account_addresses(None, 2, "other")
+            .await
+            .unwrap();
+        assert_eq!(
+            vec![
+                crate::try_address_with_prefix(&[0; 20], "other").unwrap(),
+                crate::try_address_with_prefix(&[1; 20], "other").unwrap(),
+            ],
+            first_page
+                .iter()
+                .map(|(_, address)| *address)
+                .collect::<Vec<_>>(),
+        );
+
+        let cursor = &first_page.last().unwrap().0;
+        let second_page = state
+            .get_bridge_account_addresses(Some(cursor), 2, "other")
+            .await
+            .unwrap();
+        assert_eq!(
+            vec![crate::try_address_with_prefix(&[2; 20], "other").unwrap()],
+            second_page
+                .iter()
+                .map(|(_, address)| *address)
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    #[tokio::test]
+    async fn backfill_bridge_account_index_lists_accounts_missing_from_index() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        // bridge accounts created before the index was introduced only have their rollup ID
+        // written to verifiable state
+        let old_address = crate::astria_address([1; 20]);
+        state.put_raw(
+            super::rollup_id_storage_key(&old_address),
+            RollupId::new([1; 32]).as_ref().to_vec(),
+        );
+        state
+            .put_bridge_account_asset_id(&old_address, &Id::from_str_unchecked("test"))
+            .unwrap();
+        let new_address = crate::astria_address([2; 20]);
+        state.put_bridge_account_rollup_id(&new_address, &RollupId::new([2; 32]));
+
+        assert!(!state.is_bridge_account_index_backfilled().await.unwrap());
+        assert_eq!(
+            vec![new_address],
+            state
+                .get_bridge_account_addresses(None, 10, crate::ADDRESS_PREFIX)
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|(_, address)| address)
+                .collect::<Vec<_>>(),
+        );
+
+        state.backfill_bridge_account_index().await.unwrap();
+
+        assert!(state.is_bridge_account_index_backfilled().await.unwrap());
+        assert_eq!(
+            vec![old_address, new_address],
+            state
+                .get_bridge_account_addresses(None, 10, crate::ADDRESS_PREFIX)
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|(_, address)| address)
+                .collect::<Vec<_>>(),
+        );
+    }
 }
@@ -33,6 +33,11 @@ pub struct Config {
     pub metrics_http_listener_addr: String,
     /// Writes a human readable format to stdout instead of JSON formatted OTEL trace data.
     pub pretty_print: bool,
+    /// The bech32 prefix that addresses passed to ABCI queries must carry.
+    pub address_prefix: String,
+    /// The maximum number of bridge ABCI queries handled concurrently. Queries in excess of this
+    /// are rejected.
+    pub max_concurrent_bridge_queries: usize,
 }
 
 impl config::Config for Config {
@@ -29,7 +29,10 @@ use crate::{
         StateReadExt,
         StateWriteExt,
     },
-    bridge::state_ext::StateReadExt as _,
+    bridge::state_ext::{
+        StateReadExt as _,
+        StateWriteExt as _,
+    },
     ibc::state_ext::{
         StateReadExt as _,
         StateWriteExt as _,
@@ -185,6 +188,19 @@ impl ActionHandler for action::Ics20Withdrawal {
             .await
             .context("failed to subtract fee from sender balance")?;
 
+        // withdrawals out of a bridge account are tracked so they can be reconciled per asset
+        if state
+            .get_bridge_account_rollup_id(&from)
+            .await
+            .context("failed to get bridge account rollup id")?
+            .is_some()
+        {
+            state
+                .increase_bridge_account_total_withdrawn(&from, &self.denom().id(), self.amount())
+                .await
+                .context("failed to increase bridge account total withdrawn")?;
+        }
+
         // if we're the source, move tokens to the escrow account,
         // otherwise the tokens are just burned
         if is_source(
@@ -45,8 +45,13 @@ pub(crate) fn astria_address(array: [u8; astria_core::primitive::v1::ADDRESS_LEN
 /// # Errors
 /// Fails if the slice does not contain 20 bytes.
 pub(crate) fn try_astria_address(slice: &[u8]) -> Result<Address, AddressError> {
-    Address::builder()
-        .slice(slice)
-        .prefix(ADDRESS_PREFIX)
-        .try_build()
+    try_address_with_prefix(slice, ADDRESS_PREFIX)
+}
+
+/// Tries to construct an [`Address`] with the bech32 human readable `prefix` from a byte slice.
+///
+/// # Errors
+/// Fails if the slice does not contain 20 bytes or if `prefix` is not a valid bech32 prefix.
+pub(crate) fn try_address_with_prefix(slice: &[u8], prefix: &str) -> Result<Address, AddressError> {
+    Address::builder().slice(slice).prefix(prefix).try_build()
 }
@@ -107,8 +107,12 @@ impl Sequencer {
                 async move { service::Consensus::new(storage, app, queue).run().await }
             }));
         let mempool_service = service::Mempool::new(storage.clone(), mempool.clone(), metrics);
-        let info_service =
-            service::Info::new(storage.clone()).context("failed initializing info service")?;
+        let info_service = service::Info::new(
+            storage.clone(),
+            config.address_prefix.clone(),
+            config.max_concurrent_bridge_queries,
+        )
+        .context("failed initializing info service")?;
         let snapshot_service = service::Snapshot;
 
         let server = Server::builder()
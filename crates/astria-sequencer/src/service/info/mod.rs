@@ -1,5 +1,6 @@
 use std::{
     pin::Pin,
+    sync::Arc,
     task::{
         Context,
         Poll,
@@ -23,6 +24,7 @@ use tendermint::v0_38::abci::{
     InfoRequest,
     InfoResponse,
 };
+use tokio::sync::Semaphore;
 use tower::Service;
 use tower_abci::BoxError;
 use tracing::{
@@ -41,7 +43,12 @@ pub(crate) struct Info {
 }
 
 impl Info {
-    pub(crate) fn new(storage: Storage) -> anyhow::Result<Self> {
+    pub(crate) fn new(
+        storage: Storage,
+        address_prefix: String,
+        max_concurrent_bridge_queries: usize,
+    ) -> anyhow::Result<Self> {
+        let bridge_query_permits = Arc::new(Semaphore::new(max_concurrent_bridge_queries));
         let mut query_router = abci_query_router::Router::new();
         query_router
             .insert(
@@ -64,12 +71,90 @@ impl Info {
                 crate::asset::query::allowed_fee_asset_ids_request,
             )
             .context("invalid path: `asset/allowed_fee_asset_ids`")?;
+        query_router
+            .insert("bridge/account_last_tx_hash/:address", {
+                let address_prefix = address_prefix.clone();
+                let permits = bridge_query_permits.clone();
+                move |storage: Storage, request: request::Query, params: Vec<(String, String)>| {
+                    crate::bridge::query::with_query_permit(
+                        permits,
+                        crate::bridge::query::bridge_account_last_tx_hash_request(
+                            storage,
+                            request,
+                            params,
+                            address_prefix,
+                        ),
+                    )
+                }
+            })
+            .context("invalid path: `bridge/account_last_tx_hash/:address`")?;
+        query_router
+            .insert("bridge/account_creation_height/:address", {
+                let address_prefix = address_prefix.clone();
+                let permits = bridge_query_permits.clone();
+                move |storage: Storage, request: request::Query, params: Vec<(String, String)>| {
+                    crate::bridge::query::with_query_permit(
+                        permits,
+                        crate::bridge::query::bridge_account_creation_height_request(
+                            storage,
+                            request,
+                            params,
+                            address_prefix,
+                        ),
+                    )
+                }
+            })
+            .context("invalid path: `bridge/account_creation_height/:address`")?;
+        query_router
+            .insert("bridge/account_total_withdrawn/:address", {
+                let address_prefix = address_prefix.clone();
+                let permits = bridge_query_permits.clone();
+                move |storage: Storage, request: request::Query, params: Vec<(String, String)>| {
+                    crate::bridge::query::with_query_permit(
+                        permits,
+                        crate::bridge::query::bridge_account_total_withdrawn_request(
+                            storage,
+                            request,
+                            params,
+                            address_prefix,
+                        ),
+                    )
+                }
+            })
+            .context("invalid path: `bridge/account_total_withdrawn/:address`")?;
+        query_router
+            .insert("bridge/account_info/:address", {
+                let address_prefix = address_prefix.clone();
+                let permits = bridge_query_permits.clone();
+                move |storage: Storage, request: request::Query, params: Vec<(String, String)>| {
+                    crate::bridge::query::with_query_permit(
+                        permits,
+                        crate::bridge::query::bridge_account_info_request(
+                            storage,
+                            request,
+                            params,
+                            address_prefix,
+                        ),
+                    )
+                }
+            })
+            .context("invalid path: `bridge/account_info/:address`")?;
         query_router
             .insert(
-                "bridge/account_last_tx_hash/:address",
-                crate::bridge::query::bridge_account_last_tx_hash_request,
+                "bridge/accounts",
+                move |storage: Storage, request: request::Query, params: Vec<(String, String)>| {
+                    crate::bridge::query::with_query_permit(
+                        bridge_query_permits,
+                        crate::bridge::query::bridge_accounts_list_request(
+                            storage,
+                            request,
+                            params,
+                            address_prefix,
+                        ),
+                    )
+                },
             )
-            .context("invalid path: `bridge/account_last_tx_hash/:address`")?;
+            .context("invalid path: `bridge/accounts`")?;
         Ok(Self {
             storage,
             query_router,
@@ -231,7 +316,7 @@ mod test {
 
         let response = {
             let storage = (*storage).clone();
-            let info_service = Info::new(storage).unwrap();
+            let info_service = Info::new(storage, crate::ADDRESS_PREFIX.to_string(), 100).unwrap();
             info_service
                 .handle_info_request(info_request)
                 .await
@@ -280,7 +365,7 @@ mod test {
 
         let response = {
             let storage = (*storage).clone();
-            let info_service = Info::new(storage).unwrap();
+            let info_service = Info::new(storage, crate::ADDRESS_PREFIX.to_string(), 100).unwrap();
             info_service
                 .handle_info_request(info_request)
                 .await
@@ -335,7 +420,7 @@ mod test {
 
         let response = {
             let storage = (*storage).clone();
-            let info_service = Info::new(storage).unwrap();
+            let info_service = Info::new(storage, crate::ADDRESS_PREFIX.to_string(), 100).unwrap();
             info_service
                 .handle_info_request(info_request)
                 .await
@@ -360,4 +445,69 @@ mod test {
             );
         }
     }
+
+    #[tokio::test]
+    async fn handle_bridge_account_info_query() {
+        use astria_core::{
+            generated::protocol::bridge::v1alpha1 as raw,
+            primitive::v1::RollupId,
+            protocol::bridge::v1alpha1::BridgeAccountInfo,
+        };
+
+        use crate::bridge::state_ext::StateWriteExt as _;
+
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+
+        let bridge_address = crate::try_astria_address(&[1; 20]).unwrap();
+        let expected = BridgeAccountInfo {
+            rollup_id: RollupId::from_unhashed_bytes(b"rollup"),
+            asset_id: asset::Id::from_str_unchecked("asset"),
+            sudo_address: crate::try_astria_address(&[2; 20]).unwrap(),
+            withdrawer_address: crate::try_astria_address(&[3; 20]).unwrap(),
+        };
+        let height = 99;
+        state.put_block_height(height);
+        state.put_bridge_account_rollup_id(&bridge_address, &expected.rollup_id);
+        state
+            .put_bridge_account_asset_id(&bridge_address, &expected.asset_id)
+            .unwrap();
+        state.put_bridge_account_sudo_address(&bridge_address, &expected.sudo_address);
+        state.put_bridge_account_withdrawer_address(&bridge_address, &expected.withdrawer_address);
+        storage.commit(state).await.unwrap();
+
+        let info_request = InfoRequest::Query(request::Query {
+            path: format!(
+                "bridge/account_info/{}",
+                hex::encode(bridge_address.bytes())
+            ),
+            data: vec![].into(),
+            height: u32::try_from(height).unwrap().into(),
+            prove: false,
+        });
+
+        let response = {
+            let storage = (*storage).clone();
+            let info_service = Info::new(storage, crate::ADDRESS_PREFIX.to_string(), 100).unwrap();
+            info_service
+                .handle_info_request(info_request)
+                .await
+                .unwrap()
+        };
+        let query_response = match response {
+            InfoResponse::Query(query) => query,
+            other => panic!("expected InfoResponse::Query, got {other:?}"),
+        };
+        assert!(query_response.code.is_ok());
+
+        let bridge_account_info_resp = raw::BridgeAccountInfoResponse::decode(query_response.value)
+            .unwrap()
+            .try_into_native()
+            .unwrap();
+        assert_eq!(bridge_account_info_resp.height, height);
+        assert_eq!(
+            bridge_account_info_resp.info_or_not_found().unwrap(),
+            &expected
+        );
+    }
 }
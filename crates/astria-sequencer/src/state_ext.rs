@@ -83,6 +83,19 @@ pub(crate) trait StateReadExt: StateRead {
         Ok(u64::from_be_bytes(bytes))
     }
 
+    /// Returns if the state was initialized by `init_chain`, which writes the first block height.
+    ///
+    /// A node whose store was never initialized has no block height, so [`Self::get_block_height`]
+    /// fails for it.
+    #[instrument(skip(self))]
+    async fn is_initialized(&self) -> Result<bool> {
+        Ok(self
+            .get_raw("block_height")
+            .await
+            .context("failed to read raw block_height from state")?
+            .is_some())
+    }
+
     #[instrument(skip(self))]
     async fn get_block_timestamp(&self) -> Result<Time> {
         let Some(bytes) = self
@@ -70,10 +70,15 @@ pub(crate) async fn execute<S: StateWriteExt>(
         .context("failed to check account rollup id")?
         .is_some()
     {
+        let height = state
+            .get_block_height()
+            .await
+            .context("failed to get block height")?;
         state.put_last_transaction_hash_for_bridge_account(
             &signer_address,
             &tx.sha256_of_proto_encoding(),
         );
+        state.put_last_transaction_height_for_bridge_account(&signer_address, height);
     }
 
     tx.unsigned_transaction()